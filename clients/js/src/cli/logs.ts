@@ -39,6 +39,7 @@ export function logExports(
                       [Encoding.Utf8]: 'UTF-8',
                       [Encoding.Base58]: 'Base58',
                       [Encoding.Base64]: 'Base64',
+                      [Encoding.Base16]: 'Base16',
                   }[options.exportEncoding]
               }`;
     const message = `Exporting ${transactionLength} ${transactionPluralized}${forAuthority} ${suffix}:\n`;
@@ -60,7 +60,7 @@ export const exportEncodingOption = new Option(
     '--export-encoding <encoding>',
     'Describes how to encode exported transactions. Use "instruction-list" to print a human-readable list of instructions instead of the raw transaction bytes.',
 )
-    .choices(['none', 'utf8', 'base58', 'base64', 'instruction-list'])
+    .choices(['none', 'utf8', 'base58', 'base64', 'base16', 'instruction-list'])
     .default(Encoding.Base64, 'base64')
     .argParser(
         (value: string): ExportEncoding => (value === 'instruction-list' ? 'instruction-list' : encodingParser(value)),
@@ -150,7 +150,7 @@ export const compressionOption = new Option('--compression <compression>', 'Desc
 
 export type EncodingOption = { encoding: Encoding };
 export const encodingOption = new Option('--encoding <encoding>', 'Describes how to encode the data.')
-    .choices(['none', 'utf8', 'base58', 'base64'])
+    .choices(['none', 'utf8', 'base58', 'base64', 'base16'])
     .default(Encoding.Utf8, 'utf8')
     .argParser(encodingParser);
 
@@ -30,6 +30,8 @@ export const encodingParser = (value: string): Encoding => {
             return Encoding.Base58;
         case 'base64':
             return Encoding.Base64;
+        case 'base16':
+            return Encoding.Base16;
         default:
             logErrorAndExit(`Invalid encoding option: ${value}`);
     }
@@ -23,6 +23,8 @@ import {
     getOptionEncoder,
     getStructDecoder,
     getStructEncoder,
+    getU8Decoder,
+    getU8Encoder,
     padRightDecoder,
     padRightEncoder,
     transformEncoder,
@@ -57,6 +59,8 @@ export type Buffer = {
     authority: Option<Address>;
     canonical: boolean;
     seed: Seed;
+    version: number;
+    seedLength: number;
     data: ReadonlyUint8Array;
 };
 
@@ -65,6 +69,8 @@ export type BufferArgs = {
     authority: OptionOrNullable<Address>;
     canonical: boolean;
     seed: SeedArgs;
+    version: number;
+    seedLength: number;
     data: ReadonlyUint8Array;
 };
 
@@ -76,7 +82,9 @@ export function getBufferEncoder(): Encoder<BufferArgs> {
             ['program', getOptionEncoder(getAddressEncoder(), { prefix: null, noneValue: 'zeroes' })],
             ['authority', getOptionEncoder(getAddressEncoder(), { prefix: null, noneValue: 'zeroes' })],
             ['canonical', getBooleanEncoder()],
-            ['seed', padRightEncoder(getSeedEncoder(), 14)],
+            ['seed', getSeedEncoder()],
+            ['version', getU8Encoder()],
+            ['seedLength', padRightEncoder(getU8Encoder(), 12)],
             ['data', getBytesEncoder()],
         ]),
         value => ({ ...value, discriminator: AccountDiscriminator.Buffer }),
@@ -90,7 +98,9 @@ export function getBufferDecoder(): Decoder<Buffer> {
         ['program', getOptionDecoder(getAddressDecoder(), { prefix: null, noneValue: 'zeroes' })],
         ['authority', getOptionDecoder(getAddressDecoder(), { prefix: null, noneValue: 'zeroes' })],
         ['canonical', getBooleanDecoder()],
-        ['seed', padRightDecoder(getSeedDecoder(), 14)],
+        ['seed', getSeedDecoder()],
+        ['version', getU8Decoder()],
+        ['seedLength', padRightDecoder(getU8Decoder(), 12)],
         ['data', getBytesDecoder()],
     ]);
 }
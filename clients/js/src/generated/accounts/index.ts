@@ -8,3 +8,4 @@
 
 export * from './buffer';
 export * from './metadata';
+export * from './pendingAuthority';
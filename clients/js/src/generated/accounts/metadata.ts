@@ -13,6 +13,8 @@ import {
     decodeAccount,
     fetchEncodedAccount,
     fetchEncodedAccounts,
+    fixDecoderSize,
+    fixEncoderSize,
     getAddressDecoder,
     getAddressEncoder,
     getBooleanDecoder,
@@ -25,8 +27,8 @@ import {
     getStructEncoder,
     getU32Decoder,
     getU32Encoder,
-    padRightDecoder,
-    padRightEncoder,
+    getU8Decoder,
+    getU8Encoder,
     transformEncoder,
     type Account,
     type Address,
@@ -81,6 +83,10 @@ export type Metadata = {
     format: Format;
     dataSource: DataSource;
     dataLength: number;
+    flags: number;
+    reserved: ReadonlyUint8Array;
+    version: number;
+    seedLength: number;
     data: ReadonlyUint8Array;
 };
 
@@ -95,6 +101,10 @@ export type MetadataArgs = {
     format: FormatArgs;
     dataSource: DataSourceArgs;
     dataLength: number;
+    flags: number;
+    reserved: ReadonlyUint8Array;
+    version: number;
+    seedLength: number;
     data: ReadonlyUint8Array;
 };
 
@@ -112,7 +122,11 @@ export function getMetadataEncoder(): Encoder<MetadataArgs> {
             ['compression', getCompressionEncoder()],
             ['format', getFormatEncoder()],
             ['dataSource', getDataSourceEncoder()],
-            ['dataLength', padRightEncoder(getU32Encoder(), 5)],
+            ['dataLength', getU32Encoder()],
+            ['flags', getU8Encoder()],
+            ['reserved', fixEncoderSize(getBytesEncoder(), 2)],
+            ['version', getU8Encoder()],
+            ['seedLength', getU8Encoder()],
             ['data', getBytesEncoder()],
         ]),
         value => ({ ...value, discriminator: AccountDiscriminator.Metadata }),
@@ -132,7 +146,11 @@ export function getMetadataDecoder(): Decoder<Metadata> {
         ['compression', getCompressionDecoder()],
         ['format', getFormatDecoder()],
         ['dataSource', getDataSourceDecoder()],
-        ['dataLength', padRightDecoder(getU32Decoder(), 5)],
+        ['dataLength', getU32Decoder()],
+        ['flags', getU8Decoder()],
+        ['reserved', fixDecoderSize(getBytesDecoder(), 2)],
+        ['version', getU8Decoder()],
+        ['seedLength', getU8Decoder()],
         ['data', getBytesDecoder()],
     ]);
 }
@@ -24,22 +24,34 @@ export const PROGRAM_METADATA_ERROR__INVALID_PROGRAM_DATA_ACCOUNT = 0x2; // 2
 export const PROGRAM_METADATA_ERROR__IMMUTABLE_METADATA_ACCOUNT = 0x3; // 3
 /** InvalidDataLength: The account data length is invalid */
 export const PROGRAM_METADATA_ERROR__INVALID_DATA_LENGTH = 0x4; // 4
+/** HashMismatch: The metadata account content hash does not match */
+export const PROGRAM_METADATA_ERROR__HASH_MISMATCH = 0x5; // 5
+/** MetadataChanged: The metadata account has changed since it was read */
+export const PROGRAM_METADATA_ERROR__METADATA_CHANGED = 0x6; // 6
+/** UnsupportedAccountVersion: The account layout version is not supported */
+export const PROGRAM_METADATA_ERROR__UNSUPPORTED_ACCOUNT_VERSION = 0x7; // 7
 
 export type ProgramMetadataError =
+    | typeof PROGRAM_METADATA_ERROR__HASH_MISMATCH
     | typeof PROGRAM_METADATA_ERROR__IMMUTABLE_METADATA_ACCOUNT
     | typeof PROGRAM_METADATA_ERROR__INVALID_DATA_LENGTH
     | typeof PROGRAM_METADATA_ERROR__INVALID_PROGRAM_DATA_ACCOUNT
     | typeof PROGRAM_METADATA_ERROR__INVALID_PROGRAM_STATE
-    | typeof PROGRAM_METADATA_ERROR__NOT_EXECUTABLE_ACCOUNT;
+    | typeof PROGRAM_METADATA_ERROR__METADATA_CHANGED
+    | typeof PROGRAM_METADATA_ERROR__NOT_EXECUTABLE_ACCOUNT
+    | typeof PROGRAM_METADATA_ERROR__UNSUPPORTED_ACCOUNT_VERSION;
 
 let programMetadataErrorMessages: Record<ProgramMetadataError, string> | undefined;
 if (process.env['NODE_ENV'] !== 'production') {
     programMetadataErrorMessages = {
+        [PROGRAM_METADATA_ERROR__HASH_MISMATCH]: `The metadata account content hash does not match`,
         [PROGRAM_METADATA_ERROR__IMMUTABLE_METADATA_ACCOUNT]: `The metadata account is immutable`,
         [PROGRAM_METADATA_ERROR__INVALID_DATA_LENGTH]: `The account data length is invalid`,
         [PROGRAM_METADATA_ERROR__INVALID_PROGRAM_DATA_ACCOUNT]: `The program data account is invalid`,
         [PROGRAM_METADATA_ERROR__INVALID_PROGRAM_STATE]: `The program state is invalid`,
+        [PROGRAM_METADATA_ERROR__METADATA_CHANGED]: `The metadata account has changed since it was read`,
         [PROGRAM_METADATA_ERROR__NOT_EXECUTABLE_ACCOUNT]: `The program account is not executable`,
+        [PROGRAM_METADATA_ERROR__UNSUPPORTED_ACCOUNT_VERSION]: `The account layout version is not supported`,
     };
 }
 
@@ -12,6 +12,8 @@ import {
     getOptionEncoder,
     getStructDecoder,
     getStructEncoder,
+    getU32Decoder,
+    getU32Encoder,
     getU8Decoder,
     getU8Encoder,
     none,
@@ -72,11 +74,15 @@ export type AllocateInstructionData = {
     discriminator: number;
     /** The seed of the metadata for PDA buffers. */
     seed: Option<Seed>;
+    /** The initial capacity of the buffer data, in addition to the buffer header. */
+    capacity: Option<number>;
 };
 
 export type AllocateInstructionDataArgs = {
     /** The seed of the metadata for PDA buffers. */
     seed?: OptionOrNullable<SeedArgs>;
+    /** The initial capacity of the buffer data, in addition to the buffer header. */
+    capacity?: OptionOrNullable<number>;
 };
 
 export function getAllocateInstructionDataEncoder(): Encoder<AllocateInstructionDataArgs> {
@@ -84,8 +90,14 @@ export function getAllocateInstructionDataEncoder(): Encoder<AllocateInstruction
         getStructEncoder([
             ['discriminator', getU8Encoder()],
             ['seed', getOptionEncoder(getSeedEncoder(), { prefix: null })],
+            ['capacity', getOptionEncoder(getU32Encoder(), { prefix: null })],
         ]),
-        value => ({ ...value, discriminator: ALLOCATE_DISCRIMINATOR, seed: value.seed ?? none() }),
+        value => ({
+            ...value,
+            discriminator: ALLOCATE_DISCRIMINATOR,
+            seed: value.seed ?? none(),
+            capacity: value.capacity ?? none(),
+        }),
     );
 }
 
@@ -93,6 +105,7 @@ export function getAllocateInstructionDataDecoder(): Decoder<AllocateInstruction
     return getStructDecoder([
         ['discriminator', getU8Decoder()],
         ['seed', getOptionDecoder(getSeedDecoder(), { prefix: null })],
+        ['capacity', getOptionDecoder(getU32Decoder(), { prefix: null })],
     ]);
 }
 
@@ -118,6 +131,7 @@ export type AllocateInput<
     /** System program. */
     system?: Address<TAccountSystem>;
     seed?: AllocateInstructionDataArgs['seed'];
+    capacity?: AllocateInstructionDataArgs['capacity'];
 };
 
 export function getAllocateInstruction<
@@ -10,8 +10,8 @@ import {
     combineCodec,
     getStructDecoder,
     getStructEncoder,
-    getU16Decoder,
-    getU16Encoder,
+    getU32Decoder,
+    getU32Encoder,
     getU8Decoder,
     getU8Encoder,
     SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
@@ -31,6 +31,7 @@ import {
     type ReadonlyUint8Array,
     type TransactionSigner,
     type WritableAccount,
+    type WritableSignerAccount,
 } from '@solana/kit';
 import { getAccountMetaFactory, type ResolvedInstructionAccount } from '@solana/kit/program-client-core';
 import { PROGRAM_METADATA_PROGRAM_ADDRESS } from '../programs';
@@ -47,6 +48,8 @@ export type ExtendInstruction<
     TAccountAuthority extends string | AccountMeta<string> = string,
     TAccountProgram extends string | AccountMeta<string> = string,
     TAccountProgramData extends string | AccountMeta<string> = string,
+    TAccountPayer extends string | AccountMeta<string> = string,
+    TAccountSystem extends string | AccountMeta<string> = '11111111111111111111111111111111',
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -58,6 +61,10 @@ export type ExtendInstruction<
                 : TAccountAuthority,
             TAccountProgram extends string ? ReadonlyAccount<TAccountProgram> : TAccountProgram,
             TAccountProgramData extends string ? ReadonlyAccount<TAccountProgramData> : TAccountProgramData,
+            TAccountPayer extends string
+                ? WritableSignerAccount<TAccountPayer> & AccountSignerMeta<TAccountPayer>
+                : TAccountPayer,
+            TAccountSystem extends string ? ReadonlyAccount<TAccountSystem> : TAccountSystem,
             ...TRemainingAccounts,
         ]
     >;
@@ -77,7 +84,7 @@ export function getExtendInstructionDataEncoder(): FixedSizeEncoder<ExtendInstru
     return transformEncoder(
         getStructEncoder([
             ['discriminator', getU8Encoder()],
-            ['length', getU16Encoder()],
+            ['length', getU32Encoder()],
         ]),
         value => ({ ...value, discriminator: EXTEND_DISCRIMINATOR }),
     );
@@ -86,7 +93,7 @@ export function getExtendInstructionDataEncoder(): FixedSizeEncoder<ExtendInstru
 export function getExtendInstructionDataDecoder(): FixedSizeDecoder<ExtendInstructionData> {
     return getStructDecoder([
         ['discriminator', getU8Decoder()],
-        ['length', getU16Decoder()],
+        ['length', getU32Decoder()],
     ]);
 }
 
@@ -99,6 +106,8 @@ export type ExtendInput<
     TAccountAuthority extends string = string,
     TAccountProgram extends string = string,
     TAccountProgramData extends string = string,
+    TAccountPayer extends string = string,
+    TAccountSystem extends string = string,
 > = {
     /** Buffer or metadata account. */
     account: Address<TAccountAccount>;
@@ -108,6 +117,10 @@ export type ExtendInput<
     program?: Address<TAccountProgram>;
     /** Program data account. */
     programData?: Address<TAccountProgramData>;
+    /** Payer account to fund the additional rent of the account. */
+    payer?: TransactionSigner<TAccountPayer>;
+    /** System program. */
+    system?: Address<TAccountSystem>;
     length: ExtendInstructionDataArgs['length'];
 };
 
@@ -116,11 +129,28 @@ export function getExtendInstruction<
     TAccountAuthority extends string,
     TAccountProgram extends string,
     TAccountProgramData extends string,
+    TAccountPayer extends string,
+    TAccountSystem extends string,
     TProgramAddress extends Address = typeof PROGRAM_METADATA_PROGRAM_ADDRESS,
 >(
-    input: ExtendInput<TAccountAccount, TAccountAuthority, TAccountProgram, TAccountProgramData>,
+    input: ExtendInput<
+        TAccountAccount,
+        TAccountAuthority,
+        TAccountProgram,
+        TAccountProgramData,
+        TAccountPayer,
+        TAccountSystem
+    >,
     config?: { programAddress?: TProgramAddress },
-): ExtendInstruction<TProgramAddress, TAccountAccount, TAccountAuthority, TAccountProgram, TAccountProgramData> {
+): ExtendInstruction<
+    TProgramAddress,
+    TAccountAccount,
+    TAccountAuthority,
+    TAccountProgram,
+    TAccountProgramData,
+    TAccountPayer,
+    TAccountSystem
+> {
     // Program address.
     const programAddress = config?.programAddress ?? PROGRAM_METADATA_PROGRAM_ADDRESS;
 
@@ -130,12 +160,19 @@ export function getExtendInstruction<
         authority: { value: input.authority ?? null, isWritable: false },
         program: { value: input.program ?? null, isWritable: false },
         programData: { value: input.programData ?? null, isWritable: false },
+        payer: { value: input.payer ?? null, isWritable: true },
+        system: { value: input.system ?? null, isWritable: false },
     };
     const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
     // Original args.
     const args = { ...input };
 
+    // Resolve default values.
+    if (!accounts.system.value) {
+        accounts.system.value = '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+    }
+
     const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
     return Object.freeze({
         accounts: [
@@ -143,10 +180,20 @@ export function getExtendInstruction<
             getAccountMeta('authority', accounts.authority),
             getAccountMeta('program', accounts.program),
             getAccountMeta('programData', accounts.programData),
+            getAccountMeta('payer', accounts.payer),
+            getAccountMeta('system', accounts.system),
         ],
         data: getExtendInstructionDataEncoder().encode(args as ExtendInstructionDataArgs),
         programAddress,
-    } as ExtendInstruction<TProgramAddress, TAccountAccount, TAccountAuthority, TAccountProgram, TAccountProgramData>);
+    } as ExtendInstruction<
+        TProgramAddress,
+        TAccountAccount,
+        TAccountAuthority,
+        TAccountProgram,
+        TAccountProgramData,
+        TAccountPayer,
+        TAccountSystem
+    >);
 }
 
 export type ParsedExtendInstruction<
@@ -163,6 +210,10 @@ export type ParsedExtendInstruction<
         program?: TAccountMetas[2] | undefined;
         /** Program data account. */
         programData?: TAccountMetas[3] | undefined;
+        /** Payer account to fund the additional rent of the account. */
+        payer?: TAccountMetas[4] | undefined;
+        /** System program. */
+        system?: TAccountMetas[5] | undefined;
     };
     data: ExtendInstructionData;
 };
@@ -172,10 +223,10 @@ export function parseExtendInstruction<TProgram extends string, TAccountMetas ex
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>,
 ): ParsedExtendInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 4) {
+    if (instruction.accounts.length < 6) {
         throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, {
             actualAccountMetas: instruction.accounts.length,
-            expectedAccountMetas: 4,
+            expectedAccountMetas: 6,
         });
     }
     let accountIndex = 0;
@@ -195,6 +246,8 @@ export function parseExtendInstruction<TProgram extends string, TAccountMetas ex
             authority: getNextAccount(),
             program: getNextOptionalAccount(),
             programData: getNextOptionalAccount(),
+            payer: getNextOptionalAccount(),
+            system: getNextOptionalAccount(),
         },
         data: getExtendInstructionDataDecoder().decode(instruction.data),
     };
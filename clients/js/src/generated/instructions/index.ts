@@ -6,12 +6,24 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAuthority';
 export * from './allocate';
+export * from './cancelAuthority';
 export * from './close';
 export * from './extend';
 export * from './initialize';
+export * from './initializeWithSeed';
+export * from './migrate';
+export * from './move';
+export * from './patch';
+export * from './promote';
+export * from './proposeAuthority';
 export * from './setAuthority';
 export * from './setData';
+export * from './setDataChecked';
+export * from './setExtension';
+export * from './setHash';
 export * from './setImmutable';
 export * from './trim';
+export * from './verifyHash';
 export * from './write';
@@ -54,6 +54,8 @@ import {
     getEncodingEncoder,
     getFormatDecoder,
     getFormatEncoder,
+    getHashDecoder,
+    getHashEncoder,
     getSeedDecoder,
     getSeedEncoder,
     type Compression,
@@ -64,6 +66,8 @@ import {
     type EncodingArgs,
     type Format,
     type FormatArgs,
+    type Hash,
+    type HashArgs,
     type Seed,
     type SeedArgs,
 } from '../types';
@@ -81,6 +85,9 @@ export type InitializeInstruction<
     TAccountProgram extends string | AccountMeta<string> = string,
     TAccountProgramData extends string | AccountMeta<string> = string,
     TAccountSystem extends string | AccountMeta<string> = '11111111111111111111111111111111',
+    TAccountSourceBuffer extends string | AccountMeta<string> = string,
+    TAccountDestination extends string | AccountMeta<string> = string,
+    TAccountExternal extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -93,6 +100,9 @@ export type InitializeInstruction<
             TAccountProgram extends string ? ReadonlyAccount<TAccountProgram> : TAccountProgram,
             TAccountProgramData extends string ? ReadonlyAccount<TAccountProgramData> : TAccountProgramData,
             TAccountSystem extends string ? ReadonlyAccount<TAccountSystem> : TAccountSystem,
+            TAccountSourceBuffer extends string ? WritableAccount<TAccountSourceBuffer> : TAccountSourceBuffer,
+            TAccountDestination extends string ? WritableAccount<TAccountDestination> : TAccountDestination,
+            TAccountExternal extends string ? ReadonlyAccount<TAccountExternal> : TAccountExternal,
             ...TRemainingAccounts,
         ]
     >;
@@ -104,6 +114,7 @@ export type InitializeInstructionData = {
     compression: Compression;
     format: Format;
     dataSource: DataSource;
+    hash: Option<Hash>;
     data: Option<ReadonlyUint8Array>;
 };
 
@@ -113,6 +124,7 @@ export type InitializeInstructionDataArgs = {
     compression: CompressionArgs;
     format: FormatArgs;
     dataSource: DataSourceArgs;
+    hash?: OptionOrNullable<HashArgs>;
     data?: OptionOrNullable<ReadonlyUint8Array>;
 };
 
@@ -125,9 +137,15 @@ export function getInitializeInstructionDataEncoder(): Encoder<InitializeInstruc
             ['compression', getCompressionEncoder()],
             ['format', getFormatEncoder()],
             ['dataSource', getDataSourceEncoder()],
+            ['hash', getOptionEncoder(getHashEncoder())],
             ['data', getOptionEncoder(getBytesEncoder(), { prefix: null })],
         ]),
-        value => ({ ...value, discriminator: INITIALIZE_DISCRIMINATOR, data: value.data ?? none() }),
+        value => ({
+            ...value,
+            discriminator: INITIALIZE_DISCRIMINATOR,
+            hash: value.hash ?? none(),
+            data: value.data ?? none(),
+        }),
     );
 }
 
@@ -139,6 +157,7 @@ export function getInitializeInstructionDataDecoder(): Decoder<InitializeInstruc
         ['compression', getCompressionDecoder()],
         ['format', getFormatDecoder()],
         ['dataSource', getDataSourceDecoder()],
+        ['hash', getOptionDecoder(getHashDecoder())],
         ['data', getOptionDecoder(getBytesDecoder(), { prefix: null })],
     ]);
 }
@@ -153,6 +172,9 @@ export type InitializeAsyncInput<
     TAccountProgram extends string = string,
     TAccountProgramData extends string = string,
     TAccountSystem extends string = string,
+    TAccountSourceBuffer extends string = string,
+    TAccountDestination extends string = string,
+    TAccountExternal extends string = string,
 > = {
     /** Metadata account the initialize. */
     metadata?: Address<TAccountMetadata>;
@@ -164,11 +186,27 @@ export type InitializeAsyncInput<
     programData?: Address<TAccountProgramData>;
     /** System program. */
     system?: Address<TAccountSystem>;
+    /**
+     * Buffer account to copy the data from.
+     * You may use the `data` argument instead of this account to pass data directly.
+     */
+    sourceBuffer?: Address<TAccountSourceBuffer>;
+    /**
+     * Destination account for the lamports of the source buffer account.
+     * When provided, the source buffer account is closed after its data is copied.
+     */
+    destination?: Address<TAccountDestination>;
+    /**
+     * External account referenced by the data.
+     * Required for external data that records an expected owner and content digest.
+     */
+    external?: Address<TAccountExternal>;
     seed: InitializeInstructionDataArgs['seed'];
     encoding: InitializeInstructionDataArgs['encoding'];
     compression: InitializeInstructionDataArgs['compression'];
     format: InitializeInstructionDataArgs['format'];
     dataSource: InitializeInstructionDataArgs['dataSource'];
+    hash?: InitializeInstructionDataArgs['hash'];
     data?: InitializeInstructionDataArgs['data'];
 };
 
@@ -178,6 +216,9 @@ export async function getInitializeInstructionAsync<
     TAccountProgram extends string,
     TAccountProgramData extends string,
     TAccountSystem extends string,
+    TAccountSourceBuffer extends string,
+    TAccountDestination extends string,
+    TAccountExternal extends string,
     TProgramAddress extends Address = typeof PROGRAM_METADATA_PROGRAM_ADDRESS,
 >(
     input: InitializeAsyncInput<
@@ -185,7 +226,10 @@ export async function getInitializeInstructionAsync<
         TAccountAuthority,
         TAccountProgram,
         TAccountProgramData,
-        TAccountSystem
+        TAccountSystem,
+        TAccountSourceBuffer,
+        TAccountDestination,
+        TAccountExternal
     >,
     config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -195,7 +239,10 @@ export async function getInitializeInstructionAsync<
         TAccountAuthority,
         TAccountProgram,
         TAccountProgramData,
-        TAccountSystem
+        TAccountSystem,
+        TAccountSourceBuffer,
+        TAccountDestination,
+        TAccountExternal
     >
 > {
     // Program address.
@@ -208,6 +255,9 @@ export async function getInitializeInstructionAsync<
         program: { value: input.program ?? null, isWritable: false },
         programData: { value: input.programData ?? null, isWritable: false },
         system: { value: input.system ?? null, isWritable: false },
+        sourceBuffer: { value: input.sourceBuffer ?? null, isWritable: true },
+        destination: { value: input.destination ?? null, isWritable: true },
+        external: { value: input.external ?? null, isWritable: false },
     };
     const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
@@ -241,6 +291,9 @@ export async function getInitializeInstructionAsync<
             getAccountMeta('program', accounts.program),
             getAccountMeta('programData', accounts.programData),
             getAccountMeta('system', accounts.system),
+            getAccountMeta('sourceBuffer', accounts.sourceBuffer),
+            getAccountMeta('destination', accounts.destination),
+            getAccountMeta('external', accounts.external),
         ],
         data: getInitializeInstructionDataEncoder().encode(args as InitializeInstructionDataArgs),
         programAddress,
@@ -250,7 +303,10 @@ export async function getInitializeInstructionAsync<
         TAccountAuthority,
         TAccountProgram,
         TAccountProgramData,
-        TAccountSystem
+        TAccountSystem,
+        TAccountSourceBuffer,
+        TAccountDestination,
+        TAccountExternal
     >);
 }
 
@@ -260,6 +316,9 @@ export type InitializeInput<
     TAccountProgram extends string = string,
     TAccountProgramData extends string = string,
     TAccountSystem extends string = string,
+    TAccountSourceBuffer extends string = string,
+    TAccountDestination extends string = string,
+    TAccountExternal extends string = string,
 > = {
     /** Metadata account the initialize. */
     metadata: Address<TAccountMetadata>;
@@ -271,11 +330,27 @@ export type InitializeInput<
     programData?: Address<TAccountProgramData>;
     /** System program. */
     system?: Address<TAccountSystem>;
+    /**
+     * Buffer account to copy the data from.
+     * You may use the `data` argument instead of this account to pass data directly.
+     */
+    sourceBuffer?: Address<TAccountSourceBuffer>;
+    /**
+     * Destination account for the lamports of the source buffer account.
+     * When provided, the source buffer account is closed after its data is copied.
+     */
+    destination?: Address<TAccountDestination>;
+    /**
+     * External account referenced by the data.
+     * Required for external data that records an expected owner and content digest.
+     */
+    external?: Address<TAccountExternal>;
     seed: InitializeInstructionDataArgs['seed'];
     encoding: InitializeInstructionDataArgs['encoding'];
     compression: InitializeInstructionDataArgs['compression'];
     format: InitializeInstructionDataArgs['format'];
     dataSource: InitializeInstructionDataArgs['dataSource'];
+    hash?: InitializeInstructionDataArgs['hash'];
     data?: InitializeInstructionDataArgs['data'];
 };
 
@@ -285,9 +360,21 @@ export function getInitializeInstruction<
     TAccountProgram extends string,
     TAccountProgramData extends string,
     TAccountSystem extends string,
+    TAccountSourceBuffer extends string,
+    TAccountDestination extends string,
+    TAccountExternal extends string,
     TProgramAddress extends Address = typeof PROGRAM_METADATA_PROGRAM_ADDRESS,
 >(
-    input: InitializeInput<TAccountMetadata, TAccountAuthority, TAccountProgram, TAccountProgramData, TAccountSystem>,
+    input: InitializeInput<
+        TAccountMetadata,
+        TAccountAuthority,
+        TAccountProgram,
+        TAccountProgramData,
+        TAccountSystem,
+        TAccountSourceBuffer,
+        TAccountDestination,
+        TAccountExternal
+    >,
     config?: { programAddress?: TProgramAddress },
 ): InitializeInstruction<
     TProgramAddress,
@@ -295,7 +382,10 @@ export function getInitializeInstruction<
     TAccountAuthority,
     TAccountProgram,
     TAccountProgramData,
-    TAccountSystem
+    TAccountSystem,
+    TAccountSourceBuffer,
+    TAccountDestination,
+    TAccountExternal
 > {
     // Program address.
     const programAddress = config?.programAddress ?? PROGRAM_METADATA_PROGRAM_ADDRESS;
@@ -307,6 +397,9 @@ export function getInitializeInstruction<
         program: { value: input.program ?? null, isWritable: false },
         programData: { value: input.programData ?? null, isWritable: false },
         system: { value: input.system ?? null, isWritable: false },
+        sourceBuffer: { value: input.sourceBuffer ?? null, isWritable: true },
+        destination: { value: input.destination ?? null, isWritable: true },
+        external: { value: input.external ?? null, isWritable: false },
     };
     const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
@@ -326,6 +419,9 @@ export function getInitializeInstruction<
             getAccountMeta('program', accounts.program),
             getAccountMeta('programData', accounts.programData),
             getAccountMeta('system', accounts.system),
+            getAccountMeta('sourceBuffer', accounts.sourceBuffer),
+            getAccountMeta('destination', accounts.destination),
+            getAccountMeta('external', accounts.external),
         ],
         data: getInitializeInstructionDataEncoder().encode(args as InitializeInstructionDataArgs),
         programAddress,
@@ -335,7 +431,10 @@ export function getInitializeInstruction<
         TAccountAuthority,
         TAccountProgram,
         TAccountProgramData,
-        TAccountSystem
+        TAccountSystem,
+        TAccountSourceBuffer,
+        TAccountDestination,
+        TAccountExternal
     >);
 }
 
@@ -355,6 +454,21 @@ export type ParsedInitializeInstruction<
         programData?: TAccountMetas[3] | undefined;
         /** System program. */
         system?: TAccountMetas[4] | undefined;
+        /**
+         * Buffer account to copy the data from.
+         * You may use the `data` argument instead of this account to pass data directly.
+         */
+        sourceBuffer?: TAccountMetas[5] | undefined;
+        /**
+         * Destination account for the lamports of the source buffer account.
+         * When provided, the source buffer account is closed after its data is copied.
+         */
+        destination?: TAccountMetas[6] | undefined;
+        /**
+         * External account referenced by the data.
+         * Required for external data that records an expected owner and content digest.
+         */
+        external?: TAccountMetas[7] | undefined;
     };
     data: InitializeInstructionData;
 };
@@ -364,10 +478,10 @@ export function parseInitializeInstruction<TProgram extends string, TAccountMeta
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>,
 ): ParsedInitializeInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 5) {
+    if (instruction.accounts.length < 8) {
         throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, {
             actualAccountMetas: instruction.accounts.length,
-            expectedAccountMetas: 5,
+            expectedAccountMetas: 8,
         });
     }
     let accountIndex = 0;
@@ -388,6 +502,9 @@ export function parseInitializeInstruction<TProgram extends string, TAccountMeta
             program: getNextAccount(),
             programData: getNextOptionalAccount(),
             system: getNextOptionalAccount(),
+            sourceBuffer: getNextOptionalAccount(),
+            destination: getNextOptionalAccount(),
+            external: getNextOptionalAccount(),
         },
         data: getInitializeInstructionDataDecoder().decode(instruction.data),
     };
@@ -48,6 +48,8 @@ import {
     getEncodingEncoder,
     getFormatDecoder,
     getFormatEncoder,
+    getHashDecoder,
+    getHashEncoder,
     type Compression,
     type CompressionArgs,
     type DataSource,
@@ -56,6 +58,8 @@ import {
     type EncodingArgs,
     type Format,
     type FormatArgs,
+    type Hash,
+    type HashArgs,
 } from '../types';
 
 export const SET_DATA_DISCRIMINATOR = 3;
@@ -71,6 +75,8 @@ export type SetDataInstruction<
     TAccountBuffer extends string | AccountMeta<string> = string,
     TAccountProgram extends string | AccountMeta<string> = string,
     TAccountProgramData extends string | AccountMeta<string> = string,
+    TAccountDestination extends string | AccountMeta<string> = string,
+    TAccountExternal extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -83,6 +89,8 @@ export type SetDataInstruction<
             TAccountBuffer extends string ? WritableAccount<TAccountBuffer> : TAccountBuffer,
             TAccountProgram extends string ? ReadonlyAccount<TAccountProgram> : TAccountProgram,
             TAccountProgramData extends string ? ReadonlyAccount<TAccountProgramData> : TAccountProgramData,
+            TAccountDestination extends string ? WritableAccount<TAccountDestination> : TAccountDestination,
+            TAccountExternal extends string ? ReadonlyAccount<TAccountExternal> : TAccountExternal,
             ...TRemainingAccounts,
         ]
     >;
@@ -92,6 +100,7 @@ export type SetDataInstructionData = {
     encoding: Encoding;
     compression: Compression;
     format: Format;
+    hash: Option<Hash>;
     dataSource: DataSource;
     data: Option<ReadonlyUint8Array>;
 };
@@ -100,6 +109,7 @@ export type SetDataInstructionDataArgs = {
     encoding: EncodingArgs;
     compression: CompressionArgs;
     format: FormatArgs;
+    hash?: OptionOrNullable<HashArgs>;
     dataSource: DataSourceArgs;
     data?: OptionOrNullable<ReadonlyUint8Array>;
 };
@@ -111,10 +121,16 @@ export function getSetDataInstructionDataEncoder(): Encoder<SetDataInstructionDa
             ['encoding', getEncodingEncoder()],
             ['compression', getCompressionEncoder()],
             ['format', getFormatEncoder()],
+            ['hash', getOptionEncoder(getHashEncoder())],
             ['dataSource', getDataSourceEncoder()],
             ['data', getOptionEncoder(getBytesEncoder(), { prefix: null })],
         ]),
-        value => ({ ...value, discriminator: SET_DATA_DISCRIMINATOR, data: value.data ?? none() }),
+        value => ({
+            ...value,
+            discriminator: SET_DATA_DISCRIMINATOR,
+            hash: value.hash ?? none(),
+            data: value.data ?? none(),
+        }),
     );
 }
 
@@ -124,6 +140,7 @@ export function getSetDataInstructionDataDecoder(): Decoder<SetDataInstructionDa
         ['encoding', getEncodingDecoder()],
         ['compression', getCompressionDecoder()],
         ['format', getFormatDecoder()],
+        ['hash', getOptionDecoder(getHashDecoder())],
         ['dataSource', getDataSourceDecoder()],
         ['data', getOptionDecoder(getBytesDecoder(), { prefix: null })],
     ]);
@@ -139,6 +156,8 @@ export type SetDataInput<
     TAccountBuffer extends string = string,
     TAccountProgram extends string = string,
     TAccountProgramData extends string = string,
+    TAccountDestination extends string = string,
+    TAccountExternal extends string = string,
 > = {
     /** Metadata account. */
     metadata: Address<TAccountMetadata>;
@@ -150,9 +169,20 @@ export type SetDataInput<
     program?: Address<TAccountProgram>;
     /** Program data account. */
     programData?: Address<TAccountProgramData>;
+    /**
+     * Destination account for the excess lamports of the metadata account.
+     * When provided, the buffer account (if any) is also closed after its data is copied.
+     */
+    destination?: Address<TAccountDestination>;
+    /**
+     * External account referenced by the data.
+     * Required for external data that records an expected owner and content digest.
+     */
+    external?: Address<TAccountExternal>;
     encoding: SetDataInstructionDataArgs['encoding'];
     compression: SetDataInstructionDataArgs['compression'];
     format: SetDataInstructionDataArgs['format'];
+    hash?: SetDataInstructionDataArgs['hash'];
     dataSource: SetDataInstructionDataArgs['dataSource'];
     data?: SetDataInstructionDataArgs['data'];
 };
@@ -163,9 +193,19 @@ export function getSetDataInstruction<
     TAccountBuffer extends string,
     TAccountProgram extends string,
     TAccountProgramData extends string,
+    TAccountDestination extends string,
+    TAccountExternal extends string,
     TProgramAddress extends Address = typeof PROGRAM_METADATA_PROGRAM_ADDRESS,
 >(
-    input: SetDataInput<TAccountMetadata, TAccountAuthority, TAccountBuffer, TAccountProgram, TAccountProgramData>,
+    input: SetDataInput<
+        TAccountMetadata,
+        TAccountAuthority,
+        TAccountBuffer,
+        TAccountProgram,
+        TAccountProgramData,
+        TAccountDestination,
+        TAccountExternal
+    >,
     config?: { programAddress?: TProgramAddress },
 ): SetDataInstruction<
     TProgramAddress,
@@ -173,7 +213,9 @@ export function getSetDataInstruction<
     TAccountAuthority,
     TAccountBuffer,
     TAccountProgram,
-    TAccountProgramData
+    TAccountProgramData,
+    TAccountDestination,
+    TAccountExternal
 > {
     // Program address.
     const programAddress = config?.programAddress ?? PROGRAM_METADATA_PROGRAM_ADDRESS;
@@ -185,6 +227,8 @@ export function getSetDataInstruction<
         buffer: { value: input.buffer ?? null, isWritable: true },
         program: { value: input.program ?? null, isWritable: false },
         programData: { value: input.programData ?? null, isWritable: false },
+        destination: { value: input.destination ?? null, isWritable: true },
+        external: { value: input.external ?? null, isWritable: false },
     };
     const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
@@ -199,6 +243,8 @@ export function getSetDataInstruction<
             getAccountMeta('buffer', accounts.buffer),
             getAccountMeta('program', accounts.program),
             getAccountMeta('programData', accounts.programData),
+            getAccountMeta('destination', accounts.destination),
+            getAccountMeta('external', accounts.external),
         ],
         data: getSetDataInstructionDataEncoder().encode(args as SetDataInstructionDataArgs),
         programAddress,
@@ -208,7 +254,9 @@ export function getSetDataInstruction<
         TAccountAuthority,
         TAccountBuffer,
         TAccountProgram,
-        TAccountProgramData
+        TAccountProgramData,
+        TAccountDestination,
+        TAccountExternal
     >);
 }
 
@@ -228,6 +276,16 @@ export type ParsedSetDataInstruction<
         program?: TAccountMetas[3] | undefined;
         /** Program data account. */
         programData?: TAccountMetas[4] | undefined;
+        /**
+         * Destination account for the excess lamports of the metadata account.
+         * When provided, the buffer account (if any) is also closed after its data is copied.
+         */
+        destination?: TAccountMetas[5] | undefined;
+        /**
+         * External account referenced by the data.
+         * Required for external data that records an expected owner and content digest.
+         */
+        external?: TAccountMetas[6] | undefined;
     };
     data: SetDataInstructionData;
 };
@@ -237,10 +295,10 @@ export function parseSetDataInstruction<TProgram extends string, TAccountMetas e
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>,
 ): ParsedSetDataInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 5) {
+    if (instruction.accounts.length < 7) {
         throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, {
             actualAccountMetas: instruction.accounts.length,
-            expectedAccountMetas: 5,
+            expectedAccountMetas: 7,
         });
     }
     let accountIndex = 0;
@@ -261,6 +319,8 @@ export function parseSetDataInstruction<TProgram extends string, TAccountMetas e
             buffer: getNextOptionalAccount(),
             program: getNextOptionalAccount(),
             programData: getNextOptionalAccount(),
+            destination: getNextOptionalAccount(),
+            external: getNextOptionalAccount(),
         },
         data: getSetDataInstructionDataDecoder().decode(instruction.data),
     };
@@ -38,6 +38,7 @@ import {
     type ReadonlyUint8Array,
     type TransactionSigner,
     type WritableAccount,
+    type WritableSignerAccount,
 } from '@solana/kit';
 import { getAccountMetaFactory, type ResolvedInstructionAccount } from '@solana/kit/program-client-core';
 import { PROGRAM_METADATA_PROGRAM_ADDRESS } from '../programs';
@@ -53,6 +54,8 @@ export type WriteInstruction<
     TAccountBuffer extends string | AccountMeta<string> = string,
     TAccountAuthority extends string | AccountMeta<string> = string,
     TAccountSourceBuffer extends string | AccountMeta<string> = string,
+    TAccountPayer extends string | AccountMeta<string> = string,
+    TAccountSystem extends string | AccountMeta<string> = '11111111111111111111111111111111',
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -63,6 +66,10 @@ export type WriteInstruction<
                 ? ReadonlySignerAccount<TAccountAuthority> & AccountSignerMeta<TAccountAuthority>
                 : TAccountAuthority,
             TAccountSourceBuffer extends string ? ReadonlyAccount<TAccountSourceBuffer> : TAccountSourceBuffer,
+            TAccountPayer extends string
+                ? WritableSignerAccount<TAccountPayer> & AccountSignerMeta<TAccountPayer>
+                : TAccountPayer,
+            TAccountSystem extends string ? ReadonlyAccount<TAccountSystem> : TAccountSystem,
             ...TRemainingAccounts,
         ]
     >;
@@ -74,6 +81,7 @@ export type WriteInstructionData = {
     /**
      * The data to write at the provided offset.
      * You may use the `source_buffer` account instead of this argument to copy from an existing buffer.
+     * When using the `source_buffer` account, this argument may hold the `u32` offset and `u32` length of the range to copy.
      */
     data: Option<ReadonlyUint8Array>;
 };
@@ -84,6 +92,7 @@ export type WriteInstructionDataArgs = {
     /**
      * The data to write at the provided offset.
      * You may use the `source_buffer` account instead of this argument to copy from an existing buffer.
+     * When using the `source_buffer` account, this argument may hold the `u32` offset and `u32` length of the range to copy.
      */
     data?: OptionOrNullable<ReadonlyUint8Array>;
 };
@@ -115,16 +124,22 @@ export type WriteInput<
     TAccountBuffer extends string = string,
     TAccountAuthority extends string = string,
     TAccountSourceBuffer extends string = string,
+    TAccountPayer extends string = string,
+    TAccountSystem extends string = string,
 > = {
     /** The buffer to write to. */
     buffer: Address<TAccountBuffer>;
     /** The authority of the buffer. */
     authority: TransactionSigner<TAccountAuthority>;
     /**
-     * Buffer to copy the data from.
+     * Buffer or metadata account to copy the data from.
      * You may use the `data` argument instead of this account to pass data directly.
      */
     sourceBuffer?: Address<TAccountSourceBuffer>;
+    /** Payer account to fund the additional rent of the account. */
+    payer?: TransactionSigner<TAccountPayer>;
+    /** System program. */
+    system?: Address<TAccountSystem>;
     offset: WriteInstructionDataArgs['offset'];
     data?: WriteInstructionDataArgs['data'];
 };
@@ -133,11 +148,20 @@ export function getWriteInstruction<
     TAccountBuffer extends string,
     TAccountAuthority extends string,
     TAccountSourceBuffer extends string,
+    TAccountPayer extends string,
+    TAccountSystem extends string,
     TProgramAddress extends Address = typeof PROGRAM_METADATA_PROGRAM_ADDRESS,
 >(
-    input: WriteInput<TAccountBuffer, TAccountAuthority, TAccountSourceBuffer>,
+    input: WriteInput<TAccountBuffer, TAccountAuthority, TAccountSourceBuffer, TAccountPayer, TAccountSystem>,
     config?: { programAddress?: TProgramAddress },
-): WriteInstruction<TProgramAddress, TAccountBuffer, TAccountAuthority, TAccountSourceBuffer> {
+): WriteInstruction<
+    TProgramAddress,
+    TAccountBuffer,
+    TAccountAuthority,
+    TAccountSourceBuffer,
+    TAccountPayer,
+    TAccountSystem
+> {
     // Program address.
     const programAddress = config?.programAddress ?? PROGRAM_METADATA_PROGRAM_ADDRESS;
 
@@ -146,22 +170,38 @@ export function getWriteInstruction<
         buffer: { value: input.buffer ?? null, isWritable: true },
         authority: { value: input.authority ?? null, isWritable: false },
         sourceBuffer: { value: input.sourceBuffer ?? null, isWritable: false },
+        payer: { value: input.payer ?? null, isWritable: true },
+        system: { value: input.system ?? null, isWritable: false },
     };
     const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
     // Original args.
     const args = { ...input };
 
+    // Resolve default values.
+    if (!accounts.system.value) {
+        accounts.system.value = '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+    }
+
     const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
     return Object.freeze({
         accounts: [
             getAccountMeta('buffer', accounts.buffer),
             getAccountMeta('authority', accounts.authority),
             getAccountMeta('sourceBuffer', accounts.sourceBuffer),
+            getAccountMeta('payer', accounts.payer),
+            getAccountMeta('system', accounts.system),
         ],
         data: getWriteInstructionDataEncoder().encode(args as WriteInstructionDataArgs),
         programAddress,
-    } as WriteInstruction<TProgramAddress, TAccountBuffer, TAccountAuthority, TAccountSourceBuffer>);
+    } as WriteInstruction<
+        TProgramAddress,
+        TAccountBuffer,
+        TAccountAuthority,
+        TAccountSourceBuffer,
+        TAccountPayer,
+        TAccountSystem
+    >);
 }
 
 export type ParsedWriteInstruction<
@@ -175,10 +215,14 @@ export type ParsedWriteInstruction<
         /** The authority of the buffer. */
         authority: TAccountMetas[1];
         /**
-         * Buffer to copy the data from.
+         * Buffer or metadata account to copy the data from.
          * You may use the `data` argument instead of this account to pass data directly.
          */
         sourceBuffer?: TAccountMetas[2] | undefined;
+        /** Payer account to fund the additional rent of the account. */
+        payer?: TAccountMetas[3] | undefined;
+        /** System program. */
+        system?: TAccountMetas[4] | undefined;
     };
     data: WriteInstructionData;
 };
@@ -188,10 +232,10 @@ export function parseWriteInstruction<TProgram extends string, TAccountMetas ext
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>,
 ): ParsedWriteInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 3) {
+    if (instruction.accounts.length < 5) {
         throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, {
             actualAccountMetas: instruction.accounts.length,
-            expectedAccountMetas: 3,
+            expectedAccountMetas: 5,
         });
     }
     let accountIndex = 0;
@@ -206,7 +250,13 @@ export function parseWriteInstruction<TProgram extends string, TAccountMetas ext
     };
     return {
         programAddress: instruction.programAddress,
-        accounts: { buffer: getNextAccount(), authority: getNextAccount(), sourceBuffer: getNextOptionalAccount() },
+        accounts: {
+            buffer: getNextAccount(),
+            authority: getNextAccount(),
+            sourceBuffer: getNextOptionalAccount(),
+            payer: getNextOptionalAccount(),
+            system: getNextOptionalAccount(),
+        },
         data: getWriteInstructionDataDecoder().decode(instruction.data),
     };
 }
@@ -9,3 +9,4 @@
 export * from './canonical';
 export * from './metadata';
 export * from './nonCanonical';
+export * from './pendingAuthority';
@@ -34,50 +34,101 @@ import {
 import {
     getBufferCodec,
     getMetadataCodec,
+    getPendingAuthorityCodec,
     type Buffer,
     type BufferArgs,
     type Metadata,
     type MetadataArgs,
+    type PendingAuthority,
+    type PendingAuthorityArgs,
 } from '../accounts';
 import {
+    getAcceptAuthorityInstructionAsync,
     getAllocateInstruction,
+    getCancelAuthorityInstructionAsync,
     getCloseInstruction,
     getExtendInstruction,
     getInitializeInstructionAsync,
+    getInitializeWithSeedInstruction,
+    getMigrateInstruction,
+    getMoveInstructionAsync,
+    getPatchInstruction,
+    getPromoteInstruction,
+    getProposeAuthorityInstructionAsync,
     getSetAuthorityInstruction,
+    getSetDataCheckedInstruction,
     getSetDataInstruction,
+    getSetExtensionInstruction,
+    getSetHashInstruction,
     getSetImmutableInstruction,
     getTrimInstruction,
+    getVerifyHashInstruction,
     getWriteInstruction,
+    parseAcceptAuthorityInstruction,
     parseAllocateInstruction,
+    parseCancelAuthorityInstruction,
     parseCloseInstruction,
     parseExtendInstruction,
     parseInitializeInstruction,
+    parseInitializeWithSeedInstruction,
+    parseMigrateInstruction,
+    parseMoveInstruction,
+    parsePatchInstruction,
+    parsePromoteInstruction,
+    parseProposeAuthorityInstruction,
     parseSetAuthorityInstruction,
+    parseSetDataCheckedInstruction,
     parseSetDataInstruction,
+    parseSetExtensionInstruction,
+    parseSetHashInstruction,
     parseSetImmutableInstruction,
     parseTrimInstruction,
+    parseVerifyHashInstruction,
     parseWriteInstruction,
+    type AcceptAuthorityAsyncInput,
     type AllocateInput,
+    type CancelAuthorityAsyncInput,
     type CloseInput,
     type ExtendInput,
     type InitializeAsyncInput,
+    type InitializeWithSeedInput,
+    type MigrateInput,
+    type MoveAsyncInput,
+    type ParsedAcceptAuthorityInstruction,
     type ParsedAllocateInstruction,
+    type ParsedCancelAuthorityInstruction,
     type ParsedCloseInstruction,
     type ParsedExtendInstruction,
     type ParsedInitializeInstruction,
+    type ParsedInitializeWithSeedInstruction,
+    type ParsedMigrateInstruction,
+    type ParsedMoveInstruction,
+    type ParsedPatchInstruction,
+    type ParsedPromoteInstruction,
+    type ParsedProposeAuthorityInstruction,
     type ParsedSetAuthorityInstruction,
+    type ParsedSetDataCheckedInstruction,
     type ParsedSetDataInstruction,
+    type ParsedSetExtensionInstruction,
+    type ParsedSetHashInstruction,
     type ParsedSetImmutableInstruction,
     type ParsedTrimInstruction,
+    type ParsedVerifyHashInstruction,
     type ParsedWriteInstruction,
+    type PatchInput,
+    type PromoteInput,
+    type ProposeAuthorityAsyncInput,
     type SetAuthorityInput,
+    type SetDataCheckedInput,
     type SetDataInput,
+    type SetExtensionInput,
+    type SetHashInput,
     type SetImmutableInput,
     type TrimInput,
+    type VerifyHashInput,
     type WriteInput,
 } from '../instructions';
-import { findCanonicalPda, findMetadataPda, findNonCanonicalPda } from '../pdas';
+import { findCanonicalPda, findMetadataPda, findNonCanonicalPda, findPendingAuthorityPda } from '../pdas';
 
 export const PROGRAM_METADATA_PROGRAM_ADDRESS =
     'ProgM6JCCvbYkfKqJYHePx4xxSUSqJp7rh8Lyv7nk7S' as Address<'ProgM6JCCvbYkfKqJYHePx4xxSUSqJp7rh8Lyv7nk7S'>;
@@ -85,6 +136,7 @@ export const PROGRAM_METADATA_PROGRAM_ADDRESS =
 export enum ProgramMetadataAccount {
     Buffer,
     Metadata,
+    PendingAuthority,
 }
 
 export enum ProgramMetadataInstruction {
@@ -97,6 +149,18 @@ export enum ProgramMetadataInstruction {
     Close,
     Allocate,
     Extend,
+    ProposeAuthority,
+    AcceptAuthority,
+    CancelAuthority,
+    SetHash,
+    VerifyHash,
+    SetDataChecked,
+    SetExtension,
+    Migrate,
+    Patch,
+    Promote,
+    Move,
+    InitializeWithSeed,
 }
 
 export function identifyProgramMetadataInstruction(
@@ -130,6 +194,42 @@ export function identifyProgramMetadataInstruction(
     if (containsBytes(data, getU8Encoder().encode(8), 0)) {
         return ProgramMetadataInstruction.Extend;
     }
+    if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+        return ProgramMetadataInstruction.ProposeAuthority;
+    }
+    if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+        return ProgramMetadataInstruction.AcceptAuthority;
+    }
+    if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+        return ProgramMetadataInstruction.CancelAuthority;
+    }
+    if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+        return ProgramMetadataInstruction.SetHash;
+    }
+    if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+        return ProgramMetadataInstruction.VerifyHash;
+    }
+    if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+        return ProgramMetadataInstruction.SetDataChecked;
+    }
+    if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+        return ProgramMetadataInstruction.SetExtension;
+    }
+    if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+        return ProgramMetadataInstruction.Migrate;
+    }
+    if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+        return ProgramMetadataInstruction.Patch;
+    }
+    if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+        return ProgramMetadataInstruction.Promote;
+    }
+    if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+        return ProgramMetadataInstruction.Move;
+    }
+    if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+        return ProgramMetadataInstruction.InitializeWithSeed;
+    }
     throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_INSTRUCTION, {
         instructionData: data,
         programName: 'programMetadata',
@@ -145,7 +245,20 @@ export type ParsedProgramMetadataInstruction<TProgram extends string = 'ProgM6JC
     | ({ instructionType: ProgramMetadataInstruction.Trim } & ParsedTrimInstruction<TProgram>)
     | ({ instructionType: ProgramMetadataInstruction.Close } & ParsedCloseInstruction<TProgram>)
     | ({ instructionType: ProgramMetadataInstruction.Allocate } & ParsedAllocateInstruction<TProgram>)
-    | ({ instructionType: ProgramMetadataInstruction.Extend } & ParsedExtendInstruction<TProgram>);
+    | ({ instructionType: ProgramMetadataInstruction.Extend } & ParsedExtendInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.ProposeAuthority } & ParsedProposeAuthorityInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.AcceptAuthority } & ParsedAcceptAuthorityInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.CancelAuthority } & ParsedCancelAuthorityInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.SetHash } & ParsedSetHashInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.VerifyHash } & ParsedVerifyHashInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.SetDataChecked } & ParsedSetDataCheckedInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.SetExtension } & ParsedSetExtensionInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.Migrate } & ParsedMigrateInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.Patch } & ParsedPatchInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.Promote } & ParsedPromoteInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.Move } & ParsedMoveInstruction<TProgram>)
+    | ({ instructionType: ProgramMetadataInstruction.InitializeWithSeed } &
+        ParsedInitializeWithSeedInstruction<TProgram>);
 
 export function parseProgramMetadataInstruction<TProgram extends string>(
     instruction: Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array>,
@@ -197,6 +310,75 @@ export function parseProgramMetadataInstruction<TProgram extends string>(
             assertIsInstructionWithAccounts(instruction);
             return { instructionType: ProgramMetadataInstruction.Extend, ...parseExtendInstruction(instruction) };
         }
+        case ProgramMetadataInstruction.ProposeAuthority: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.ProposeAuthority,
+                ...parseProposeAuthorityInstruction(instruction),
+            };
+        }
+        case ProgramMetadataInstruction.AcceptAuthority: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.AcceptAuthority,
+                ...parseAcceptAuthorityInstruction(instruction),
+            };
+        }
+        case ProgramMetadataInstruction.CancelAuthority: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.CancelAuthority,
+                ...parseCancelAuthorityInstruction(instruction),
+            };
+        }
+        case ProgramMetadataInstruction.SetHash: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: ProgramMetadataInstruction.SetHash, ...parseSetHashInstruction(instruction) };
+        }
+        case ProgramMetadataInstruction.VerifyHash: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.VerifyHash,
+                ...parseVerifyHashInstruction(instruction),
+            };
+        }
+        case ProgramMetadataInstruction.SetDataChecked: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.SetDataChecked,
+                ...parseSetDataCheckedInstruction(instruction),
+            };
+        }
+        case ProgramMetadataInstruction.SetExtension: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.SetExtension,
+                ...parseSetExtensionInstruction(instruction),
+            };
+        }
+        case ProgramMetadataInstruction.Migrate: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: ProgramMetadataInstruction.Migrate, ...parseMigrateInstruction(instruction) };
+        }
+        case ProgramMetadataInstruction.Patch: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: ProgramMetadataInstruction.Patch, ...parsePatchInstruction(instruction) };
+        }
+        case ProgramMetadataInstruction.Promote: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: ProgramMetadataInstruction.Promote, ...parsePromoteInstruction(instruction) };
+        }
+        case ProgramMetadataInstruction.Move: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: ProgramMetadataInstruction.Move, ...parseMoveInstruction(instruction) };
+        }
+        case ProgramMetadataInstruction.InitializeWithSeed: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: ProgramMetadataInstruction.InitializeWithSeed,
+                ...parseInitializeWithSeedInstruction(instruction),
+            };
+        }
         default:
             throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__UNRECOGNIZED_INSTRUCTION_TYPE, {
                 instructionType: instructionType as string,
@@ -216,6 +398,8 @@ export type ProgramMetadataPlugin = {
 export type ProgramMetadataPluginAccounts = {
     buffer: ReturnType<typeof getBufferCodec> & SelfFetchFunctions<BufferArgs, Buffer>;
     metadata: ReturnType<typeof getMetadataCodec> & SelfFetchFunctions<MetadataArgs, Metadata>;
+    pendingAuthority: ReturnType<typeof getPendingAuthorityCodec> &
+        SelfFetchFunctions<PendingAuthorityArgs, PendingAuthority>;
 };
 
 export type ProgramMetadataPluginInstructions = {
@@ -234,12 +418,37 @@ export type ProgramMetadataPluginInstructions = {
     close: (input: CloseInput) => ReturnType<typeof getCloseInstruction> & SelfPlanAndSendFunctions;
     allocate: (input: AllocateInput) => ReturnType<typeof getAllocateInstruction> & SelfPlanAndSendFunctions;
     extend: (input: ExtendInput) => ReturnType<typeof getExtendInstruction> & SelfPlanAndSendFunctions;
+    proposeAuthority: (
+        input: ProposeAuthorityAsyncInput,
+    ) => ReturnType<typeof getProposeAuthorityInstructionAsync> & SelfPlanAndSendFunctions;
+    acceptAuthority: (
+        input: AcceptAuthorityAsyncInput,
+    ) => ReturnType<typeof getAcceptAuthorityInstructionAsync> & SelfPlanAndSendFunctions;
+    cancelAuthority: (
+        input: CancelAuthorityAsyncInput,
+    ) => ReturnType<typeof getCancelAuthorityInstructionAsync> & SelfPlanAndSendFunctions;
+    setHash: (input: SetHashInput) => ReturnType<typeof getSetHashInstruction> & SelfPlanAndSendFunctions;
+    verifyHash: (input: VerifyHashInput) => ReturnType<typeof getVerifyHashInstruction> & SelfPlanAndSendFunctions;
+    setDataChecked: (
+        input: SetDataCheckedInput,
+    ) => ReturnType<typeof getSetDataCheckedInstruction> & SelfPlanAndSendFunctions;
+    setExtension: (
+        input: SetExtensionInput,
+    ) => ReturnType<typeof getSetExtensionInstruction> & SelfPlanAndSendFunctions;
+    migrate: (input: MigrateInput) => ReturnType<typeof getMigrateInstruction> & SelfPlanAndSendFunctions;
+    patch: (input: PatchInput) => ReturnType<typeof getPatchInstruction> & SelfPlanAndSendFunctions;
+    promote: (input: PromoteInput) => ReturnType<typeof getPromoteInstruction> & SelfPlanAndSendFunctions;
+    move: (input: MoveAsyncInput) => ReturnType<typeof getMoveInstructionAsync> & SelfPlanAndSendFunctions;
+    initializeWithSeed: (
+        input: InitializeWithSeedInput,
+    ) => ReturnType<typeof getInitializeWithSeedInstruction> & SelfPlanAndSendFunctions;
 };
 
 export type ProgramMetadataPluginPdas = {
     canonical: typeof findCanonicalPda;
     nonCanonical: typeof findNonCanonicalPda;
     metadata: typeof findMetadataPda;
+    pendingAuthority: typeof findPendingAuthorityPda;
 };
 
 export type ProgramMetadataPluginRequirements = ClientWithRpc<GetAccountInfoApi & GetMultipleAccountsApi> &
@@ -255,6 +464,7 @@ export function programMetadataProgram() {
                 accounts: {
                     buffer: addSelfFetchFunctions(client, getBufferCodec()),
                     metadata: addSelfFetchFunctions(client, getMetadataCodec()),
+                    pendingAuthority: addSelfFetchFunctions(client, getPendingAuthorityCodec()),
                 },
                 instructions: {
                     write: input => addSelfPlanAndSendFunctions(client, getWriteInstruction(input)),
@@ -266,8 +476,29 @@ export function programMetadataProgram() {
                     close: input => addSelfPlanAndSendFunctions(client, getCloseInstruction(input)),
                     allocate: input => addSelfPlanAndSendFunctions(client, getAllocateInstruction(input)),
                     extend: input => addSelfPlanAndSendFunctions(client, getExtendInstruction(input)),
+                    proposeAuthority: input =>
+                        addSelfPlanAndSendFunctions(client, getProposeAuthorityInstructionAsync(input)),
+                    acceptAuthority: input =>
+                        addSelfPlanAndSendFunctions(client, getAcceptAuthorityInstructionAsync(input)),
+                    cancelAuthority: input =>
+                        addSelfPlanAndSendFunctions(client, getCancelAuthorityInstructionAsync(input)),
+                    setHash: input => addSelfPlanAndSendFunctions(client, getSetHashInstruction(input)),
+                    verifyHash: input => addSelfPlanAndSendFunctions(client, getVerifyHashInstruction(input)),
+                    setDataChecked: input => addSelfPlanAndSendFunctions(client, getSetDataCheckedInstruction(input)),
+                    setExtension: input => addSelfPlanAndSendFunctions(client, getSetExtensionInstruction(input)),
+                    migrate: input => addSelfPlanAndSendFunctions(client, getMigrateInstruction(input)),
+                    patch: input => addSelfPlanAndSendFunctions(client, getPatchInstruction(input)),
+                    promote: input => addSelfPlanAndSendFunctions(client, getPromoteInstruction(input)),
+                    move: input => addSelfPlanAndSendFunctions(client, getMoveInstructionAsync(input)),
+                    initializeWithSeed: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeWithSeedInstruction(input)),
+                },
+                pdas: {
+                    canonical: findCanonicalPda,
+                    nonCanonical: findNonCanonicalPda,
+                    metadata: findMetadataPda,
+                    pendingAuthority: findPendingAuthorityPda,
                 },
-                pdas: { canonical: findCanonicalPda, nonCanonical: findNonCanonicalPda, metadata: findMetadataPda },
                 identifyInstruction: identifyProgramMetadataInstruction,
                 parseInstruction: parseProgramMetadataInstruction,
             },
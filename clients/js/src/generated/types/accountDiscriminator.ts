@@ -19,6 +19,7 @@ export enum AccountDiscriminator {
     Empty,
     Buffer,
     Metadata,
+    PendingAuthority,
 }
 
 export type AccountDiscriminatorArgs = AccountDiscriminator;
@@ -19,6 +19,9 @@ export enum Compression {
     None,
     Gzip,
     Zlib,
+    Zstd,
+    Brotli,
+    Lz4,
 }
 
 export type CompressionArgs = Compression;
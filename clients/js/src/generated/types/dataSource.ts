@@ -19,6 +19,9 @@ export enum DataSource {
     Direct,
     Url,
     External,
+    ContentAddressed,
+    Chunked,
+    Alias,
 }
 
 export type DataSourceArgs = DataSource;
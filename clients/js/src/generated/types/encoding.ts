@@ -20,6 +20,7 @@ export enum Encoding {
     Utf8,
     Base58,
     Base64,
+    Base16,
 }
 
 export type EncodingArgs = Encoding;
@@ -20,6 +20,10 @@ export enum Format {
     Json,
     Yaml,
     Toml,
+    Borsh,
+    Protobuf,
+    Cbor,
+    MessagePack,
 }
 
 export type FormatArgs = Format;
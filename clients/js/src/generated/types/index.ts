@@ -7,9 +7,17 @@
  */
 
 export * from './accountDiscriminator';
+export * from './aliasData';
+export * from './chunkData';
 export * from './compression';
+export * from './contentData';
+export * from './contentProtocol';
+export * from './dataExpectation';
 export * from './dataSource';
 export * from './encoding';
 export * from './externalData';
+export * from './externalDataV2';
 export * from './format';
+export * from './hash';
 export * from './seed';
+export * from './variableSeed';
@@ -218,6 +218,8 @@ export async function unpackAndFetchAllData({
                         unpackedExternalData: unpackedExternalAccounts[accountIndex].unpacked,
                     });
                 }
+                default:
+                    throw new Error('Unsupported data source');
             }
         }),
     );
@@ -231,6 +233,8 @@ export function compressData(data: ReadonlyUint8Array, compression: Compression)
             return gzip(data as Uint8Array);
         case Compression.Zlib:
             return deflate(data as Uint8Array);
+        default:
+            throw new Error('Unsupported compression');
     }
 }
 
@@ -242,6 +246,8 @@ export function uncompressData(data: ReadonlyUint8Array, compression: Compressio
             return ungzip(data as Uint8Array);
         case Compression.Zlib:
             return inflate(data as Uint8Array);
+        default:
+            throw new Error('Unsupported compression');
     }
 }
 
@@ -255,6 +261,8 @@ export function encodeData(data: string, encoding: Encoding): ReadonlyUint8Array
             return getBase58Encoder().encode(data);
         case Encoding.Base64:
             return getBase64Encoder().encode(data);
+        case Encoding.Base16:
+            return getBase16Encoder().encode(data);
     }
 }
 
@@ -268,5 +276,7 @@ export function decodeData(data: ReadonlyUint8Array, encoding: Encoding): string
             return getBase58Decoder().decode(data);
         case Encoding.Base64:
             return getBase64Decoder().decode(data);
+        case Encoding.Base16:
+            return getBase16Decoder().decode(data);
     }
 }
@@ -9,6 +9,8 @@ import {
     generateKeyPairSigner,
     GetAccountInfoApi,
     InstructionPlan,
+    isOption,
+    isSome,
     lamports,
     Lamports,
     ReadonlyUint8Array,
@@ -20,6 +22,7 @@ import {
 import { getCreateBufferInstructionPlan } from './createBuffer';
 import {
     Buffer,
+    DataSource,
     fetchBuffer,
     fetchMetadata,
     getCloseInstruction,
@@ -114,7 +117,7 @@ export async function getUpdateMetadataInstructionPlanUsingInstructionData(
         payer: TransactionSigner;
     },
 ) {
-    const sizeDifference = BigInt(input.data.length) - BigInt(input.metadata.data.data.length);
+    const sizeDifference = getSizeDifference(input.metadata.data, input, input.data.length);
     const extraRent = await getExtraRent(client, sizeDifference);
     return sequentialInstructionPlan([
         ...(sizeDifference > 0
@@ -151,7 +154,7 @@ export async function getUpdateMetadataInstructionPlanUsingNewBuffer(
         payer: TransactionSigner;
     },
 ) {
-    const sizeDifference = BigInt(input.data.length) - BigInt(input.metadata.data.data.length);
+    const sizeDifference = getSizeDifference(input.metadata.data, input, input.data.length);
     const extraRent = await getExtraRent(client, sizeDifference);
     return sequentialInstructionPlan([
         ...(sizeDifference > 0
@@ -219,7 +222,7 @@ export async function getUpdateMetadataInstructionPlanUsingExistingBuffer(
         payer: TransactionSigner;
     },
 ) {
-    const sizeDifference = BigInt(input.dataLength) - BigInt(input.metadata.data.data.length);
+    const sizeDifference = getSizeDifference(input.metadata.data, input, input.dataLength);
     const extraRent = await getExtraRent(client, sizeDifference);
     return sequentialInstructionPlan([
         ...(sizeDifference > 0
@@ -275,3 +278,45 @@ async function getExtraRent(client: ClientWithGetMinimumBalance, sizeDifference:
     if (sizeDifference <= 0) return lamports(0n);
     return await client.getMinimumBalance(Number(sizeDifference), { withoutHeader: true });
 }
+
+/** Type of the extension holding the revision of a metadata account. */
+const REVISION_EXTENSION_TYPE = 0xfffe;
+
+/**
+ * Returns the difference between the size of the metadata account after setting
+ * data of the given length and its current size.
+ *
+ * Setting new data keeps the content hash when a new hash is provided or when an
+ * existing hash is recomputed for `direct` data, and removes it otherwise. It also
+ * adds the revision extension to the extension area when the account does not
+ * have one yet.
+ */
+function getSizeDifference(
+    metadata: Metadata,
+    input: Pick<SetDataInput, 'dataSource' | 'hash'>,
+    dataLength: number,
+): bigint {
+    const hasHash = (metadata.flags & 0b01) !== 0;
+    const hasExtensions = (metadata.flags & 0b10) !== 0;
+    const recordsHash = input.hash != null && (!isOption(input.hash) || isSome(input.hash));
+    const keepsHash = recordsHash || (hasHash && input.dataSource === DataSource.Direct);
+    const hashLength = keepsHash ? 32 : 0;
+    const area = metadata.data.subarray(hasHash ? 32 : 0);
+    const view = new DataView(area.buffer, area.byteOffset, area.byteLength);
+
+    // Walk the extension area to find the revision extension and the used length.
+    const areaLength = hasExtensions && area.length >= 2 ? view.getUint16(0, true) : 0;
+    let offset = 2;
+    while (offset + 4 <= areaLength) {
+        const extensionType = view.getUint16(offset, true);
+        if (extensionType === 0) break;
+        if (extensionType === REVISION_EXTENSION_TYPE) {
+            return BigInt(hashLength + areaLength + dataLength) - BigInt(metadata.data.length);
+        }
+        offset += 4 + view.getUint16(offset + 2, true);
+    }
+
+    // The revision extension holds a `u64` value and the area is padded to 8 bytes.
+    const newAreaLength = Math.ceil((offset + 4 + 8) / 8) * 8;
+    return BigInt(hashLength + newAreaLength + dataLength) - BigInt(metadata.data.length);
+}
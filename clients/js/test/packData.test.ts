@@ -0,0 +1,44 @@
+import { getUtf8Encoder } from '@solana/kit';
+import { expect, test } from 'vitest';
+
+import { Compression, decodeData, encodeData, Encoding, packDirectData, unpackDirectData } from '../src';
+
+test('it encodes and decodes base16 data', () => {
+    // Given the following hex content.
+    const content = 'deadbeef00ff';
+
+    // When we encode it using the Base16 encoding.
+    const data = encodeData(content, Encoding.Base16);
+
+    // Then we expect the raw bytes to be stored.
+    expect(data).toEqual(new Uint8Array([0xde, 0xad, 0xbe, 0xef, 0x00, 0xff]));
+
+    // And decoding these bytes to return the original content.
+    expect(decodeData(data, Encoding.Base16)).toBe(content);
+});
+
+test('it packs and unpacks base16 direct data', () => {
+    // Given the following hex content.
+    const content = '48656c6c6f2c20576f726c6421';
+
+    // When we pack it using the Base16 encoding and Zlib compression.
+    const packed = packDirectData({ content, compression: Compression.Zlib, encoding: Encoding.Base16 });
+
+    // Then we expect to get the original content back when unpacking it.
+    expect(packed.encoding).toBe(Encoding.Base16);
+    expect(unpackDirectData(packed)).toBe(content);
+});
+
+test('it round-trips content with every encoding', () => {
+    // Given the same bytes represented with each encoding.
+    const bytes = getUtf8Encoder().encode('Hello, World!');
+    const encodings = [Encoding.None, Encoding.Utf8, Encoding.Base58, Encoding.Base64, Encoding.Base16];
+
+    for (const encoding of encodings) {
+        // When we decode the bytes into a string and encode it back.
+        const content = decodeData(bytes, encoding);
+
+        // Then we expect to get the original bytes.
+        expect(encodeData(content, encoding)).toEqual(bytes);
+    }
+});
@@ -19,6 +19,8 @@ pub struct Buffer {
     pub authority: ZeroableOptionPubkey,
     pub canonical: bool,
     pub seed: Seed,
+    pub version: u8,
+    pub seed_length: u8,
     pub data: TrailingVec<u8>,
 }
 
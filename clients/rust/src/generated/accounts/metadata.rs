@@ -30,6 +30,10 @@ pub struct Metadata {
     pub format: Format,
     pub data_source: DataSource,
     pub data_length: u32,
+    pub flags: u8,
+    pub reserved: [u8; 2],
+    pub version: u8,
+    pub seed_length: u8,
     pub data: TrailingVec<u8>,
 }
 
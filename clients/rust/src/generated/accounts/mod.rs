@@ -7,6 +7,8 @@
 
 pub(crate) mod r#buffer;
 pub(crate) mod r#metadata;
+pub(crate) mod r#pending_authority;
 
 pub use self::r#buffer::*;
 pub use self::r#metadata::*;
+pub use self::r#pending_authority::*;
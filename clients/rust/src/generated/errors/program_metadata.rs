@@ -25,6 +25,15 @@ pub enum ProgramMetadataError {
     /// 4 - The account data length is invalid
     #[error("The account data length is invalid")]
     InvalidDataLength = 0x4,
+    /// 5 - The metadata account content hash does not match
+    #[error("The metadata account content hash does not match")]
+    HashMismatch = 0x5,
+    /// 6 - The metadata account has changed since it was read
+    #[error("The metadata account has changed since it was read")]
+    MetadataChanged = 0x6,
+    /// 7 - The account layout version is not supported
+    #[error("The account layout version is not supported")]
+    UnsupportedAccountVersion = 0x7,
 }
 
 impl From<ProgramMetadataError> for solana_program_error::ProgramError {
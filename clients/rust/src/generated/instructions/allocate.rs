@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::hooked::RemainderOptionCapacity;
 use crate::hooked::RemainderOptionSeed;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -109,6 +110,7 @@ impl Default for AllocateInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 pub struct AllocateInstructionArgs {
     pub seed: RemainderOptionSeed,
+    pub capacity: RemainderOptionCapacity,
 }
 
 impl AllocateInstructionArgs {
@@ -134,6 +136,7 @@ pub struct AllocateBuilder {
     program_data: Option<solana_address::Address>,
     system: Option<solana_address::Address>,
     seed: Option<RemainderOptionSeed>,
+    capacity: Option<RemainderOptionCapacity>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -179,6 +182,11 @@ impl AllocateBuilder {
         self.seed = Some(seed);
         self
     }
+    #[inline(always)]
+    pub fn capacity(&mut self, capacity: RemainderOptionCapacity) -> &mut Self {
+        self.capacity = Some(capacity);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -205,6 +213,7 @@ impl AllocateBuilder {
         };
         let args = AllocateInstructionArgs {
             seed: self.seed.clone().expect("seed is not set"),
+            capacity: self.capacity.clone().expect("capacity is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -389,6 +398,7 @@ impl<'a, 'b> AllocateCpiBuilder<'a, 'b> {
             program_data: None,
             system: None,
             seed: None,
+            capacity: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -440,6 +450,11 @@ impl<'a, 'b> AllocateCpiBuilder<'a, 'b> {
         self.instruction.seed = Some(seed);
         self
     }
+    #[inline(always)]
+    pub fn capacity(&mut self, capacity: RemainderOptionCapacity) -> &mut Self {
+        self.instruction.capacity = Some(capacity);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -476,6 +491,11 @@ impl<'a, 'b> AllocateCpiBuilder<'a, 'b> {
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = AllocateInstructionArgs {
             seed: self.instruction.seed.clone().expect("seed is not set"),
+            capacity: self
+                .instruction
+                .capacity
+                .clone()
+                .expect("capacity is not set"),
         };
         let instruction = AllocateCpi {
             __program: self.instruction.__program,
@@ -507,6 +527,7 @@ struct AllocateCpiBuilderInstruction<'a, 'b> {
     program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     system: Option<&'b solana_account_info::AccountInfo<'a>>,
     seed: Option<RemainderOptionSeed>,
+    capacity: Option<RemainderOptionCapacity>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -21,6 +21,10 @@ pub struct Extend {
     pub program: Option<solana_address::Address>,
     /// Program data account.
     pub program_data: Option<solana_address::Address>,
+    /// Payer account to fund the additional rent of the account.
+    pub payer: Option<solana_address::Address>,
+    /// System program.
+    pub system: Option<solana_address::Address>,
 }
 
 impl Extend {
@@ -34,7 +38,7 @@ impl Extend {
         args: ExtendInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.account, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
@@ -61,6 +65,22 @@ impl Extend {
                 false,
             ));
         }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(system) = self.system {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(system, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ExtendInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -97,7 +117,7 @@ impl Default for ExtendInstructionData {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 pub struct ExtendInstructionArgs {
-    pub length: u16,
+    pub length: u32,
 }
 
 impl ExtendInstructionArgs {
@@ -114,13 +134,17 @@ impl ExtendInstructionArgs {
 ///   1. `[signer]` authority
 ///   2. `[optional]` program
 ///   3. `[optional]` program_data
+///   4. `[writable, signer, optional]` payer
+///   5. `[optional]` system (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ExtendBuilder {
     account: Option<solana_address::Address>,
     authority: Option<solana_address::Address>,
     program: Option<solana_address::Address>,
     program_data: Option<solana_address::Address>,
-    length: Option<u16>,
+    payer: Option<solana_address::Address>,
+    system: Option<solana_address::Address>,
+    length: Option<u32>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -154,8 +178,22 @@ impl ExtendBuilder {
         self.program_data = program_data;
         self
     }
+    /// `[optional account]`
+    /// Payer account to fund the additional rent of the account.
     #[inline(always)]
-    pub fn length(&mut self, length: u16) -> &mut Self {
+    pub fn payer(&mut self, payer: Option<solana_address::Address>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// System program.
+    #[inline(always)]
+    pub fn system(&mut self, system: Option<solana_address::Address>) -> &mut Self {
+        self.system = system;
+        self
+    }
+    #[inline(always)]
+    pub fn length(&mut self, length: u32) -> &mut Self {
         self.length = Some(length);
         self
     }
@@ -181,6 +219,8 @@ impl ExtendBuilder {
             authority: self.authority.expect("authority is not set"),
             program: self.program,
             program_data: self.program_data,
+            payer: self.payer,
+            system: self.system,
         };
         let args = ExtendInstructionArgs {
             length: self.length.clone().expect("length is not set"),
@@ -200,6 +240,10 @@ pub struct ExtendCpiAccounts<'a, 'b> {
     pub program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Program data account.
     pub program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Payer account to fund the additional rent of the account.
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// System program.
+    pub system: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `extend` CPI instruction.
@@ -214,6 +258,10 @@ pub struct ExtendCpi<'a, 'b> {
     pub program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Program data account.
     pub program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Payer account to fund the additional rent of the account.
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// System program.
+    pub system: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ExtendInstructionArgs,
 }
@@ -230,6 +278,8 @@ impl<'a, 'b> ExtendCpi<'a, 'b> {
             authority: accounts.authority,
             program: accounts.program,
             program_data: accounts.program_data,
+            payer: accounts.payer,
+            system: accounts.system,
             __args: args,
         }
     }
@@ -256,7 +306,7 @@ impl<'a, 'b> ExtendCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.account.key,
             false,
@@ -287,6 +337,25 @@ impl<'a, 'b> ExtendCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(*payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(system) = self.system {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -303,7 +372,7 @@ impl<'a, 'b> ExtendCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.account.clone());
         account_infos.push(self.authority.clone());
@@ -313,6 +382,12 @@ impl<'a, 'b> ExtendCpi<'a, 'b> {
         if let Some(program_data) = self.program_data {
             account_infos.push(program_data.clone());
         }
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(system) = self.system {
+            account_infos.push(system.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -333,6 +408,8 @@ impl<'a, 'b> ExtendCpi<'a, 'b> {
 ///   1. `[signer]` authority
 ///   2. `[optional]` program
 ///   3. `[optional]` program_data
+///   4. `[writable, signer, optional]` payer
+///   5. `[optional]` system
 #[derive(Clone, Debug)]
 pub struct ExtendCpiBuilder<'a, 'b> {
     instruction: Box<ExtendCpiBuilderInstruction<'a, 'b>>,
@@ -346,6 +423,8 @@ impl<'a, 'b> ExtendCpiBuilder<'a, 'b> {
             authority: None,
             program: None,
             program_data: None,
+            payer: None,
+            system: None,
             length: None,
             __remaining_accounts: Vec::new(),
         });
@@ -383,8 +462,25 @@ impl<'a, 'b> ExtendCpiBuilder<'a, 'b> {
         self.instruction.program_data = program_data;
         self
     }
+    /// `[optional account]`
+    /// Payer account to fund the additional rent of the account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// System program.
+    #[inline(always)]
+    pub fn system(
+        &mut self,
+        system: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system = system;
+        self
+    }
     #[inline(always)]
-    pub fn length(&mut self, length: u16) -> &mut Self {
+    pub fn length(&mut self, length: u32) -> &mut Self {
         self.instruction.length = Some(length);
         self
     }
@@ -435,6 +531,10 @@ impl<'a, 'b> ExtendCpiBuilder<'a, 'b> {
             program: self.instruction.program,
 
             program_data: self.instruction.program_data,
+
+            payer: self.instruction.payer,
+
+            system: self.instruction.system,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -451,7 +551,9 @@ struct ExtendCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     program: Option<&'b solana_account_info::AccountInfo<'a>>,
     program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
-    length: Option<u16>,
+    payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system: Option<&'b solana_account_info::AccountInfo<'a>>,
+    length: Option<u32>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -9,6 +9,7 @@ use crate::generated::types::Compression;
 use crate::generated::types::DataSource;
 use crate::generated::types::Encoding;
 use crate::generated::types::Format;
+use crate::generated::types::Hash;
 use crate::generated::types::Seed;
 use crate::hooked::RemainderOptionBytes;
 use borsh::BorshDeserialize;
@@ -29,6 +30,15 @@ pub struct Initialize {
     pub program_data: Option<solana_address::Address>,
     /// System program.
     pub system: Option<solana_address::Address>,
+    /// Buffer account to copy the data from.
+    /// You may use the `data` argument instead of this account to pass data directly.
+    pub source_buffer: Option<solana_address::Address>,
+    /// Destination account for the lamports of the source buffer account.
+    /// When provided, the source buffer account is closed after its data is copied.
+    pub destination: Option<solana_address::Address>,
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    pub external: Option<solana_address::Address>,
 }
 
 impl Initialize {
@@ -42,7 +52,7 @@ impl Initialize {
         args: InitializeInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.metadata, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
@@ -71,6 +81,32 @@ impl Initialize {
                 false,
             ));
         }
+        if let Some(source_buffer) = self.source_buffer {
+            accounts.push(solana_instruction::AccountMeta::new(source_buffer, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_instruction::AccountMeta::new(destination, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(external) = self.external {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                external, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitializeInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -112,6 +148,7 @@ pub struct InitializeInstructionArgs {
     pub compression: Compression,
     pub format: Format,
     pub data_source: DataSource,
+    pub hash: Option<Hash>,
     pub data: RemainderOptionBytes,
 }
 
@@ -130,6 +167,9 @@ impl InitializeInstructionArgs {
 ///   2. `[]` program
 ///   3. `[optional]` program_data
 ///   4. `[optional]` system (default to `11111111111111111111111111111111`)
+///   5. `[writable, optional]` source_buffer
+///   6. `[writable, optional]` destination
+///   7. `[optional]` external
 #[derive(Clone, Debug, Default)]
 pub struct InitializeBuilder {
     metadata: Option<solana_address::Address>,
@@ -137,11 +177,15 @@ pub struct InitializeBuilder {
     program: Option<solana_address::Address>,
     program_data: Option<solana_address::Address>,
     system: Option<solana_address::Address>,
+    source_buffer: Option<solana_address::Address>,
+    destination: Option<solana_address::Address>,
+    external: Option<solana_address::Address>,
     seed: Option<Seed>,
     encoding: Option<Encoding>,
     compression: Option<Compression>,
     format: Option<Format>,
     data_source: Option<DataSource>,
+    hash: Option<Hash>,
     data: Option<RemainderOptionBytes>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -182,6 +226,30 @@ impl InitializeBuilder {
         self.system = system;
         self
     }
+    /// `[optional account]`
+    /// Buffer account to copy the data from.
+    /// You may use the `data` argument instead of this account to pass data directly.
+    #[inline(always)]
+    pub fn source_buffer(&mut self, source_buffer: Option<solana_address::Address>) -> &mut Self {
+        self.source_buffer = source_buffer;
+        self
+    }
+    /// `[optional account]`
+    /// Destination account for the lamports of the source buffer account.
+    /// When provided, the source buffer account is closed after its data is copied.
+    #[inline(always)]
+    pub fn destination(&mut self, destination: Option<solana_address::Address>) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    #[inline(always)]
+    pub fn external(&mut self, external: Option<solana_address::Address>) -> &mut Self {
+        self.external = external;
+        self
+    }
     #[inline(always)]
     pub fn seed(&mut self, seed: Seed) -> &mut Self {
         self.seed = Some(seed);
@@ -207,6 +275,12 @@ impl InitializeBuilder {
         self.data_source = Some(data_source);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn hash(&mut self, hash: Hash) -> &mut Self {
+        self.hash = Some(hash);
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: RemainderOptionBytes) -> &mut Self {
         self.data = Some(data);
@@ -235,6 +309,9 @@ impl InitializeBuilder {
             program: self.program.expect("program is not set"),
             program_data: self.program_data,
             system: self.system,
+            source_buffer: self.source_buffer,
+            destination: self.destination,
+            external: self.external,
         };
         let args = InitializeInstructionArgs {
             seed: self.seed.clone().expect("seed is not set"),
@@ -242,6 +319,7 @@ impl InitializeBuilder {
             compression: self.compression.clone().expect("compression is not set"),
             format: self.format.clone().expect("format is not set"),
             data_source: self.data_source.clone().expect("data_source is not set"),
+            hash: self.hash.clone(),
             data: self.data.clone().expect("data is not set"),
         };
 
@@ -261,6 +339,15 @@ pub struct InitializeCpiAccounts<'a, 'b> {
     pub program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// System program.
     pub system: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Buffer account to copy the data from.
+    /// You may use the `data` argument instead of this account to pass data directly.
+    pub source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Destination account for the lamports of the source buffer account.
+    /// When provided, the source buffer account is closed after its data is copied.
+    pub destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    pub external: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `initialize` CPI instruction.
@@ -277,6 +364,15 @@ pub struct InitializeCpi<'a, 'b> {
     pub program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// System program.
     pub system: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Buffer account to copy the data from.
+    /// You may use the `data` argument instead of this account to pass data directly.
+    pub source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Destination account for the lamports of the source buffer account.
+    /// When provided, the source buffer account is closed after its data is copied.
+    pub destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    pub external: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitializeInstructionArgs,
 }
@@ -294,6 +390,9 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             program: accounts.program,
             program_data: accounts.program_data,
             system: accounts.system,
+            source_buffer: accounts.source_buffer,
+            destination: accounts.destination,
+            external: accounts.external,
             __args: args,
         }
     }
@@ -320,7 +419,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.metadata.key,
             false,
@@ -355,6 +454,39 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(source_buffer) = self.source_buffer {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *source_buffer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(external) = self.external {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *external.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -371,7 +503,7 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.metadata.clone());
         account_infos.push(self.authority.clone());
@@ -382,6 +514,15 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
         if let Some(system) = self.system {
             account_infos.push(system.clone());
         }
+        if let Some(source_buffer) = self.source_buffer {
+            account_infos.push(source_buffer.clone());
+        }
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
+        if let Some(external) = self.external {
+            account_infos.push(external.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -403,6 +544,9 @@ impl<'a, 'b> InitializeCpi<'a, 'b> {
 ///   2. `[]` program
 ///   3. `[optional]` program_data
 ///   4. `[optional]` system
+///   5. `[writable, optional]` source_buffer
+///   6. `[writable, optional]` destination
+///   7. `[optional]` external
 #[derive(Clone, Debug)]
 pub struct InitializeCpiBuilder<'a, 'b> {
     instruction: Box<InitializeCpiBuilderInstruction<'a, 'b>>,
@@ -417,11 +561,15 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
             program: None,
             program_data: None,
             system: None,
+            source_buffer: None,
+            destination: None,
+            external: None,
             seed: None,
             encoding: None,
             compression: None,
             format: None,
             data_source: None,
+            hash: None,
             data: None,
             __remaining_accounts: Vec::new(),
         });
@@ -465,6 +613,39 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
         self.instruction.system = system;
         self
     }
+    /// `[optional account]`
+    /// Buffer account to copy the data from.
+    /// You may use the `data` argument instead of this account to pass data directly.
+    #[inline(always)]
+    pub fn source_buffer(
+        &mut self,
+        source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.source_buffer = source_buffer;
+        self
+    }
+    /// `[optional account]`
+    /// Destination account for the lamports of the source buffer account.
+    /// When provided, the source buffer account is closed after its data is copied.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    #[inline(always)]
+    pub fn external(
+        &mut self,
+        external: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.external = external;
+        self
+    }
     #[inline(always)]
     pub fn seed(&mut self, seed: Seed) -> &mut Self {
         self.instruction.seed = Some(seed);
@@ -490,6 +671,12 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
         self.instruction.data_source = Some(data_source);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn hash(&mut self, hash: Hash) -> &mut Self {
+        self.instruction.hash = Some(hash);
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: RemainderOptionBytes) -> &mut Self {
         self.instruction.data = Some(data);
@@ -547,6 +734,7 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
                 .data_source
                 .clone()
                 .expect("data_source is not set"),
+            hash: self.instruction.hash.clone(),
             data: self.instruction.data.clone().expect("data is not set"),
         };
         let instruction = InitializeCpi {
@@ -561,6 +749,12 @@ impl<'a, 'b> InitializeCpiBuilder<'a, 'b> {
             program_data: self.instruction.program_data,
 
             system: self.instruction.system,
+
+            source_buffer: self.instruction.source_buffer,
+
+            destination: self.instruction.destination,
+
+            external: self.instruction.external,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -578,11 +772,15 @@ struct InitializeCpiBuilderInstruction<'a, 'b> {
     program: Option<&'b solana_account_info::AccountInfo<'a>>,
     program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
     system: Option<&'b solana_account_info::AccountInfo<'a>>,
+    source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    external: Option<&'b solana_account_info::AccountInfo<'a>>,
     seed: Option<Seed>,
     encoding: Option<Encoding>,
     compression: Option<Compression>,
     format: Option<Format>,
     data_source: Option<DataSource>,
+    hash: Option<Hash>,
     data: Option<RemainderOptionBytes>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
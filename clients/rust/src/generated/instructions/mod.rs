@@ -5,22 +5,46 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_authority;
 pub(crate) mod r#allocate;
+pub(crate) mod r#cancel_authority;
 pub(crate) mod r#close;
 pub(crate) mod r#extend;
 pub(crate) mod r#initialize;
+pub(crate) mod r#initialize_with_seed;
+pub(crate) mod r#migrate;
+pub(crate) mod r#move;
+pub(crate) mod r#patch;
+pub(crate) mod r#promote;
+pub(crate) mod r#propose_authority;
 pub(crate) mod r#set_authority;
 pub(crate) mod r#set_data;
+pub(crate) mod r#set_data_checked;
+pub(crate) mod r#set_extension;
+pub(crate) mod r#set_hash;
 pub(crate) mod r#set_immutable;
 pub(crate) mod r#trim;
+pub(crate) mod r#verify_hash;
 pub(crate) mod r#write;
 
+pub use self::r#accept_authority::*;
 pub use self::r#allocate::*;
+pub use self::r#cancel_authority::*;
 pub use self::r#close::*;
 pub use self::r#extend::*;
 pub use self::r#initialize::*;
+pub use self::r#initialize_with_seed::*;
+pub use self::r#migrate::*;
+pub use self::r#move::*;
+pub use self::r#patch::*;
+pub use self::r#promote::*;
+pub use self::r#propose_authority::*;
 pub use self::r#set_authority::*;
 pub use self::r#set_data::*;
+pub use self::r#set_data_checked::*;
+pub use self::r#set_extension::*;
+pub use self::r#set_hash::*;
 pub use self::r#set_immutable::*;
 pub use self::r#trim::*;
+pub use self::r#verify_hash::*;
 pub use self::r#write::*;
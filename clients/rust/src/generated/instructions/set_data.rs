@@ -9,6 +9,7 @@ use crate::generated::types::Compression;
 use crate::generated::types::DataSource;
 use crate::generated::types::Encoding;
 use crate::generated::types::Format;
+use crate::generated::types::Hash;
 use crate::hooked::RemainderOptionBytes;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -28,6 +29,12 @@ pub struct SetData {
     pub program: Option<solana_address::Address>,
     /// Program data account.
     pub program_data: Option<solana_address::Address>,
+    /// Destination account for the excess lamports of the metadata account.
+    /// When provided, the buffer account (if any) is also closed after its data is copied.
+    pub destination: Option<solana_address::Address>,
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    pub external: Option<solana_address::Address>,
 }
 
 impl SetData {
@@ -41,7 +48,7 @@ impl SetData {
         args: SetDataInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.metadata, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
@@ -76,6 +83,24 @@ impl SetData {
                 false,
             ));
         }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_instruction::AccountMeta::new(destination, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(external) = self.external {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                external, false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = SetDataInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -115,6 +140,7 @@ pub struct SetDataInstructionArgs {
     pub encoding: Encoding,
     pub compression: Compression,
     pub format: Format,
+    pub hash: Option<Hash>,
     pub data_source: DataSource,
     pub data: RemainderOptionBytes,
 }
@@ -134,6 +160,8 @@ impl SetDataInstructionArgs {
 ///   2. `[writable, optional]` buffer
 ///   3. `[optional]` program
 ///   4. `[optional]` program_data
+///   5. `[writable, optional]` destination
+///   6. `[optional]` external
 #[derive(Clone, Debug, Default)]
 pub struct SetDataBuilder {
     metadata: Option<solana_address::Address>,
@@ -141,9 +169,12 @@ pub struct SetDataBuilder {
     buffer: Option<solana_address::Address>,
     program: Option<solana_address::Address>,
     program_data: Option<solana_address::Address>,
+    destination: Option<solana_address::Address>,
+    external: Option<solana_address::Address>,
     encoding: Option<Encoding>,
     compression: Option<Compression>,
     format: Option<Format>,
+    hash: Option<Hash>,
     data_source: Option<DataSource>,
     data: Option<RemainderOptionBytes>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -186,6 +217,22 @@ impl SetDataBuilder {
         self.program_data = program_data;
         self
     }
+    /// `[optional account]`
+    /// Destination account for the excess lamports of the metadata account.
+    /// When provided, the buffer account (if any) is also closed after its data is copied.
+    #[inline(always)]
+    pub fn destination(&mut self, destination: Option<solana_address::Address>) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    #[inline(always)]
+    pub fn external(&mut self, external: Option<solana_address::Address>) -> &mut Self {
+        self.external = external;
+        self
+    }
     #[inline(always)]
     pub fn encoding(&mut self, encoding: Encoding) -> &mut Self {
         self.encoding = Some(encoding);
@@ -201,6 +248,12 @@ impl SetDataBuilder {
         self.format = Some(format);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn hash(&mut self, hash: Hash) -> &mut Self {
+        self.hash = Some(hash);
+        self
+    }
     #[inline(always)]
     pub fn data_source(&mut self, data_source: DataSource) -> &mut Self {
         self.data_source = Some(data_source);
@@ -234,11 +287,14 @@ impl SetDataBuilder {
             buffer: self.buffer,
             program: self.program,
             program_data: self.program_data,
+            destination: self.destination,
+            external: self.external,
         };
         let args = SetDataInstructionArgs {
             encoding: self.encoding.clone().expect("encoding is not set"),
             compression: self.compression.clone().expect("compression is not set"),
             format: self.format.clone().expect("format is not set"),
+            hash: self.hash.clone(),
             data_source: self.data_source.clone().expect("data_source is not set"),
             data: self.data.clone().expect("data is not set"),
         };
@@ -259,6 +315,12 @@ pub struct SetDataCpiAccounts<'a, 'b> {
     pub program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Program data account.
     pub program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Destination account for the excess lamports of the metadata account.
+    /// When provided, the buffer account (if any) is also closed after its data is copied.
+    pub destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    pub external: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `set_data` CPI instruction.
@@ -275,6 +337,12 @@ pub struct SetDataCpi<'a, 'b> {
     pub program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Program data account.
     pub program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Destination account for the excess lamports of the metadata account.
+    /// When provided, the buffer account (if any) is also closed after its data is copied.
+    pub destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    pub external: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: SetDataInstructionArgs,
 }
@@ -292,6 +360,8 @@ impl<'a, 'b> SetDataCpi<'a, 'b> {
             buffer: accounts.buffer,
             program: accounts.program,
             program_data: accounts.program_data,
+            destination: accounts.destination,
+            external: accounts.external,
             __args: args,
         }
     }
@@ -318,7 +388,7 @@ impl<'a, 'b> SetDataCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.metadata.key,
             false,
@@ -357,6 +427,28 @@ impl<'a, 'b> SetDataCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(external) = self.external {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *external.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -373,7 +465,7 @@ impl<'a, 'b> SetDataCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.metadata.clone());
         account_infos.push(self.authority.clone());
@@ -386,6 +478,12 @@ impl<'a, 'b> SetDataCpi<'a, 'b> {
         if let Some(program_data) = self.program_data {
             account_infos.push(program_data.clone());
         }
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
+        if let Some(external) = self.external {
+            account_infos.push(external.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -407,6 +505,8 @@ impl<'a, 'b> SetDataCpi<'a, 'b> {
 ///   2. `[writable, optional]` buffer
 ///   3. `[optional]` program
 ///   4. `[optional]` program_data
+///   5. `[writable, optional]` destination
+///   6. `[optional]` external
 #[derive(Clone, Debug)]
 pub struct SetDataCpiBuilder<'a, 'b> {
     instruction: Box<SetDataCpiBuilderInstruction<'a, 'b>>,
@@ -421,9 +521,12 @@ impl<'a, 'b> SetDataCpiBuilder<'a, 'b> {
             buffer: None,
             program: None,
             program_data: None,
+            destination: None,
+            external: None,
             encoding: None,
             compression: None,
             format: None,
+            hash: None,
             data_source: None,
             data: None,
             __remaining_accounts: Vec::new(),
@@ -472,6 +575,28 @@ impl<'a, 'b> SetDataCpiBuilder<'a, 'b> {
         self.instruction.program_data = program_data;
         self
     }
+    /// `[optional account]`
+    /// Destination account for the excess lamports of the metadata account.
+    /// When provided, the buffer account (if any) is also closed after its data is copied.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
+    /// `[optional account]`
+    /// External account referenced by the data.
+    /// Required for external data that records an expected owner and content digest.
+    #[inline(always)]
+    pub fn external(
+        &mut self,
+        external: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.external = external;
+        self
+    }
     #[inline(always)]
     pub fn encoding(&mut self, encoding: Encoding) -> &mut Self {
         self.instruction.encoding = Some(encoding);
@@ -487,6 +612,12 @@ impl<'a, 'b> SetDataCpiBuilder<'a, 'b> {
         self.instruction.format = Some(format);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn hash(&mut self, hash: Hash) -> &mut Self {
+        self.instruction.hash = Some(hash);
+        self
+    }
     #[inline(always)]
     pub fn data_source(&mut self, data_source: DataSource) -> &mut Self {
         self.instruction.data_source = Some(data_source);
@@ -543,6 +674,7 @@ impl<'a, 'b> SetDataCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("compression is not set"),
             format: self.instruction.format.clone().expect("format is not set"),
+            hash: self.instruction.hash.clone(),
             data_source: self
                 .instruction
                 .data_source
@@ -562,6 +694,10 @@ impl<'a, 'b> SetDataCpiBuilder<'a, 'b> {
             program: self.instruction.program,
 
             program_data: self.instruction.program_data,
+
+            destination: self.instruction.destination,
+
+            external: self.instruction.external,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -579,9 +715,12 @@ struct SetDataCpiBuilderInstruction<'a, 'b> {
     buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
     program: Option<&'b solana_account_info::AccountInfo<'a>>,
     program_data: Option<&'b solana_account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    external: Option<&'b solana_account_info::AccountInfo<'a>>,
     encoding: Option<Encoding>,
     compression: Option<Compression>,
     format: Option<Format>,
+    hash: Option<Hash>,
     data_source: Option<DataSource>,
     data: Option<RemainderOptionBytes>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
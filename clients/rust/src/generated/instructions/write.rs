@@ -18,9 +18,13 @@ pub struct Write {
     pub buffer: solana_address::Address,
     /// The authority of the buffer.
     pub authority: solana_address::Address,
-    /// Buffer to copy the data from.
+    /// Buffer or metadata account to copy the data from.
     /// You may use the `data` argument instead of this account to pass data directly.
     pub source_buffer: Option<solana_address::Address>,
+    /// Payer account to fund the additional rent of the account.
+    pub payer: Option<solana_address::Address>,
+    /// System program.
+    pub system: Option<solana_address::Address>,
 }
 
 impl Write {
@@ -34,7 +38,7 @@ impl Write {
         args: WriteInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.buffer, false));
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
@@ -51,6 +55,22 @@ impl Write {
                 false,
             ));
         }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(system) = self.system {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(system, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = WriteInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -104,11 +124,15 @@ impl WriteInstructionArgs {
 ///   0. `[writable]` buffer
 ///   1. `[signer]` authority
 ///   2. `[optional]` source_buffer
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct WriteBuilder {
     buffer: Option<solana_address::Address>,
     authority: Option<solana_address::Address>,
     source_buffer: Option<solana_address::Address>,
+    payer: Option<solana_address::Address>,
+    system: Option<solana_address::Address>,
     offset: Option<u32>,
     data: Option<RemainderOptionBytes>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -131,13 +155,27 @@ impl WriteBuilder {
         self
     }
     /// `[optional account]`
-    /// Buffer to copy the data from.
+    /// Buffer or metadata account to copy the data from.
     /// You may use the `data` argument instead of this account to pass data directly.
     #[inline(always)]
     pub fn source_buffer(&mut self, source_buffer: Option<solana_address::Address>) -> &mut Self {
         self.source_buffer = source_buffer;
         self
     }
+    /// `[optional account]`
+    /// Payer account to fund the additional rent of the account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_address::Address>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// System program.
+    #[inline(always)]
+    pub fn system(&mut self, system: Option<solana_address::Address>) -> &mut Self {
+        self.system = system;
+        self
+    }
     #[inline(always)]
     pub fn offset(&mut self, offset: u32) -> &mut Self {
         self.offset = Some(offset);
@@ -169,6 +207,8 @@ impl WriteBuilder {
             buffer: self.buffer.expect("buffer is not set"),
             authority: self.authority.expect("authority is not set"),
             source_buffer: self.source_buffer,
+            payer: self.payer,
+            system: self.system,
         };
         let args = WriteInstructionArgs {
             offset: self.offset.clone().expect("offset is not set"),
@@ -185,9 +225,13 @@ pub struct WriteCpiAccounts<'a, 'b> {
     pub buffer: &'b solana_account_info::AccountInfo<'a>,
     /// The authority of the buffer.
     pub authority: &'b solana_account_info::AccountInfo<'a>,
-    /// Buffer to copy the data from.
+    /// Buffer or metadata account to copy the data from.
     /// You may use the `data` argument instead of this account to pass data directly.
     pub source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Payer account to fund the additional rent of the account.
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// System program.
+    pub system: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `write` CPI instruction.
@@ -198,9 +242,13 @@ pub struct WriteCpi<'a, 'b> {
     pub buffer: &'b solana_account_info::AccountInfo<'a>,
     /// The authority of the buffer.
     pub authority: &'b solana_account_info::AccountInfo<'a>,
-    /// Buffer to copy the data from.
+    /// Buffer or metadata account to copy the data from.
     /// You may use the `data` argument instead of this account to pass data directly.
     pub source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// Payer account to fund the additional rent of the account.
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    /// System program.
+    pub system: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WriteInstructionArgs,
 }
@@ -216,6 +264,8 @@ impl<'a, 'b> WriteCpi<'a, 'b> {
             buffer: accounts.buffer,
             authority: accounts.authority,
             source_buffer: accounts.source_buffer,
+            payer: accounts.payer,
+            system: accounts.system,
             __args: args,
         }
     }
@@ -242,7 +292,7 @@ impl<'a, 'b> WriteCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.buffer.key,
             false,
@@ -262,6 +312,25 @@ impl<'a, 'b> WriteCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(*payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
+        if let Some(system) = self.system {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::PROGRAM_METADATA_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -278,13 +347,19 @@ impl<'a, 'b> WriteCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.buffer.clone());
         account_infos.push(self.authority.clone());
         if let Some(source_buffer) = self.source_buffer {
             account_infos.push(source_buffer.clone());
         }
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(system) = self.system {
+            account_infos.push(system.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -304,6 +379,8 @@ impl<'a, 'b> WriteCpi<'a, 'b> {
 ///   0. `[writable]` buffer
 ///   1. `[signer]` authority
 ///   2. `[optional]` source_buffer
+///   3. `[writable, signer, optional]` payer
+///   4. `[optional]` system
 #[derive(Clone, Debug)]
 pub struct WriteCpiBuilder<'a, 'b> {
     instruction: Box<WriteCpiBuilderInstruction<'a, 'b>>,
@@ -316,6 +393,8 @@ impl<'a, 'b> WriteCpiBuilder<'a, 'b> {
             buffer: None,
             authority: None,
             source_buffer: None,
+            payer: None,
+            system: None,
             offset: None,
             data: None,
             __remaining_accounts: Vec::new(),
@@ -335,7 +414,7 @@ impl<'a, 'b> WriteCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// Buffer to copy the data from.
+    /// Buffer or metadata account to copy the data from.
     /// You may use the `data` argument instead of this account to pass data directly.
     #[inline(always)]
     pub fn source_buffer(
@@ -345,6 +424,23 @@ impl<'a, 'b> WriteCpiBuilder<'a, 'b> {
         self.instruction.source_buffer = source_buffer;
         self
     }
+    /// `[optional account]`
+    /// Payer account to fund the additional rent of the account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// System program.
+    #[inline(always)]
+    pub fn system(
+        &mut self,
+        system: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system = system;
+        self
+    }
     #[inline(always)]
     pub fn offset(&mut self, offset: u32) -> &mut Self {
         self.instruction.offset = Some(offset);
@@ -401,6 +497,10 @@ impl<'a, 'b> WriteCpiBuilder<'a, 'b> {
             authority: self.instruction.authority.expect("authority is not set"),
 
             source_buffer: self.instruction.source_buffer,
+
+            payer: self.instruction.payer,
+
+            system: self.instruction.system,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -416,6 +516,8 @@ struct WriteCpiBuilderInstruction<'a, 'b> {
     buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_account_info::AccountInfo<'a>>,
     source_buffer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system: Option<&'b solana_account_info::AccountInfo<'a>>,
     offset: Option<u32>,
     data: Option<RemainderOptionBytes>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -25,4 +25,5 @@ pub enum AccountDiscriminator {
     Empty,
     Buffer,
     Metadata,
+    PendingAuthority,
 }
@@ -25,4 +25,7 @@ pub enum Compression {
     None,
     Gzip,
     Zlib,
+    Zstd,
+    Brotli,
+    Lz4,
 }
@@ -25,4 +25,7 @@ pub enum DataSource {
     Direct,
     Url,
     External,
+    ContentAddressed,
+    Chunked,
+    Alias,
 }
@@ -26,4 +26,5 @@ pub enum Encoding {
     Utf8,
     Base58,
     Base64,
+    Base16,
 }
@@ -26,4 +26,8 @@ pub enum Format {
     Json,
     Yaml,
     Toml,
+    Borsh,
+    Protobuf,
+    Cbor,
+    MessagePack,
 }
@@ -6,17 +6,31 @@
 //!
 
 pub(crate) mod r#account_discriminator;
+pub(crate) mod r#alias_data;
+pub(crate) mod r#chunk_data;
 pub(crate) mod r#compression;
+pub(crate) mod r#content_data;
+pub(crate) mod r#content_protocol;
+pub(crate) mod r#data_expectation;
 pub(crate) mod r#data_source;
 pub(crate) mod r#encoding;
 pub(crate) mod r#external_data;
+pub(crate) mod r#external_data_v2;
 pub(crate) mod r#format;
+pub(crate) mod r#hash;
 pub(crate) mod r#seed;
 
 pub use self::r#account_discriminator::*;
+pub use self::r#alias_data::*;
+pub use self::r#chunk_data::*;
 pub use self::r#compression::*;
+pub use self::r#content_data::*;
+pub use self::r#content_protocol::*;
+pub use self::r#data_expectation::*;
 pub use self::r#data_source::*;
 pub use self::r#encoding::*;
 pub use self::r#external_data::*;
+pub use self::r#external_data_v2::*;
 pub use self::r#format::*;
+pub use self::r#hash::*;
 pub use self::r#seed::*;
@@ -0,0 +1,92 @@
+//! Helpers to reassemble chunked data.
+//!
+//! Metadata accounts with a [`DataSource::Chunked`](crate::types::DataSource::Chunked)
+//! data source store an ordered list of [`ChunkData`] values, each referencing
+//! a range of bytes in an account. The content is the concatenation of all
+//! chunks, in order.
+
+use std::io;
+
+use borsh::BorshDeserialize;
+use solana_address::Address;
+
+use crate::types::ChunkData;
+
+/// Length of a serialized [`ChunkData`] value.
+pub const CHUNK_DATA_LEN: usize = 40;
+
+/// Parses the list of chunks stored in `data`.
+pub fn parse(data: &[u8]) -> io::Result<Vec<ChunkData>> {
+    if data.is_empty() || data.len() % CHUNK_DATA_LEN != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid chunked data length: {}", data.len()),
+        ));
+    }
+    data.chunks_exact(CHUNK_DATA_LEN)
+        .map(ChunkData::try_from_slice)
+        .collect()
+}
+
+/// Reassembles the content referenced by `chunks`.
+///
+/// The `account_data` function returns the data of the account with the given
+/// address, if it exists. Fails if an account is missing or if a chunk range
+/// is out of bounds of its account data.
+pub fn reassemble<'a>(
+    chunks: &[ChunkData],
+    mut account_data: impl FnMut(&Address) -> Option<&'a [u8]>,
+) -> io::Result<Vec<u8>> {
+    let mut content = Vec::new();
+
+    for chunk in chunks {
+        let data = account_data(&chunk.address).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("chunk account not found: {}", chunk.address),
+            )
+        })?;
+        let start = chunk.offset as usize;
+        let end = start.saturating_add(chunk.length as usize);
+        let bytes = data.get(start..end).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "chunk range {start}..{end} out of bounds of {}",
+                    chunk.address
+                ),
+            )
+        })?;
+        content.extend_from_slice(bytes);
+    }
+
+    Ok(content)
+}
+
+/// Fetches the accounts referenced by `chunks` and reassembles their content.
+#[cfg(feature = "fetch")]
+pub fn fetch(
+    rpc: &solana_rpc_client::rpc_client::RpcClient,
+    chunks: &[ChunkData],
+) -> io::Result<Vec<u8>> {
+    // Maximum number of accounts per `getMultipleAccounts` request.
+    const MAX_ACCOUNTS: usize = 100;
+
+    let mut addresses: Vec<Address> = chunks.iter().map(|chunk| chunk.address).collect();
+    addresses.sort();
+    addresses.dedup();
+
+    let mut accounts = std::collections::HashMap::with_capacity(addresses.len());
+    for batch in addresses.chunks(MAX_ACCOUNTS) {
+        let fetched = rpc
+            .get_multiple_accounts(batch)
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        for (address, account) in batch.iter().zip(fetched) {
+            if let Some(account) = account {
+                accounts.insert(*address, account.data);
+            }
+        }
+    }
+
+    reassemble(chunks, |address| accounts.get(address).map(Vec::as_slice))
+}
@@ -0,0 +1,60 @@
+//! Helpers to retrieve and verify content-addressed data.
+//!
+//! Metadata accounts with a [`DataSource::ContentAddressed`](crate::types::DataSource::ContentAddressed)
+//! data source store a [`ContentData`] value: the network where the content is
+//! stored, its content identifier and the SHA-256 digest of the content.
+//!
+//! Downloading the content is delegated to a [`ContentResolver`], which allows
+//! plugging in any gateway (or a local stand-in). The downloaded content is
+//! then verified against the stored digest.
+
+use std::io;
+
+use sha2::{Digest, Sha256};
+
+use crate::types::{ContentData, ContentProtocol, Hash};
+
+/// Trait for types that download content from a content-addressed network.
+pub trait ContentResolver {
+    /// Returns the content identified by `identifier` on the `protocol` network.
+    ///
+    /// The content is returned as stored on the network, i.e. before any
+    /// decompression or decoding.
+    fn resolve(&self, protocol: ContentProtocol, identifier: &str) -> io::Result<Vec<u8>>;
+}
+
+impl<F> ContentResolver for F
+where
+    F: Fn(ContentProtocol, &str) -> io::Result<Vec<u8>>,
+{
+    fn resolve(&self, protocol: ContentProtocol, identifier: &str) -> io::Result<Vec<u8>> {
+        self(protocol, identifier)
+    }
+}
+
+/// Computes the digest of `content`, as stored in [`ContentData::digest`].
+pub fn digest(content: &[u8]) -> Hash {
+    Sha256::digest(content).into()
+}
+
+/// Verifies that `content` matches the digest of the content-addressed `data`.
+pub fn verify(data: &ContentData, content: &[u8]) -> io::Result<()> {
+    if digest(content) != data.digest {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "digest mismatch for {:?} content {}",
+                data.protocol, &*data.identifier
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Downloads the content-addressed `data` using the `resolver` and verifies
+/// it against the stored digest.
+pub fn resolve(resolver: &impl ContentResolver, data: &ContentData) -> io::Result<Vec<u8>> {
+    let content = resolver.resolve(data.protocol, &data.identifier)?;
+    verify(data, &content)?;
+    Ok(content)
+}
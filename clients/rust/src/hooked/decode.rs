@@ -0,0 +1,31 @@
+//! Helpers to decode the content of metadata accounts into typed values.
+//!
+//! Borsh content is always supported. Other binary formats that can be parsed
+//! natively are only available when their cargo feature is enabled: `cbor` for
+//! [`Format::Cbor`](crate::types::Format::Cbor) and `msgpack` for
+//! [`Format::MessagePack`](crate::types::Format::MessagePack).
+
+use std::io;
+
+use borsh::BorshDeserialize;
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+use serde::de::DeserializeOwned;
+
+/// Decodes a Borsh serialized value.
+///
+/// The whole `data` must be consumed by the value.
+pub fn borsh<T: BorshDeserialize>(data: &[u8]) -> io::Result<T> {
+    T::try_from_slice(data)
+}
+
+/// Decodes a CBOR serialized value.
+#[cfg(feature = "cbor")]
+pub fn cbor<T: DeserializeOwned>(data: &[u8]) -> io::Result<T> {
+    ciborium::from_reader(data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Decodes a MessagePack serialized value.
+#[cfg(feature = "msgpack")]
+pub fn message_pack<T: DeserializeOwned>(data: &[u8]) -> io::Result<T> {
+    rmp_serde::from_slice(data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
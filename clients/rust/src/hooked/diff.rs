@@ -0,0 +1,133 @@
+//! Helpers to update metadata content in place.
+//!
+//! The [`Patch`](crate::instructions::Patch) instruction writes a range of
+//! bytes directly into the data of a metadata account and resizes it to a new
+//! data length. These helpers compute the set of patches needed to turn the
+//! current content of an account into new content, so that only the bytes
+//! that changed are sent.
+
+use solana_address::Address;
+use solana_instruction::Instruction;
+
+use crate::{hooked::RemainderOptionBytes, instructions::PatchBuilder};
+
+/// Size of the fixed part of the `Patch` instruction data (discriminator,
+/// offset and data length).
+///
+/// Unchanged bytes shorter than this are included in a patch instead of
+/// starting a new one, since a new patch would cost at least as much.
+pub const PATCH_OVERHEAD: usize = 9;
+
+/// A range of bytes to write into the data of a metadata account.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DataPatch {
+    /// Offset to write to, relative to the start of the data.
+    pub offset: u32,
+    /// Bytes to write.
+    pub bytes: Vec<u8>,
+}
+
+/// Computes the patches that turn `old` into `new`.
+///
+/// Each patch writes at most `max_len` bytes and all patches must be applied
+/// with a data length of `new.len()`. Bytes past the end of `old` are
+/// compared against zero, since growing the data zero-extends it.
+///
+/// When the content only changes in length, a single empty patch is returned
+/// to resize the data; when the content is unchanged, no patches are returned.
+///
+/// Panics if `max_len` is zero.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn diff(old: &[u8], new: &[u8], max_len: usize) -> Vec<DataPatch> {
+    assert!(max_len > 0, "max_len must be greater than zero");
+
+    // Indices are bounded by `new.len()`, so the arithmetic below cannot
+    // overflow.
+    let old_byte = |index: usize| old.get(index).copied().unwrap_or(0);
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut index = 0;
+
+    while index < new.len() {
+        if new[index] == old_byte(index) {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < new.len() && new[index] != old_byte(index) {
+            index += 1;
+        }
+        match ranges.last_mut() {
+            // Merges ranges separated by fewer unchanged bytes than the cost
+            // of a new patch.
+            Some((_, end)) if start - *end < PATCH_OVERHEAD => *end = index,
+            _ => ranges.push((start, index)),
+        }
+    }
+
+    let mut patches: Vec<DataPatch> = ranges
+        .into_iter()
+        .flat_map(|(start, end)| {
+            (start..end).step_by(max_len).map(move |offset| DataPatch {
+                offset: offset as u32,
+                bytes: new[offset..offset + (end - offset).min(max_len)].to_vec(),
+            })
+        })
+        .collect();
+
+    if patches.is_empty() && old.len() != new.len() {
+        patches.push(DataPatch {
+            offset: 0,
+            bytes: Vec::new(),
+        });
+    }
+
+    patches
+}
+
+/// Applies `patches` to `data` the same way the program does, resizing it to
+/// `data_length` bytes.
+///
+/// Returns `None` if a patch does not fit within `data_length`.
+pub fn apply(data: &[u8], patches: &[DataPatch], data_length: usize) -> Option<Vec<u8>> {
+    let mut data = data.to_vec();
+    data.resize(data_length, 0);
+
+    for patch in patches {
+        let start = patch.offset as usize;
+        let end = start.checked_add(patch.bytes.len())?;
+        data.get_mut(start..end)?.copy_from_slice(&patch.bytes);
+    }
+
+    Some(data)
+}
+
+/// Returns the `Patch` instructions that turn `old` into `new`.
+///
+/// The `program` and `program_data` accounts are only needed when the
+/// `authority` is the upgrade authority of a canonical metadata account.
+pub fn instructions(
+    metadata: Address,
+    authority: Address,
+    program: Option<Address>,
+    program_data: Option<Address>,
+    old: &[u8],
+    new: &[u8],
+    max_len: usize,
+) -> Vec<Instruction> {
+    let data_length = new.len() as u32;
+
+    diff(old, new, max_len)
+        .into_iter()
+        .map(|patch| {
+            PatchBuilder::new()
+                .metadata(metadata)
+                .authority(authority)
+                .program(program)
+                .program_data(program_data)
+                .offset(patch.offset)
+                .data_length(data_length)
+                .data(RemainderOptionBytes::Some(patch.bytes))
+                .instruction()
+        })
+        .collect()
+}
@@ -0,0 +1,63 @@
+//! Helpers to grow buffer and metadata accounts.
+//!
+//! The [`Extend`](crate::instructions::Extend) instruction can only grow an
+//! account by [`MAX_EXTEND_LENGTH`] bytes, the maximum data increase allowed by
+//! the runtime within an instruction. These helpers split a larger increase
+//! into as many instructions as needed.
+
+use solana_address::Address;
+use solana_instruction::Instruction;
+
+use crate::instructions::ExtendBuilder;
+
+/// Maximum number of bytes an account can grow by with a single `Extend`
+/// instruction.
+pub const MAX_EXTEND_LENGTH: u32 = 10_240;
+
+/// Returns the lengths of the `Extend` instructions needed to grow an account
+/// from `current_len` to `new_len` bytes.
+///
+/// No lengths are returned when `new_len` is not greater than `current_len`.
+pub fn lengths(current_len: usize, new_len: usize) -> Vec<u32> {
+    let mut remaining = new_len.saturating_sub(current_len);
+    let mut lengths = Vec::new();
+
+    while remaining > 0 {
+        let length = remaining.min(MAX_EXTEND_LENGTH as usize);
+        lengths.push(length as u32);
+        remaining = remaining.saturating_sub(length);
+    }
+
+    lengths
+}
+
+/// Returns the `Extend` instructions that grow `account` from `current_len`
+/// to `new_len` bytes.
+///
+/// The `program` and `program_data` accounts are only needed when the
+/// `authority` is the upgrade authority of a canonical account. When a
+/// `payer` is provided, it funds the lamports required for the new size;
+/// otherwise the account must already be funded.
+pub fn instructions(
+    account: Address,
+    authority: Address,
+    program: Option<Address>,
+    program_data: Option<Address>,
+    payer: Option<Address>,
+    current_len: usize,
+    new_len: usize,
+) -> Vec<Instruction> {
+    lengths(current_len, new_len)
+        .into_iter()
+        .map(|length| {
+            ExtendBuilder::new()
+                .account(account)
+                .authority(authority)
+                .program(program)
+                .program_data(program_data)
+                .payer(payer)
+                .length(length)
+                .instruction()
+        })
+        .collect()
+}
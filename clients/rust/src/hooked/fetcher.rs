@@ -0,0 +1,162 @@
+//! Helpers to retrieve the content of metadata accounts.
+//!
+//! The content of a metadata account can be stored in other accounts,
+//! depending on its data source:
+//!  - `External`: a range of bytes of another account (see [`external_content`]),
+//!    optionally constrained by its owner and digest (see [`verify_external`]).
+//!  - `Chunked`: ranges of bytes of several accounts (see [`chunked`](crate::chunked)).
+//!  - `Alias`: the content of another metadata account (see [`follow_aliases`]).
+//!
+//! With the `fetch` feature, [`fetch_content`] retrieves the content of a
+//! metadata account over RPC for all account-based data sources.
+
+use std::{collections::HashSet, io};
+
+use solana_address::Address;
+
+use crate::{
+    accounts::Metadata,
+    content::digest,
+    hooked::ZeroableOption,
+    types::{ExternalData, ExternalDataV2},
+};
+
+/// Maximum number of aliases followed when resolving a metadata account.
+pub const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Follows the aliases starting at the `metadata` account stored at `address`.
+///
+/// The `fetch_metadata` function returns the metadata account stored at the
+/// given address. Returns the address and data of the first metadata account
+/// that is not an alias, which is the starting account itself when it is not
+/// an alias. Fails if an alias cycle is detected or if more than
+/// [`MAX_ALIAS_DEPTH`] aliases are followed.
+pub fn follow_aliases(
+    address: Address,
+    metadata: Metadata,
+    mut fetch_metadata: impl FnMut(&Address) -> io::Result<Metadata>,
+) -> io::Result<(Address, Metadata)> {
+    let mut visited = HashSet::from([address]);
+    let (mut address, mut metadata) = (address, metadata);
+
+    while metadata.is_alias() {
+        let target = metadata.alias()?;
+
+        if !visited.insert(target) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("alias cycle detected at {target}"),
+            ));
+        }
+        if visited.len() > MAX_ALIAS_DEPTH + 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("too many aliases followed from {address}"),
+            ));
+        }
+
+        metadata = fetch_metadata(&target)?;
+        address = target;
+    }
+
+    Ok((address, metadata))
+}
+
+/// Returns the range of `account_data` referenced by the `external` data.
+pub fn external_content<'a>(
+    external: &ExternalData,
+    account_data: &'a [u8],
+) -> io::Result<&'a [u8]> {
+    let start = external.offset as usize;
+    let end = match external.length {
+        ZeroableOption::Some(length) => start.saturating_add(length as usize),
+        ZeroableOption::None => account_data.len(),
+    };
+    account_data.get(start..end).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "external range {start}..{end} out of bounds of {}",
+                external.address
+            ),
+        )
+    })
+}
+
+/// Verifies that the external account matches the constraints of the
+/// `external` data and returns the referenced range of its data.
+///
+/// The `owner` and `account_data` are the owner and data of the external
+/// account. Fails if the account is not owned by the expected program or if
+/// the digest of the referenced range does not match.
+pub fn verify_external<'a>(
+    external: &ExternalDataV2,
+    owner: &Address,
+    account_data: &'a [u8],
+) -> io::Result<&'a [u8]> {
+    if owner != &external.owner {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "external account {} owned by {owner}, expected {}",
+                external.address, external.owner
+            ),
+        ));
+    }
+
+    let content = external_content(
+        &ExternalData {
+            address: external.address,
+            offset: external.offset,
+            length: external.length.clone(),
+        },
+        account_data,
+    )?;
+
+    if digest(content) != external.digest {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("digest mismatch for external account {}", external.address),
+        ));
+    }
+
+    Ok(content)
+}
+
+/// Fetches the content of the metadata account at `address`.
+///
+/// Aliases are followed first; the returned metadata is the one of the
+/// account holding the content, which describes how to decompress and decode
+/// it. External data with owner and digest constraints is verified. The content of `Url` and `ContentAddressed` data sources is not stored
+/// in accounts, so the stored reference is returned as is (see the
+/// [`content`](crate::content) module to resolve content-addressed data).
+#[cfg(feature = "fetch")]
+pub fn fetch_content(
+    rpc: &solana_rpc_client::rpc_client::RpcClient,
+    address: &Address,
+) -> io::Result<(Metadata, Vec<u8>)> {
+    use crate::{accounts::fetch_metadata, types::DataSource};
+
+    let metadata = fetch_metadata(rpc, address)?.data;
+    let (_, metadata) = follow_aliases(*address, metadata, |address| {
+        fetch_metadata(rpc, address).map(|account| account.data)
+    })?;
+
+    let content = match metadata.data_source {
+        DataSource::External => {
+            let external = metadata.external_data()?;
+            let account = rpc
+                .get_account(&external.address)
+                .map_err(|e| io::Error::other(e.to_string()))?;
+            match metadata.external_data_v2()? {
+                Some(external) => verify_external(&external, &account.owner, &account.data)?,
+                None => external_content(&external, &account.data)?,
+            }
+            .to_vec()
+        }
+        DataSource::Chunked => super::chunked::fetch(rpc, &metadata.chunks()?)?,
+        _ => metadata.content().to_vec(),
+    };
+
+    Ok((metadata, content))
+}
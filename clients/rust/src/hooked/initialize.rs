@@ -0,0 +1,20 @@
+//! Helpers for the `Initialize` instruction builder.
+
+use solana_address::Address;
+
+use crate::instructions::InitializeBuilder;
+
+impl InitializeBuilder {
+    /// Copies the data of the `source_buffer` account to the metadata account
+    /// and closes it, moving its lamports to the `destination` account.
+    ///
+    /// The authority must also be the buffer authority.
+    pub fn close_source_buffer(
+        &mut self,
+        source_buffer: Address,
+        destination: Address,
+    ) -> &mut Self {
+        self.source_buffer(Some(source_buffer))
+            .destination(Some(destination))
+    }
+}
@@ -0,0 +1,281 @@
+use std::io;
+
+use borsh::BorshDeserialize;
+use solana_address::Address;
+
+use crate::{
+    accounts::Metadata,
+    instructions::{SetDataCheckedBuilder, SetDataCheckedCpiBuilder},
+    types::{
+        AliasData, ChunkData, ContentData, DataExpectation, DataSource, ExternalData,
+        ExternalDataV2, Format, Hash,
+    },
+};
+
+/// Offset of the end of the content hash section.
+const HASH_SECTION_END: usize = size_of::<Hash>();
+
+/// Length of a serialized `ExternalDataV2` value.
+const EXTERNAL_DATA_V2_LEN: usize = 104;
+
+/// Length of the extension area length prefix.
+const EXTENSIONS_LENGTH_LEN: usize = 2;
+
+impl Metadata {
+    /// Flag indicating that the content hash section follows the header.
+    pub const HASH_FLAG: u8 = 0b0000_0001;
+
+    /// Indicates whether the metadata account has a content hash.
+    pub fn has_hash(&self) -> bool {
+        self.flags & Self::HASH_FLAG != 0
+    }
+
+    /// Flag indicating that the extension area precedes the data.
+    pub const EXTENSIONS_FLAG: u8 = 0b0000_0010;
+
+    /// Indicates whether the metadata account has an extension area.
+    pub fn has_extensions(&self) -> bool {
+        self.flags & Self::EXTENSIONS_FLAG != 0
+    }
+
+    /// Returns the content hash of the metadata account, if any.
+    pub fn hash(&self) -> Option<&Hash> {
+        if !self.has_hash() {
+            return None;
+        }
+        self.data
+            .get(..HASH_SECTION_END)
+            .and_then(|hash| hash.try_into().ok())
+    }
+
+    /// Returns the extensions of the metadata account as `(type, value)` pairs.
+    ///
+    /// Iteration stops at the end of the extension area or at the first
+    /// malformed extension.
+    pub fn extensions(&self) -> impl Iterator<Item = (u16, &[u8])> {
+        let mut area = self.extension_area().get(EXTENSIONS_LENGTH_LEN..);
+
+        std::iter::from_fn(move || {
+            let [t0, t1, l0, l1, remaining @ ..] = area? else {
+                return None;
+            };
+            let extension_type = u16::from_le_bytes([*t0, *t1]);
+            // A zero extension type marks the start of the padding.
+            if extension_type == 0 {
+                return None;
+            }
+            let length = u16::from_le_bytes([*l0, *l1]) as usize;
+            let value = remaining.get(..length)?;
+            area = remaining.get(length..);
+            Some((extension_type, value))
+        })
+    }
+
+    /// Returns the value of the extension of the given type, if present.
+    pub fn extension(&self, extension_type: u16) -> Option<&[u8]> {
+        self.extensions()
+            .find(|(t, _)| *t == extension_type)
+            .map(|(_, value)| value)
+    }
+
+    /// Type of the extension holding the revision of the metadata account.
+    pub const REVISION_EXTENSION_TYPE: u16 = u16::MAX - 1;
+
+    /// Returns the revision of the metadata account, which is incremented
+    /// every time its data changes.
+    ///
+    /// Accounts without a revision extension are at revision `0`.
+    pub fn revision(&self) -> u64 {
+        self.extension(Self::REVISION_EXTENSION_TYPE)
+            .and_then(|revision| revision.try_into().ok())
+            .map_or(0, u64::from_le_bytes)
+    }
+
+    /// Returns the content of the metadata account.
+    ///
+    /// This skips any optional section, and is limited
+    /// to `data_length` bytes.
+    pub fn content(&self) -> &[u8] {
+        let offset = self
+            .extensions_offset()
+            .saturating_add(self.extension_area().len());
+        let data = self.data.get(offset..).unwrap_or_default();
+        &data[..data.len().min(self.data_length as usize)]
+    }
+
+    /// Returns the content-addressed data of the metadata account.
+    ///
+    /// Fails if the metadata account does not use the `ContentAddressed` data
+    /// source.
+    pub fn content_data(&self) -> io::Result<ContentData> {
+        self.expect_data_source(DataSource::ContentAddressed)?;
+        ContentData::try_from_slice(self.content())
+    }
+
+    /// Returns the chunks of the metadata account content.
+    ///
+    /// Fails if the metadata account does not use the `Chunked` data source.
+    /// See the [`chunked`](crate::chunked) module to reassemble the content.
+    pub fn chunks(&self) -> io::Result<Vec<ChunkData>> {
+        self.expect_data_source(DataSource::Chunked)?;
+        super::chunked::parse(self.content())
+    }
+
+    /// Returns the external data of the metadata account.
+    ///
+    /// Since [`ExternalDataV2`] extends [`ExternalData`], this returns the
+    /// common fields of both versions. Fails if the metadata account does not
+    /// use the `External` data source.
+    pub fn external_data(&self) -> io::Result<ExternalData> {
+        self.expect_data_source(DataSource::External)?;
+        ExternalData::deserialize(&mut self.content())
+    }
+
+    /// Returns the external data of the metadata account, if it records an
+    /// expected owner and content digest.
+    ///
+    /// Fails if the metadata account does not use the `External` data source.
+    pub fn external_data_v2(&self) -> io::Result<Option<ExternalDataV2>> {
+        self.expect_data_source(DataSource::External)?;
+        match self.content() {
+            content if content.len() == EXTERNAL_DATA_V2_LEN => {
+                ExternalDataV2::try_from_slice(content).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Indicates whether the metadata account is an alias of another metadata
+    /// account.
+    pub fn is_alias(&self) -> bool {
+        self.data_source == DataSource::Alias
+    }
+
+    /// Returns the address of the metadata account aliased by this account.
+    ///
+    /// Fails if the metadata account does not use the `Alias` data source.
+    /// See [`fetcher::follow_aliases`](crate::fetcher::follow_aliases) to
+    /// resolve aliases.
+    pub fn alias(&self) -> io::Result<Address> {
+        self.expect_data_source(DataSource::Alias)?;
+        AliasData::try_from_slice(self.content()).map(|alias| alias.address)
+    }
+
+    /// Ensures the metadata account content has the `expected` format.
+    fn expect_format(&self, expected: Format) -> io::Result<()> {
+        if self.format != expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected {expected:?} content, found {:?}", self.format),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ensures the metadata account uses the `expected` data source.
+    fn expect_data_source(&self, expected: DataSource) -> io::Result<()> {
+        if self.data_source != expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected {expected:?} data, found {:?}", self.data_source),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the offset of the extension area in `data`.
+    fn extensions_offset(&self) -> usize {
+        if self.has_hash() {
+            HASH_SECTION_END
+        } else {
+            0
+        }
+    }
+
+    /// Returns the bytes of the extension area, including its length prefix.
+    fn extension_area(&self) -> &[u8] {
+        if !self.has_extensions() {
+            return &[];
+        }
+        let area = self
+            .data
+            .get(self.extensions_offset()..)
+            .unwrap_or_default();
+        match area {
+            [l0, l1, ..] => {
+                let length = u16::from_le_bytes([*l0, *l1]) as usize;
+                area.get(..length).unwrap_or(area)
+            }
+            _ => area,
+        }
+    }
+
+    /// Returns the content of the metadata account, decompressed according to
+    /// its `compression`.
+    ///
+    /// See [`pack::decompress`](crate::pack::decompress) for the required cargo
+    /// features.
+    pub fn decompressed_content(&self) -> io::Result<Vec<u8>> {
+        super::pack::decompress(self.content(), self.compression)
+    }
+
+    /// Returns the content of the metadata account as a string, decompressed and
+    /// decoded according to its `compression` and `encoding`.
+    ///
+    /// See [`pack::unpack_data`](crate::pack::unpack_data) for details.
+    pub fn unpacked_content(&self) -> io::Result<String> {
+        super::pack::unpack_data(self.content(), self.encoding, self.compression)
+    }
+
+    /// Decodes the content of a metadata account with the `Borsh` format.
+    ///
+    /// The content is decompressed first (see [`Self::decompressed_content`]).
+    pub fn borsh_content<T: borsh::BorshDeserialize>(&self) -> io::Result<T> {
+        self.expect_format(Format::Borsh)?;
+        super::decode::borsh(&self.decompressed_content()?)
+    }
+
+    /// Decodes the content of a metadata account with a `serde` compatible
+    /// binary format.
+    ///
+    /// Supports the `Cbor` (`cbor` feature) and `MessagePack` (`msgpack` feature)
+    /// formats. The content is decompressed first (see [`Self::decompressed_content`]).
+    #[cfg(any(feature = "cbor", feature = "msgpack"))]
+    pub fn deserialize_content<T: serde::de::DeserializeOwned>(&self) -> io::Result<T> {
+        let content = self.decompressed_content()?;
+        match self.format {
+            #[cfg(feature = "cbor")]
+            Format::Cbor => super::decode::cbor(&content),
+            #[cfg(feature = "msgpack")]
+            Format::MessagePack => super::decode::message_pack(&content),
+            format => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("{format:?} content cannot be deserialized"),
+            )),
+        }
+    }
+
+    /// Returns an expectation matching the current revision of the metadata account.
+    ///
+    /// This is used by the `SetDataChecked` instruction to ensure that the
+    /// account has not been updated since it was fetched.
+    pub fn expectation(&self) -> DataExpectation {
+        DataExpectation::Revision(self.revision())
+    }
+}
+
+impl SetDataCheckedBuilder {
+    /// Expects the metadata account to be unchanged since `metadata` was fetched.
+    #[inline(always)]
+    pub fn expected_metadata(&mut self, metadata: &Metadata) -> &mut Self {
+        self.expectation(metadata.expectation())
+    }
+}
+
+impl SetDataCheckedCpiBuilder<'_, '_> {
+    /// Expects the metadata account to be unchanged since `metadata` was fetched.
+    #[inline(always)]
+    pub fn expected_metadata(&mut self, metadata: &Metadata) -> &mut Self {
+        self.expectation(metadata.expectation())
+    }
+}
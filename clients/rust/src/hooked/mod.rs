@@ -1,5 +1,17 @@
+pub mod chunked;
+pub mod content;
+pub mod decode;
+pub mod diff;
+pub mod extend;
+pub mod fetcher;
+mod initialize;
+mod metadata;
+pub mod pack;
 mod remainder_options;
+pub mod seed;
+mod set_data;
 mod zeroable_options;
 
 pub use remainder_options::*;
+pub use seed::VariableSeed;
 pub use zeroable_options::*;
@@ -0,0 +1,175 @@
+//! Helpers to encode, decode, compress and decompress the content of metadata
+//! accounts.
+//!
+//! Content is packed by encoding it into bytes and then compressing these bytes;
+//! it is unpacked by applying the reverse operations.
+//!
+//! Each compression format other than [`Compression::None`] is only available
+//! when its cargo feature is enabled: `gzip`, `zlib`, `zstd`, `brotli` or `lz4`.
+//! Using a format whose feature is disabled returns an error of kind
+//! [`io::ErrorKind::Unsupported`].
+
+use std::io;
+#[cfg(any(
+    feature = "gzip",
+    feature = "zlib",
+    feature = "brotli",
+    feature = "lz4"
+))]
+use std::io::{Read, Write};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+use crate::types::{Compression, Encoding};
+
+/// Compression level used for the `zstd` format.
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 19;
+
+/// Quality used for the `brotli` format.
+#[cfg(feature = "brotli")]
+const BROTLI_QUALITY: u32 = 11;
+
+/// Window size (log2) used for the `brotli` format.
+#[cfg(feature = "brotli")]
+const BROTLI_WINDOW_SIZE: u32 = 22;
+
+/// Size of the internal buffer used by the `brotli` format.
+#[cfg(feature = "brotli")]
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+/// Encodes the `content` string into bytes with the given `encoding`.
+///
+/// With the exception of `Utf8`, the content is expected to be the string
+/// representation of binary data, which is stored as raw bytes. Since
+/// `None` has no string representation, its content is handled as hex.
+pub fn encode_data(content: &str, encoding: Encoding) -> io::Result<Vec<u8>> {
+    match encoding {
+        Encoding::None | Encoding::Base16 => hex::decode(content).map_err(invalid_data),
+        Encoding::Utf8 => Ok(content.as_bytes().to_vec()),
+        Encoding::Base58 => bs58::decode(content).into_vec().map_err(invalid_data),
+        Encoding::Base64 => BASE64.decode(content).map_err(invalid_data),
+    }
+}
+
+/// Decodes `data` into a string with the given `encoding`.
+///
+/// This is the reverse operation of [`encode_data`].
+pub fn decode_data(data: &[u8], encoding: Encoding) -> io::Result<String> {
+    match encoding {
+        Encoding::None | Encoding::Base16 => Ok(hex::encode(data)),
+        Encoding::Utf8 => String::from_utf8(data.to_vec()).map_err(invalid_data),
+        Encoding::Base58 => Ok(bs58::encode(data).into_string()),
+        Encoding::Base64 => Ok(BASE64.encode(data)),
+    }
+}
+
+/// Encodes and compresses the `content` string.
+pub fn pack_data(
+    content: &str,
+    encoding: Encoding,
+    compression: Compression,
+) -> io::Result<Vec<u8>> {
+    compress(&encode_data(content, encoding)?, compression)
+}
+
+/// Decompresses and decodes `data` into a string.
+///
+/// This is the reverse operation of [`pack_data`].
+pub fn unpack_data(
+    data: &[u8],
+    encoding: Encoding,
+    compression: Compression,
+) -> io::Result<String> {
+    decode_data(&decompress(data, compression)?, encoding)
+}
+
+/// Compresses `data` with the given `compression` format.
+///
+/// Formats are configured for the best compression ratio, since the size of
+/// the data determines the rent of the metadata account.
+pub fn compress(data: &[u8], compression: Compression) -> io::Result<Vec<u8>> {
+    match compression {
+        Compression::None => Ok(data.to_vec()),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        #[cfg(feature = "zlib")]
+        Compression::Zlib => {
+            let mut encoder =
+                flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => zstd::encode_all(data, ZSTD_LEVEL),
+        #[cfg(feature = "brotli")]
+        Compression::Brotli => {
+            let mut compressed = Vec::new();
+            {
+                let mut encoder = brotli::CompressorWriter::new(
+                    &mut compressed,
+                    BROTLI_BUFFER_SIZE,
+                    BROTLI_QUALITY,
+                    BROTLI_WINDOW_SIZE,
+                );
+                encoder.write_all(data)?;
+            }
+            Ok(compressed)
+        }
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => {
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+            encoder.write_all(data)?;
+            encoder.finish().map_err(io::Error::other)
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err(unsupported(compression)),
+    }
+}
+
+/// Decompresses `data` that was compressed with the given `compression` format.
+pub fn decompress(data: &[u8], compression: Compression) -> io::Result<Vec<u8>> {
+    match compression {
+        Compression::None => Ok(data.to_vec()),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => read_all(flate2::read::GzDecoder::new(data)),
+        #[cfg(feature = "zlib")]
+        Compression::Zlib => read_all(flate2::read::ZlibDecoder::new(data)),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => zstd::decode_all(data),
+        #[cfg(feature = "brotli")]
+        Compression::Brotli => read_all(brotli::Decompressor::new(data, BROTLI_BUFFER_SIZE)),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => read_all(lz4_flex::frame::FrameDecoder::new(data)),
+        #[allow(unreachable_patterns)]
+        _ => Err(unsupported(compression)),
+    }
+}
+
+#[cfg(any(
+    feature = "gzip",
+    feature = "zlib",
+    feature = "brotli",
+    feature = "lz4"
+))]
+fn read_all(mut reader: impl Read) -> io::Result<Vec<u8>> {
+    let mut decompressed = Vec::new();
+    reader.read_to_end(&mut decompressed)?;
+    Ok(decompressed)
+}
+
+fn invalid_data(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn unsupported(compression: Compression) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("{compression:?} compression requires its cargo feature to be enabled"),
+    )
+}
@@ -2,7 +2,7 @@ use std::{fmt::Display, str::from_utf8};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::types::Seed;
+use crate::{seed::VariableSeed, types::Seed};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -57,6 +57,11 @@ impl<T: BorshSerialize + BorshDeserialize + Display> From<Option<T>> for Remaind
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RemainderOptionSeed {
     Some(Seed),
+    /// A variable-length seed, which is only used to allocate PDA buffers.
+    ///
+    /// Since the variant cannot be told apart from a fixed seed followed by other
+    /// values, it is never deserialized.
+    Variable(VariableSeed),
     None,
 }
 
@@ -64,6 +69,7 @@ impl Display for RemainderOptionSeed {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RemainderOptionSeed::Some(item) => from_utf8(item).map_err(|_| std::fmt::Error)?.fmt(f),
+            RemainderOptionSeed::Variable(item) => item.fmt(f),
             RemainderOptionSeed::None => Ok(()),
         }
     }
@@ -76,6 +82,7 @@ impl BorshSerialize for RemainderOptionSeed {
     {
         match &self {
             Self::Some(item) => item.serialize(writer),
+            Self::Variable(item) => item.serialize(writer),
             Self::None => Ok(()),
         }
     }
@@ -102,6 +109,61 @@ impl From<Option<Seed>> for RemainderOptionSeed {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RemainderOptionCapacity {
+    Some(u32),
+    None,
+}
+
+impl Display for RemainderOptionCapacity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemainderOptionCapacity::Some(item) => item.fmt(f),
+            RemainderOptionCapacity::None => Ok(()),
+        }
+    }
+}
+
+impl BorshSerialize for RemainderOptionCapacity {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized,
+    {
+        match &self {
+            Self::Some(item) => item.serialize(writer),
+            Self::None => Ok(()),
+        }
+    }
+}
+
+impl BorshDeserialize for RemainderOptionCapacity {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        match u32::deserialize_reader(reader) {
+            Ok(item) => Ok(RemainderOptionCapacity::Some(item)),
+            Err(_) => Ok(RemainderOptionCapacity::None),
+        }
+    }
+}
+
+impl From<Option<u32>> for RemainderOptionCapacity {
+    fn from(item: Option<u32>) -> Self {
+        match item {
+            Some(item) => RemainderOptionCapacity::Some(item),
+            None => RemainderOptionCapacity::None,
+        }
+    }
+}
+
+/// Optional bytes taking the remainder of the instruction data.
+///
+/// The bytes are serialized as-is, without the `u32` length prefix of a borsh
+/// `Vec<u8>`, since the program reads the data of the `Write`, `SetData`,
+/// `SetDataChecked` and `Initialize` instructions up to the end of the
+/// instruction data.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RemainderOptionBytes {
@@ -125,8 +187,10 @@ impl BorshSerialize for RemainderOptionBytes {
     where
         Self: Sized,
     {
+        // The bytes take the remainder of the data, so they are written
+        // without a length prefix.
         match &self {
-            Self::Some(item) => item.serialize(writer),
+            Self::Some(item) => writer.write_all(item),
             Self::None => Ok(()),
         }
     }
@@ -137,13 +201,31 @@ impl BorshDeserialize for RemainderOptionBytes {
     where
         Self: Sized,
     {
-        match Vec::<u8>::deserialize_reader(reader) {
-            Ok(item) => Ok(RemainderOptionBytes::Some(item)),
-            Err(_) => Ok(RemainderOptionBytes::None),
+        let mut item = Vec::new();
+        reader.read_to_end(&mut item)?;
+
+        if item.is_empty() {
+            Ok(RemainderOptionBytes::None)
+        } else {
+            Ok(RemainderOptionBytes::Some(item))
         }
     }
 }
 
+impl RemainderOptionBytes {
+    /// Returns the bytes selecting a range of the source account data.
+    ///
+    /// This is used as the `data` argument of the `Write`, `SetData` and
+    /// `SetDataChecked` instructions to copy `length` bytes starting at
+    /// `offset` of the data of the source account, instead of all its data.
+    pub fn source_range(offset: u32, length: u32) -> Self {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&length.to_le_bytes());
+        RemainderOptionBytes::Some(bytes)
+    }
+}
+
 impl From<Option<Vec<u8>>> for RemainderOptionBytes {
     fn from(item: Option<Vec<u8>>) -> Self {
         match item {
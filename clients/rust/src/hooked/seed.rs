@@ -0,0 +1,166 @@
+//! Helpers to derive metadata accounts from variable-length seeds.
+//!
+//! Metadata and buffer accounts are derived either from a fixed 16-byte
+//! [`Seed`](crate::types::Seed) or from a [`VariableSeed`] of up to [`MAX_SEED_LEN`] bytes,
+//! which is created with the [`InitializeWithSeed`](crate::instructions::InitializeWithSeed)
+//! instruction. The bytes of a variable-length seed past [`SEED_LEN`] are
+//! stored in the [`SEED_EXTENSION_TYPE`] extension of the metadata account.
+
+use std::{fmt::Display, str::from_utf8};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_address::Address;
+use solana_instruction::Instruction;
+
+use crate::{
+    accounts::Metadata,
+    instructions::{AllocateBuilder, InitializeWithSeedBuilder, MoveBuilder},
+    RemainderOptionSeed,
+};
+
+/// Length of a fixed seed.
+pub const SEED_LEN: usize = 16;
+
+/// Maximum length of a variable-length seed.
+pub const MAX_SEED_LEN: usize = 32;
+
+/// Type of the extension holding the bytes of a variable-length seed past
+/// [`SEED_LEN`].
+pub const SEED_EXTENSION_TYPE: u16 = u16::MAX;
+
+/// Returns the address and bump of a metadata (or PDA buffer) account.
+///
+/// Canonical accounts are derived without an `authority`. The `seed` is
+/// either a fixed 16-byte [`Seed`](crate::types::Seed) or a variable-length seed.
+pub fn find_metadata_address(
+    program: &Address,
+    authority: Option<&Address>,
+    seed: &[u8],
+) -> (Address, u8) {
+    match authority {
+        Some(authority) => {
+            Address::find_program_address(&[program.as_ref(), authority.as_ref(), seed], &crate::ID)
+        }
+        None => Address::find_program_address(&[program.as_ref(), seed], &crate::ID),
+    }
+}
+
+/// Encodes a variable-length seed as its length and its zero-padded bytes.
+///
+/// Returns `None` if the seed is empty or longer than [`MAX_SEED_LEN`] bytes.
+pub fn encode(seed: &[u8]) -> Option<(u8, [u8; MAX_SEED_LEN])> {
+    if seed.is_empty() {
+        return None;
+    }
+    let mut encoded = [0u8; MAX_SEED_LEN];
+    encoded.get_mut(..seed.len())?.copy_from_slice(seed);
+    Some((seed.len() as u8, encoded))
+}
+
+/// A variable-length seed, encoded as its length followed by its zero-padded
+/// bytes.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VariableSeed {
+    length: u8,
+    bytes: [u8; MAX_SEED_LEN],
+}
+
+impl VariableSeed {
+    /// Creates a variable-length seed.
+    ///
+    /// Returns `None` if the seed is empty or longer than [`MAX_SEED_LEN`] bytes.
+    pub fn new(seed: &[u8]) -> Option<Self> {
+        let (length, bytes) = encode(seed)?;
+        Some(Self { length, bytes })
+    }
+
+    /// Returns the bytes of the seed, without padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes
+            .get(..self.length as usize)
+            .unwrap_or(&self.bytes)
+    }
+}
+
+impl Display for VariableSeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        from_utf8(self.as_bytes())
+            .map_err(|_| std::fmt::Error)?
+            .fmt(f)
+    }
+}
+
+/// Returns an `Allocate` instruction for a PDA buffer derived from a
+/// variable-length seed.
+///
+/// The buffer can then be written to and initialized with the
+/// [`InitializeWithSeed`](crate::instructions::InitializeWithSeed) instruction.
+/// Returns `None` if the seed is empty or longer than [`MAX_SEED_LEN`] bytes.
+pub fn allocate(
+    buffer: Address,
+    authority: Address,
+    program: Address,
+    program_data: Option<Address>,
+    seed: &[u8],
+    capacity: Option<u32>,
+) -> Option<Instruction> {
+    Some(
+        AllocateBuilder::new()
+            .buffer(buffer)
+            .authority(authority)
+            .program(Some(program))
+            .program_data(program_data)
+            .seed(RemainderOptionSeed::Variable(VariableSeed::new(seed)?))
+            .capacity(capacity.into())
+            .instruction(),
+    )
+}
+
+impl InitializeWithSeedBuilder {
+    /// Sets the variable-length seed of the metadata account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seed is empty or longer than [`MAX_SEED_LEN`] bytes.
+    pub fn variable_seed(&mut self, seed: &[u8]) -> &mut Self {
+        self.seed(VariableSeed::new(seed).expect("invalid variable-length seed"))
+    }
+}
+
+impl MoveBuilder {
+    /// Sets the variable-length seed of the new metadata account.
+    ///
+    /// The new metadata account uses a variable-length seed even if the current
+    /// one uses a fixed seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seed is empty or longer than [`MAX_SEED_LEN`] bytes.
+    pub fn new_variable_seed(&mut self, seed: &[u8]) -> &mut Self {
+        self.new_seed(VariableSeed::new(seed).expect("invalid variable-length seed"))
+            .variable_seed(true)
+    }
+}
+
+impl Metadata {
+    /// Returns the seed used to derive the metadata account.
+    ///
+    /// For variable-length seeds, this includes the bytes stored in the
+    /// [`SEED_EXTENSION_TYPE`] extension. Returns `None` if the extension is
+    /// missing.
+    pub fn derivation_seed(&self) -> Option<Vec<u8>> {
+        let length = match self.seed_length as usize {
+            0 => SEED_LEN,
+            length => length,
+        };
+
+        if length <= SEED_LEN {
+            return self.seed.get(..length).map(<[u8]>::to_vec);
+        }
+
+        let mut seed = self.seed.to_vec();
+        seed.extend_from_slice(self.extension(SEED_EXTENSION_TYPE)?);
+        (seed.len() == length).then_some(seed)
+    }
+}
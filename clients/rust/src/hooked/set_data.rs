@@ -0,0 +1,29 @@
+//! Helpers for the `SetData` and `SetDataChecked` instruction builders.
+
+use solana_address::Address;
+
+use crate::instructions::{SetDataBuilder, SetDataCheckedBuilder};
+
+impl SetDataBuilder {
+    /// Closes the buffer account after its data is copied, moving its
+    /// lamports to the `destination` account.
+    ///
+    /// The `destination` account also receives the lamports of the metadata
+    /// account above its rent-exempt minimum balance. The authority must also
+    /// be the buffer authority.
+    pub fn close_buffer(&mut self, destination: Address) -> &mut Self {
+        self.destination(Some(destination))
+    }
+}
+
+impl SetDataCheckedBuilder {
+    /// Closes the buffer account after its data is copied, moving its
+    /// lamports to the `destination` account.
+    ///
+    /// The `destination` account also receives the lamports of the metadata
+    /// account above its rent-exempt minimum balance. The authority must also
+    /// be the buffer authority.
+    pub fn close_buffer(&mut self, destination: Address) -> &mut Self {
+        self.destination(Some(destination))
+    }
+}
@@ -8,3 +8,7 @@ mod hooked;
 
 pub use generated::programs::PROGRAM_METADATA_ID as ID;
 pub use generated::*;
+pub use hooked::{
+    chunked, content, decode, diff, extend, fetcher, pack, seed, RemainderOptionBytes,
+    RemainderOptionCapacity, RemainderOptionSeed, VariableSeed,
+};
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use solana_address::Address;
+use spl_program_metadata_client::{
+    chunked::{parse, reassemble, CHUNK_DATA_LEN},
+    types::ChunkData,
+};
+
+fn address(seed: u8) -> Address {
+    Address::new_from_array([seed; 32])
+}
+
+fn chunk(address: Address, offset: u32, length: u32) -> ChunkData {
+    ChunkData {
+        address,
+        offset,
+        length,
+    }
+}
+
+#[test]
+fn test_parse_chunks() {
+    let chunks = vec![chunk(address(1), 96, 10_000), chunk(address(2), 0, 42)];
+    let data: Vec<u8> = chunks
+        .iter()
+        .flat_map(|chunk| borsh::to_vec(chunk).unwrap())
+        .collect();
+
+    assert_eq!(data.len(), 2 * CHUNK_DATA_LEN);
+    assert_eq!(parse(&data).unwrap(), chunks);
+}
+
+#[test]
+fn test_reassemble_chunks() {
+    let first = address(1);
+    let second = address(2);
+    let accounts = HashMap::from([
+        (first, b"__Hello, ".to_vec()),
+        (second, b"World!__".to_vec()),
+    ]);
+
+    let content = reassemble(
+        &[chunk(first, 2, 7), chunk(second, 0, 6), chunk(first, 2, 5)],
+        |address| accounts.get(address).map(Vec::as_slice),
+    )
+    .unwrap();
+
+    assert_eq!(content, b"Hello, World!Hello");
+}
+
+#[test]
+fn fail_parse_partial_chunk() {
+    assert!(parse(&[0u8; CHUNK_DATA_LEN + 4]).is_err());
+    assert!(parse(&[]).is_err());
+}
+
+#[test]
+fn fail_reassemble_missing_account() {
+    let content = reassemble(&[chunk(address(1), 0, 1)], |_| None);
+
+    assert!(content.is_err());
+}
+
+#[test]
+fn fail_reassemble_out_of_bounds_chunk() {
+    let data = [1u8; 10];
+
+    let content = reassemble(&[chunk(address(1), 5, 6)], |_| Some(&data[..]));
+
+    assert!(content.is_err());
+}
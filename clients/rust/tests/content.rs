@@ -0,0 +1,75 @@
+use std::{collections::HashMap, io};
+
+use spl_program_metadata_client::{
+    content::{digest, resolve, verify, ContentResolver},
+    types::{ContentData, ContentProtocol},
+};
+
+const CID: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+const CONTENT: &[u8] = b"{\"name\":\"program-metadata\"}";
+
+/// Local stand-in for a gateway.
+struct LocalResolver(HashMap<String, Vec<u8>>);
+
+impl ContentResolver for LocalResolver {
+    fn resolve(&self, _protocol: ContentProtocol, identifier: &str) -> io::Result<Vec<u8>> {
+        self.0
+            .get(identifier)
+            .cloned()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+fn content_data(digest: [u8; 32]) -> ContentData {
+    ContentData {
+        protocol: ContentProtocol::Ipfs,
+        digest,
+        identifier: CID.into(),
+    }
+}
+
+#[test]
+fn test_resolve_content() {
+    let resolver = LocalResolver(HashMap::from([(CID.to_string(), CONTENT.to_vec())]));
+    let data = content_data(digest(CONTENT));
+
+    assert_eq!(resolve(&resolver, &data).unwrap(), CONTENT);
+}
+
+#[test]
+fn test_resolve_content_with_closure() {
+    let resolver = |protocol: ContentProtocol, identifier: &str| {
+        assert_eq!(protocol, ContentProtocol::Ipfs);
+        assert_eq!(identifier, CID);
+        Ok(CONTENT.to_vec())
+    };
+    let data = content_data(digest(CONTENT));
+
+    assert_eq!(resolve(&resolver, &data).unwrap(), CONTENT);
+}
+
+#[test]
+fn test_content_data_round_trip() {
+    let data = content_data(digest(CONTENT));
+    let bytes = borsh::to_vec(&data).unwrap();
+
+    assert_eq!(bytes.len(), 1 + 32 + CID.len());
+    assert_eq!(borsh::from_slice::<ContentData>(&bytes).unwrap(), data);
+}
+
+#[test]
+fn fail_resolve_tampered_content() {
+    let resolver = LocalResolver(HashMap::from([(CID.to_string(), b"tampered".to_vec())]));
+    let data = content_data(digest(CONTENT));
+
+    let error = resolve(&resolver, &data).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn fail_verify_with_wrong_digest() {
+    let data = content_data([0; 32]);
+
+    assert!(verify(&data, CONTENT).is_err());
+}
@@ -0,0 +1,116 @@
+use solana_address::Address;
+use spl_program_metadata_client::{
+    diff::{apply, diff, instructions, DataPatch, PATCH_OVERHEAD},
+    instructions::PATCH_DISCRIMINATOR,
+};
+
+#[test]
+fn test_diff_unchanged_content() {
+    let content = b"unchanged".to_vec();
+    assert!(diff(&content, &content, 100).is_empty());
+}
+
+#[test]
+fn test_diff_single_change() {
+    let old = b"hello world".to_vec();
+    let new = b"hello there".to_vec();
+
+    let patches = diff(&old, &new, 100);
+
+    assert_eq!(
+        patches,
+        vec![DataPatch {
+            offset: 6,
+            bytes: b"there".to_vec(),
+        }]
+    );
+    assert_eq!(apply(&old, &patches, new.len()).unwrap(), new);
+}
+
+#[test]
+fn test_diff_merges_close_changes() {
+    let old = vec![0u8; 64];
+    let mut new = old.clone();
+    new[10] = 1;
+    new[10 + PATCH_OVERHEAD - 1] = 1;
+    new[40] = 1;
+
+    let patches = diff(&old, &new, 100);
+
+    assert_eq!(patches.len(), 2);
+    assert_eq!(patches[0].offset, 10);
+    assert_eq!(patches[0].bytes.len(), PATCH_OVERHEAD);
+    assert_eq!(patches[1].offset, 40);
+    assert_eq!(apply(&old, &patches, new.len()).unwrap(), new);
+}
+
+#[test]
+fn test_diff_splits_large_changes() {
+    let old = vec![0u8; 25];
+    let new = vec![1u8; 25];
+
+    let patches = diff(&old, &new, 10);
+
+    assert_eq!(
+        patches.iter().map(|p| p.offset).collect::<Vec<_>>(),
+        vec![0, 10, 20]
+    );
+    assert!(patches.iter().all(|p| p.bytes.len() <= 10));
+    assert_eq!(apply(&old, &patches, new.len()).unwrap(), new);
+}
+
+#[test]
+fn test_diff_resizes_content() {
+    let old = b"content".to_vec();
+
+    // Truncating only needs an empty patch.
+    let truncated = b"con".to_vec();
+    let patches = diff(&old, &truncated, 100);
+    assert_eq!(
+        patches,
+        vec![DataPatch {
+            offset: 0,
+            bytes: Vec::new(),
+        }]
+    );
+    assert_eq!(apply(&old, &patches, truncated.len()).unwrap(), truncated);
+
+    // Growing only writes the bytes that differ from the zero extension.
+    let mut extended = old.clone();
+    extended.extend_from_slice(&[0, 0, 0, 7]);
+    let patches = diff(&old, &extended, 100);
+    assert_eq!(
+        patches,
+        vec![DataPatch {
+            offset: 10,
+            bytes: vec![7],
+        }]
+    );
+    assert_eq!(apply(&old, &patches, extended.len()).unwrap(), extended);
+}
+
+#[test]
+fn test_apply_out_of_bounds_patch() {
+    let patches = vec![DataPatch {
+        offset: 4,
+        bytes: vec![1, 2],
+    }];
+    assert!(apply(&[0u8; 8], &patches, 5).is_none());
+}
+
+#[test]
+fn test_patch_instructions_data() {
+    let metadata = Address::new_from_array([1; 32]);
+    let authority = Address::new_from_array([2; 32]);
+
+    let instructions = instructions(metadata, authority, None, None, b"abcd", b"abXde", 100);
+
+    assert_eq!(instructions.len(), 1);
+    let mut expected = vec![PATCH_DISCRIMINATOR];
+    expected.extend_from_slice(&2u32.to_le_bytes());
+    expected.extend_from_slice(&5u32.to_le_bytes());
+    expected.extend_from_slice(b"Xde");
+    assert_eq!(instructions[0].data, expected);
+    assert_eq!(instructions[0].accounts.len(), 6);
+    assert_eq!(instructions[0].accounts[0].pubkey, metadata);
+}
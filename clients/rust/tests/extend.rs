@@ -0,0 +1,61 @@
+use solana_address::Address;
+use spl_program_metadata_client::{
+    extend::{instructions, lengths, MAX_EXTEND_LENGTH},
+    instructions::EXTEND_DISCRIMINATOR,
+    ID,
+};
+
+#[test]
+fn test_lengths_within_a_single_instruction() {
+    assert_eq!(lengths(96, 196), vec![100]);
+    assert_eq!(
+        lengths(96, 96 + MAX_EXTEND_LENGTH as usize),
+        vec![MAX_EXTEND_LENGTH]
+    );
+}
+
+#[test]
+fn test_lengths_across_instructions() {
+    let max = MAX_EXTEND_LENGTH as usize;
+    assert_eq!(
+        lengths(0, 2 * max + 1),
+        vec![MAX_EXTEND_LENGTH, MAX_EXTEND_LENGTH, 1]
+    );
+}
+
+#[test]
+fn test_lengths_without_growth() {
+    assert!(lengths(200, 200).is_empty());
+    assert!(lengths(200, 100).is_empty());
+}
+
+#[test]
+fn test_instructions() {
+    let account = Address::new_from_array([1; 32]);
+    let authority = Address::new_from_array([2; 32]);
+    let payer = Address::new_from_array([3; 32]);
+
+    let new_len = 96 + MAX_EXTEND_LENGTH as usize + 10;
+    let instructions = instructions(account, authority, None, None, Some(payer), 96, new_len);
+
+    assert_eq!(instructions.len(), 2);
+
+    let mut data = vec![EXTEND_DISCRIMINATOR];
+    data.extend_from_slice(&MAX_EXTEND_LENGTH.to_le_bytes());
+    assert_eq!(instructions[0].data, data);
+
+    let mut data = vec![EXTEND_DISCRIMINATOR];
+    data.extend_from_slice(&10u32.to_le_bytes());
+    assert_eq!(instructions[1].data, data);
+
+    for instruction in &instructions {
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(instruction.accounts[0].pubkey, account);
+        assert_eq!(instruction.accounts[1].pubkey, authority);
+        // Missing optional accounts are replaced by the program ID.
+        assert_eq!(instruction.accounts[2].pubkey, ID);
+        assert_eq!(instruction.accounts[3].pubkey, ID);
+        assert_eq!(instruction.accounts[4].pubkey, payer);
+        assert!(instruction.accounts[4].is_signer);
+    }
+}
@@ -0,0 +1,177 @@
+use std::{collections::HashMap, io};
+
+use solana_address::Address;
+use spl_program_metadata_client::{
+    accounts::Metadata,
+    content::digest,
+    fetcher::{external_content, follow_aliases, verify_external, MAX_ALIAS_DEPTH},
+    types::{DataSource, ExternalData, ExternalDataV2},
+};
+
+fn address(seed: u8) -> Address {
+    Address::new_from_array([seed; 32])
+}
+
+/// Serializes a metadata account with the given data source and content.
+fn metadata(data_source: DataSource, content: &[u8]) -> Metadata {
+    let mut data = vec![0u8; 96];
+    data[0] = 2; // discriminator
+    data[65] = 1; // mutable
+    data[86] = data_source as u8;
+    data[87..91].copy_from_slice(&(content.len() as u32).to_le_bytes());
+    data[94] = 1; // version
+    data.extend_from_slice(content);
+    Metadata::from_bytes(&data).unwrap()
+}
+
+fn alias(target: &Address) -> Metadata {
+    metadata(DataSource::Alias, target.as_ref())
+}
+
+fn fetch_from(
+    accounts: &HashMap<Address, Metadata>,
+) -> impl FnMut(&Address) -> io::Result<Metadata> + '_ {
+    |address| {
+        accounts
+            .get(address)
+            .cloned()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+#[test]
+fn test_follow_aliases() {
+    let accounts = HashMap::from([
+        (address(2), alias(&address(3))),
+        (address(3), metadata(DataSource::Direct, b"Hello, World!")),
+    ]);
+
+    let (resolved, metadata) =
+        follow_aliases(address(1), alias(&address(2)), fetch_from(&accounts)).unwrap();
+
+    assert_eq!(resolved, address(3));
+    assert_eq!(metadata.content(), b"Hello, World!");
+}
+
+#[test]
+fn test_follow_aliases_without_alias() {
+    let accounts = HashMap::new();
+
+    let (resolved, metadata) = follow_aliases(
+        address(1),
+        metadata(DataSource::Direct, b"Hello"),
+        fetch_from(&accounts),
+    )
+    .unwrap();
+
+    assert_eq!(resolved, address(1));
+    assert_eq!(metadata.content(), b"Hello");
+}
+
+#[test]
+fn test_external_content() {
+    let data = b"__Hello, World!__";
+    let external = |length: Option<u32>| ExternalData {
+        address: address(1),
+        offset: 2,
+        length: length.into(),
+    };
+
+    assert_eq!(
+        external_content(&external(Some(5)), data).unwrap(),
+        b"Hello"
+    );
+    assert_eq!(
+        external_content(&external(None), data).unwrap(),
+        b"Hello, World!__"
+    );
+    assert!(external_content(&external(Some(20)), data).is_err());
+}
+
+#[test]
+fn fail_follow_alias_cycle() {
+    let accounts = HashMap::from([
+        (address(2), alias(&address(3))),
+        (address(3), alias(&address(1))),
+    ]);
+
+    let error = follow_aliases(address(1), alias(&address(2)), fetch_from(&accounts)).unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn fail_follow_self_alias() {
+    let accounts = HashMap::new();
+
+    let error = follow_aliases(address(1), alias(&address(1)), fetch_from(&accounts)).unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn fail_follow_too_many_aliases() {
+    // Chain of distinct aliases longer than the maximum depth.
+    let accounts: HashMap<Address, Metadata> = (2..=MAX_ALIAS_DEPTH as u8 + 2)
+        .map(|seed| (address(seed), alias(&address(seed + 1))))
+        .collect();
+
+    let error = follow_aliases(address(1), alias(&address(2)), fetch_from(&accounts)).unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+fn external_v2(digest: [u8; 32]) -> ExternalDataV2 {
+    ExternalDataV2 {
+        address: address(1),
+        offset: 2,
+        length: Some(5).into(),
+        owner: address(9),
+        digest,
+    }
+}
+
+#[test]
+fn test_verify_external_v2() {
+    let data = b"__Hello, World!__";
+    let external = external_v2(digest(b"Hello"));
+
+    assert_eq!(
+        verify_external(&external, &address(9), data).unwrap(),
+        b"Hello"
+    );
+}
+
+#[test]
+fn test_read_external_data_versions() {
+    let v1 = ExternalData {
+        address: address(1),
+        offset: 2,
+        length: None.into(),
+    };
+    let v2 = external_v2(digest(b"Hello"));
+
+    let metadata_v1 = metadata(DataSource::External, &borsh::to_vec(&v1).unwrap());
+    assert_eq!(metadata_v1.external_data().unwrap(), v1);
+    assert_eq!(metadata_v1.external_data_v2().unwrap(), None);
+
+    let metadata_v2 = metadata(DataSource::External, &borsh::to_vec(&v2).unwrap());
+    assert_eq!(metadata_v2.external_data().unwrap().address, v2.address);
+    assert_eq!(metadata_v2.external_data_v2().unwrap(), Some(v2));
+}
+
+#[test]
+fn fail_verify_external_v2_with_wrong_owner() {
+    let data = b"__Hello, World!__";
+    let external = external_v2(digest(b"Hello"));
+
+    assert!(verify_external(&external, &address(8), data).is_err());
+}
+
+#[test]
+fn fail_verify_external_v2_with_modified_data() {
+    let data = b"__Howdy, World!__";
+    let external = external_v2(digest(b"Hello"));
+
+    assert!(verify_external(&external, &address(9), data).is_err());
+}
@@ -0,0 +1,34 @@
+use solana_address::Address;
+use spl_program_metadata_client::{
+    instructions::InitializeBuilder,
+    types::{Compression, DataSource, Encoding, Format},
+    RemainderOptionBytes,
+};
+
+#[test]
+fn test_initialize_closes_source_buffer() {
+    let source_buffer = Address::new_from_array([4; 32]);
+    let destination = Address::new_from_array([5; 32]);
+
+    let instruction = InitializeBuilder::new()
+        .metadata(Address::new_from_array([1; 32]))
+        .authority(Address::new_from_array([2; 32]))
+        .program(Address::new_from_array([3; 32]))
+        .seed([0; 16])
+        .encoding(Encoding::Utf8)
+        .compression(Compression::None)
+        .format(Format::Json)
+        .data_source(DataSource::Direct)
+        .data(RemainderOptionBytes::None)
+        .close_source_buffer(source_buffer, destination)
+        .instruction();
+
+    assert_eq!(instruction.accounts.len(), 8);
+    assert_eq!(instruction.accounts[5].pubkey, source_buffer);
+    assert!(instruction.accounts[5].is_writable);
+    assert_eq!(instruction.accounts[6].pubkey, destination);
+    assert!(instruction.accounts[6].is_writable);
+    // Only the fixed part of the instruction data and the (empty) hash option
+    // are sent.
+    assert_eq!(instruction.data.len(), 22);
+}
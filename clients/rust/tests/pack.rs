@@ -0,0 +1,56 @@
+use spl_program_metadata_client::{
+    pack::{decode_data, encode_data, pack_data, unpack_data},
+    types::{Compression, Encoding},
+};
+
+const ENCODINGS: [Encoding; 5] = [
+    Encoding::None,
+    Encoding::Utf8,
+    Encoding::Base58,
+    Encoding::Base64,
+    Encoding::Base16,
+];
+
+#[test]
+fn test_base16_round_trip() {
+    let content = "deadbeef00ff";
+
+    let data = encode_data(content, Encoding::Base16).unwrap();
+    assert_eq!(data, [0xde, 0xad, 0xbe, 0xef, 0x00, 0xff]);
+
+    let decoded = decode_data(&data, Encoding::Base16).unwrap();
+    assert_eq!(decoded, content);
+}
+
+#[test]
+fn test_base16_accepts_uppercase() {
+    let data = encode_data("DEADBEEF", Encoding::Base16).unwrap();
+    assert_eq!(data, [0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[test]
+fn test_round_trip_with_every_encoding() {
+    let bytes = b"Hello, World!";
+
+    for encoding in ENCODINGS {
+        let content = decode_data(bytes, encoding).unwrap();
+        assert_eq!(encode_data(&content, encoding).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn test_pack_and_unpack_base16_data() {
+    let content = "48656c6c6f2c20576f726c6421";
+
+    let packed = pack_data(content, Encoding::Base16, Compression::None).unwrap();
+    assert_eq!(packed, b"Hello, World!");
+
+    let unpacked = unpack_data(&packed, Encoding::Base16, Compression::None).unwrap();
+    assert_eq!(unpacked, content);
+}
+
+#[test]
+fn fail_encode_invalid_base16_data() {
+    assert!(encode_data("not hex", Encoding::Base16).is_err());
+    assert!(encode_data("abc", Encoding::Base16).is_err());
+}
@@ -0,0 +1,83 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_address::Address;
+use spl_program_metadata_client::{
+    instructions::{SetDataBuilder, SET_DATA_DISCRIMINATOR},
+    types::{Compression, DataSource, Encoding, Format},
+    RemainderOptionBytes,
+};
+
+#[test]
+fn test_serialize_remainder_bytes_without_length_prefix() {
+    let bytes = borsh::to_vec(&RemainderOptionBytes::Some(vec![1, 2, 3])).unwrap();
+    assert_eq!(bytes, [1, 2, 3]);
+}
+
+#[test]
+fn test_serialize_missing_remainder_bytes() {
+    let bytes = borsh::to_vec(&RemainderOptionBytes::None).unwrap();
+    assert!(bytes.is_empty());
+}
+
+#[test]
+fn test_deserialize_remainder_bytes() {
+    let mut bytes: &[u8] = &[1, 2, 3];
+    assert_eq!(
+        RemainderOptionBytes::deserialize(&mut bytes).unwrap(),
+        RemainderOptionBytes::Some(vec![1, 2, 3])
+    );
+
+    let mut bytes: &[u8] = &[];
+    assert_eq!(
+        RemainderOptionBytes::deserialize(&mut bytes).unwrap(),
+        RemainderOptionBytes::None
+    );
+}
+
+#[test]
+fn test_remainder_bytes_round_trip() {
+    let data = RemainderOptionBytes::Some(b"https://example.com/idl.json".to_vec());
+
+    let mut bytes = Vec::new();
+    data.serialize(&mut bytes).unwrap();
+
+    assert_eq!(RemainderOptionBytes::try_from_slice(&bytes).unwrap(), data);
+}
+
+#[test]
+fn test_set_data_instruction_data() {
+    let instruction = SetDataBuilder::new()
+        .metadata(Address::new_from_array([1; 32]))
+        .authority(Address::new_from_array([2; 32]))
+        .encoding(Encoding::Utf8)
+        .compression(Compression::None)
+        .format(Format::Json)
+        .data_source(DataSource::Direct)
+        .data(RemainderOptionBytes::Some(vec![7, 8, 9]))
+        .instruction();
+
+    // The data follows the fixed arguments without a length prefix.
+    assert_eq!(
+        instruction.data,
+        [SET_DATA_DISCRIMINATOR, 1, 0, 1, 0, 0, 7, 8, 9]
+    );
+}
+
+#[test]
+fn test_set_data_instruction_data_with_hash() {
+    let instruction = SetDataBuilder::new()
+        .metadata(Address::new_from_array([1; 32]))
+        .authority(Address::new_from_array([2; 32]))
+        .encoding(Encoding::Utf8)
+        .compression(Compression::None)
+        .format(Format::Json)
+        .hash([5; 32])
+        .data_source(DataSource::Direct)
+        .data(RemainderOptionBytes::Some(vec![7, 8, 9]))
+        .instruction();
+
+    // The hash option precedes the data source and the data.
+    let mut expected = vec![SET_DATA_DISCRIMINATOR, 1, 0, 1, 1];
+    expected.extend_from_slice(&[5; 32]);
+    expected.extend_from_slice(&[0, 7, 8, 9]);
+    assert_eq!(instruction.data, expected);
+}
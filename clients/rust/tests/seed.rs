@@ -0,0 +1,164 @@
+use solana_address::Address;
+use spl_program_metadata_client::{
+    accounts::{Metadata, PendingAuthority},
+    instructions::{
+        InitializeWithSeedBuilder, MoveBuilder, ALLOCATE_DISCRIMINATOR, MOVE_DISCRIMINATOR,
+    },
+    seed::{allocate, encode, find_metadata_address, MAX_SEED_LEN, SEED_EXTENSION_TYPE},
+    types::{Compression, DataSource, Encoding, Format},
+    RemainderOptionBytes, VariableSeed, ID,
+};
+
+const LONG_SEED: &[u8] = b"com.acme.audit-report-2025";
+
+/// Serializes a metadata account derived from the given variable-length seed.
+#[allow(clippy::arithmetic_side_effects)]
+fn metadata(seed: &[u8]) -> Metadata {
+    let mut data = vec![0u8; 96];
+    data[0] = 2; // discriminator
+    data[65] = 1; // mutable
+    let prefix = seed.len().min(16);
+    data[67..67 + prefix].copy_from_slice(&seed[..prefix]);
+    data[94] = 1; // version
+    data[95] = seed.len() as u8; // seed length
+
+    if let Some(remaining) = seed.get(16..).filter(|remaining| !remaining.is_empty()) {
+        data[91] = 0b0000_0010; // extension area
+        let area_len = (6 + remaining.len()).next_multiple_of(8);
+        data.extend_from_slice(&(area_len as u16).to_le_bytes());
+        data.extend_from_slice(&SEED_EXTENSION_TYPE.to_le_bytes());
+        data.extend_from_slice(&(remaining.len() as u16).to_le_bytes());
+        data.extend_from_slice(remaining);
+        data.resize(96 + area_len, 0);
+    }
+
+    Metadata::from_bytes(&data).unwrap()
+}
+
+#[test]
+fn test_encode() {
+    let (length, encoded) = encode(LONG_SEED).unwrap();
+
+    assert_eq!(length as usize, LONG_SEED.len());
+    assert_eq!(&encoded[..LONG_SEED.len()], LONG_SEED);
+    assert!(encoded[LONG_SEED.len()..].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn test_encode_invalid_length() {
+    assert!(encode(&[]).is_none());
+    assert!(encode(&[1; MAX_SEED_LEN + 1]).is_none());
+}
+
+#[test]
+fn test_variable_seed() {
+    let seed = VariableSeed::new(LONG_SEED).unwrap();
+
+    assert_eq!(seed.as_bytes(), LONG_SEED);
+    assert_eq!(seed.to_string(), "com.acme.audit-report-2025");
+
+    let encoded = borsh::to_vec(&seed).unwrap();
+    assert_eq!(encoded.len(), 1 + MAX_SEED_LEN);
+    assert_eq!(encoded[0] as usize, LONG_SEED.len());
+    assert_eq!(&encoded[1..1 + LONG_SEED.len()], LONG_SEED);
+
+    assert!(VariableSeed::new(&[]).is_none());
+    assert!(VariableSeed::new(&[1; MAX_SEED_LEN + 1]).is_none());
+}
+
+#[test]
+fn test_find_metadata_address() {
+    let program = Address::new_from_array([1; 32]);
+    let authority = Address::new_from_array([2; 32]);
+
+    assert_eq!(
+        find_metadata_address(&program, None, LONG_SEED),
+        Address::find_program_address(&[program.as_ref(), LONG_SEED], &ID)
+    );
+    assert_eq!(
+        find_metadata_address(&program, Some(&authority), LONG_SEED),
+        Address::find_program_address(&[program.as_ref(), authority.as_ref(), LONG_SEED], &ID)
+    );
+}
+
+#[test]
+fn test_allocate() {
+    let buffer = Address::new_from_array([1; 32]);
+    let authority = Address::new_from_array([2; 32]);
+    let program = Address::new_from_array([3; 32]);
+
+    let instruction = allocate(buffer, authority, program, None, LONG_SEED, Some(100)).unwrap();
+
+    assert_eq!(instruction.data.len(), 1 + 1 + MAX_SEED_LEN + 4);
+    assert_eq!(instruction.data[0], ALLOCATE_DISCRIMINATOR);
+    assert_eq!(instruction.data[1] as usize, LONG_SEED.len());
+    assert_eq!(&instruction.data[2..2 + LONG_SEED.len()], LONG_SEED);
+    assert_eq!(&instruction.data[34..], &100u32.to_le_bytes());
+    assert_eq!(instruction.accounts[0].pubkey, buffer);
+}
+
+#[test]
+fn test_initialize_with_variable_seed() {
+    let program = Address::new_from_array([1; 32]);
+    let authority = Address::new_from_array([2; 32]);
+    let (metadata, _) = find_metadata_address(&program, Some(&authority), LONG_SEED);
+
+    let instruction = InitializeWithSeedBuilder::new()
+        .metadata(metadata)
+        .authority(authority)
+        .program(program)
+        .variable_seed(LONG_SEED)
+        .encoding(Encoding::Utf8)
+        .compression(Compression::None)
+        .format(Format::Json)
+        .data_source(DataSource::Direct)
+        .data(RemainderOptionBytes::Some(b"{}".to_vec()))
+        .instruction();
+
+    assert_eq!(instruction.data[1] as usize, LONG_SEED.len());
+    assert_eq!(&instruction.data[2..2 + LONG_SEED.len()], LONG_SEED);
+    // The data follows the fixed arguments and the (empty) hash option.
+    assert_eq!(&instruction.data[1 + 1 + MAX_SEED_LEN + 4..], b"\0{}");
+}
+
+#[test]
+fn test_move_to_variable_seed() {
+    let program = Address::new_from_array([1; 32]);
+    let authority = Address::new_from_array([2; 32]);
+    let (metadata, _) = find_metadata_address(&program, Some(&authority), b"idl");
+    let (new_metadata, _) = find_metadata_address(&program, Some(&authority), LONG_SEED);
+    let (pending_authority, _) = PendingAuthority::find_pda(&metadata);
+
+    let instruction = MoveBuilder::new()
+        .metadata(metadata)
+        .authority(authority)
+        .new_metadata(new_metadata)
+        .program(program)
+        .pending_authority(pending_authority)
+        .destination(authority)
+        .new_variable_seed(LONG_SEED)
+        .instruction();
+
+    assert_eq!(instruction.data.len(), 1 + 1 + MAX_SEED_LEN + 1);
+    assert_eq!(instruction.data[0], MOVE_DISCRIMINATOR);
+    assert_eq!(instruction.data[1] as usize, LONG_SEED.len());
+    assert_eq!(&instruction.data[2..2 + LONG_SEED.len()], LONG_SEED);
+    // The variable-length seed is explicitly requested.
+    assert_eq!(instruction.data[1 + 1 + MAX_SEED_LEN], 1);
+    assert_eq!(instruction.accounts[6].pubkey, pending_authority);
+    assert!(instruction.accounts[6].is_writable);
+    assert_eq!(instruction.accounts[7].pubkey, authority);
+    assert!(instruction.accounts[7].is_writable);
+}
+
+#[test]
+fn test_derivation_seed() {
+    assert_eq!(
+        metadata(LONG_SEED).derivation_seed().as_deref(),
+        Some(LONG_SEED)
+    );
+    assert_eq!(
+        metadata(b"idl").derivation_seed().as_deref(),
+        Some(&b"idl"[..])
+    );
+}
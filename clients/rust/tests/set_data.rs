@@ -0,0 +1,46 @@
+use solana_address::Address;
+use spl_program_metadata_client::{
+    instructions::SetDataBuilder,
+    types::{Compression, DataSource, Encoding, Format},
+    RemainderOptionBytes, ID,
+};
+
+fn set_data_builder(buffer: Address) -> SetDataBuilder {
+    let mut builder = SetDataBuilder::new();
+    builder
+        .metadata(Address::new_from_array([1; 32]))
+        .authority(Address::new_from_array([2; 32]))
+        .buffer(Some(buffer))
+        .encoding(Encoding::Utf8)
+        .compression(Compression::None)
+        .format(Format::Json)
+        .data_source(DataSource::Direct)
+        .data(RemainderOptionBytes::None);
+    builder
+}
+
+#[test]
+fn test_set_data_keeps_buffer_by_default() {
+    let buffer = Address::new_from_array([3; 32]);
+    let instruction = set_data_builder(buffer).instruction();
+
+    assert_eq!(instruction.accounts.len(), 7);
+    assert_eq!(instruction.accounts[2].pubkey, buffer);
+    // The optional destination and external accounts default to the program id.
+    assert_eq!(instruction.accounts[5].pubkey, ID);
+    assert_eq!(instruction.accounts[6].pubkey, ID);
+}
+
+#[test]
+fn test_set_data_closes_buffer() {
+    let buffer = Address::new_from_array([3; 32]);
+    let destination = Address::new_from_array([4; 32]);
+    let instruction = set_data_builder(buffer)
+        .close_buffer(destination)
+        .instruction();
+
+    assert_eq!(instruction.accounts.len(), 7);
+    assert!(instruction.accounts[2].is_writable);
+    assert_eq!(instruction.accounts[5].pubkey, destination);
+    assert!(instruction.accounts[5].is_writable);
+}
@@ -0,0 +1,35 @@
+use solana_address::Address;
+use spl_program_metadata_client::{
+    instructions::{WriteBuilder, WRITE_DISCRIMINATOR},
+    RemainderOptionBytes,
+};
+
+#[test]
+fn test_write_instruction_data() {
+    let instruction = WriteBuilder::new()
+        .buffer(Address::new_from_array([1; 32]))
+        .authority(Address::new_from_array([2; 32]))
+        .offset(3)
+        .data(RemainderOptionBytes::Some(vec![7, 8, 9]))
+        .instruction();
+
+    assert_eq!(instruction.data, [WRITE_DISCRIMINATOR, 3, 0, 0, 0, 7, 8, 9]);
+}
+
+#[test]
+fn test_write_from_source_range_instruction_data() {
+    let source = Address::new_from_array([3; 32]);
+    let instruction = WriteBuilder::new()
+        .buffer(Address::new_from_array([1; 32]))
+        .authority(Address::new_from_array([2; 32]))
+        .source_buffer(Some(source))
+        .offset(0)
+        .data(RemainderOptionBytes::source_range(16, 32))
+        .instruction();
+
+    assert_eq!(
+        instruction.data,
+        [WRITE_DISCRIMINATOR, 0, 0, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0]
+    );
+    assert_eq!(instruction.accounts[2].pubkey, source);
+}
@@ -41,6 +41,9 @@ export default {
                                 if (c.isNode(node.item, 'definedTypeLinkNode') && node.item.name === 'seed') {
                                     return c.definedTypeLinkNode('remainderOptionSeed');
                                 }
+                                if (c.isNode(node.item, 'numberTypeNode') && node.item.format === 'u32') {
+                                    return c.definedTypeLinkNode('remainderOptionCapacity');
+                                }
                                 return node;
                             },
                         },
@@ -101,8 +104,10 @@ export default {
                                 remainderOptionBytes: 'hooked',
                                 remainderOptionPubkey: 'hooked',
                                 remainderOptionSeed: 'hooked',
+                                remainderOptionCapacity: 'hooked',
                                 zeroableOptionPubkey: 'hooked',
                                 zeroableOptionOffset: 'hooked',
+                                variableSeed: 'hooked',
                             },
                         },
                     },
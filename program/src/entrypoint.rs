@@ -6,8 +6,25 @@ use pinocchio::{
 use crate::{
     instruction::ProgramMetadataInstruction,
     processor::{
-        allocate::allocate, close::close, extend::extend, initialize::initialize,
-        set_authority::set_authority, set_data::set_data, set_immutable::set_immutable, trim::trim,
+        accept_authority::accept_authority,
+        allocate::allocate,
+        cancel_authority::cancel_authority,
+        close::close,
+        extend::extend,
+        initialize::{initialize, initialize_with_seed},
+        migrate::migrate,
+        move_metadata::move_metadata,
+        patch::patch,
+        promote::promote,
+        propose_authority::propose_authority,
+        set_authority::set_authority,
+        set_data::set_data,
+        set_data_checked::set_data_checked,
+        set_extension::set_extension,
+        set_hash::set_hash,
+        set_immutable::set_immutable,
+        trim::trim,
+        verify_hash::verify_hash,
         write::write,
     },
 };
@@ -81,5 +98,65 @@ fn process_instruction(
             cfg_log!("Instruction: Extend");
             extend(accounts, data)
         }
+        // 9 - ProposeAuthority
+        ProgramMetadataInstruction::ProposeAuthority => {
+            cfg_log!("Instruction: ProposeAuthority");
+            propose_authority(accounts, data)
+        }
+        // 10 - AcceptAuthority
+        ProgramMetadataInstruction::AcceptAuthority => {
+            cfg_log!("Instruction: AcceptAuthority");
+            accept_authority(accounts)
+        }
+        // 11 - CancelAuthority
+        ProgramMetadataInstruction::CancelAuthority => {
+            cfg_log!("Instruction: CancelAuthority");
+            cancel_authority(accounts)
+        }
+        // 12 - SetHash
+        ProgramMetadataInstruction::SetHash => {
+            cfg_log!("Instruction: SetHash");
+            set_hash(accounts, data)
+        }
+        // 13 - VerifyHash
+        ProgramMetadataInstruction::VerifyHash => {
+            cfg_log!("Instruction: VerifyHash");
+            verify_hash(accounts, data)
+        }
+        // 14 - SetDataChecked
+        ProgramMetadataInstruction::SetDataChecked => {
+            cfg_log!("Instruction: SetDataChecked");
+            set_data_checked(accounts, data)
+        }
+        // 15 - SetExtension
+        ProgramMetadataInstruction::SetExtension => {
+            cfg_log!("Instruction: SetExtension");
+            set_extension(accounts, data)
+        }
+        // 16 - Migrate
+        ProgramMetadataInstruction::Migrate => {
+            cfg_log!("Instruction: Migrate");
+            migrate(accounts)
+        }
+        // 17 - Patch
+        ProgramMetadataInstruction::Patch => {
+            cfg_log!("Instruction: Patch");
+            patch(accounts, data)
+        }
+        // 18 - Promote
+        ProgramMetadataInstruction::Promote => {
+            cfg_log!("Instruction: Promote");
+            promote(accounts, data)
+        }
+        // 19 - Move
+        ProgramMetadataInstruction::Move => {
+            cfg_log!("Instruction: Move");
+            move_metadata(accounts, data)
+        }
+        // 20 - InitializeWithSeed
+        ProgramMetadataInstruction::InitializeWithSeed => {
+            cfg_log!("Instruction: InitializeWithSeed");
+            initialize_with_seed(accounts, data)
+        }
     }
 }
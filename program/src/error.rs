@@ -16,6 +16,15 @@ pub enum ProgramMetadataError {
 
     /// 4 - The account data length is invalid.
     InvalidDataLength,
+
+    /// 5 - The metadata account content hash does not match.
+    HashMismatch,
+
+    /// 6 - The metadata account has changed since it was read.
+    MetadataChanged,
+
+    /// 7 - The account layout version is not supported.
+    UnsupportedAccountVersion,
 }
 
 impl From<ProgramMetadataError> for ProgramError {
@@ -6,33 +6,63 @@ pub enum ProgramMetadataInstruction {
     /// Writes data to a pre-funded buffer.
     ///
     /// The buffer account must be allocated and pre-funded with enough lamports
-    /// to cover the storage cost of the data being written. Additionally, the
-    /// account will be resized if the data to write is larger than its current
-    /// size.
+    /// to cover the storage cost of the data being written, unless a payer account
+    /// is provided to fund the missing lamports. Additionally, the account will be
+    /// resized if the data to write is larger than its current size.
+    ///
+    /// The data can also be copied from another buffer or from a metadata
+    /// account, in which case the data section of the metadata account is
+    /// copied. This allows existing metadata to be edited in a buffer without
+    /// uploading it again. Immutable metadata accounts can also be copied.
     ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Buffer to write to.
     /// 1. `[s]` Authority account.
-    /// 2. `[o]` Buffer to copy the data from.
+    /// 2. `[o]` Buffer or metadata account to copy the data from.
+    /// 3. `[o]` Payer account.
+    /// 4. `[o]` System program.
+    ///
+    /// When copying from an account, the instruction data can optionally hold
+    /// the offset and length of the range to copy, relative to the start of the
+    /// data of the account. Otherwise, all its data is copied.
     ///
     /// Instruction data:
     ///
     /// - `u32`: offset to write to
-    /// - `[u8]`: (optional) bytes to write
+    /// - `[u8]`: (optional) bytes to write, or the range to copy:
+    ///   - `u32`: offset to copy from
+    ///   - `u32`: length to copy
     Write,
 
     /// Initializes a metadata account.
     ///
     /// This instruction is used to create a new metadata account for a program. This can
     /// be either a new (pre-funded) account or a buffer account that has been allocated.
-    /// When not using a buffer, the data must be provided as instruction data.
+    /// Canonical metadata accounts can also be created for token mints (by the mint
+    /// authority) and Loader `v3` buffers (by the buffer authority), in which case the
+    /// mint or buffer account is provided as the program account.
+    /// When not using a buffer, the data must be provided as instruction data or copied
+    /// from a source buffer account.
     ///
-    /// There are 2 optional accounts:
+    /// The source buffer can be any buffer account controlled by the authority, i.e., the
+    /// authority must be an authority of the source buffer. When a destination account is
+    /// also provided, the source buffer is closed after its data is copied; a destination
+    /// account cannot be provided without a source buffer.
+    ///
+    /// A content hash can be recorded with the data, like the [`SetHash`](Self::SetHash)
+    /// instruction does; for `direct` data, it must be the SHA-256 digest of the data.
+    ///
+    /// There are 5 optional accounts:
     ///   - `program_data`: required to validate whether the authority is the program upgrade
-    ///     authority.
+    ///     authority. This is not required for SVM Loader `v4` programs, since their authority
+    ///     is stored on the program account.
     ///   - `system_program`: required to allocate the account. When using a pre-allocated buffer,
     ///     this is not required.
+    ///   - `source_buffer`: buffer account to copy the data from.
+    ///   - `destination`: receives the lamports of the `source_buffer` account, which is closed.
+    ///   - `external`: account referenced by `external` data that records an expected owner
+    ///     and content digest, which is required to validate the data.
     ///
     /// Accounts expected by this instruction:
     ///
@@ -41,6 +71,9 @@ pub enum ProgramMetadataInstruction {
     ///  2. `[ ]` Program account.
     ///  3. `[o]` Program data account.
     ///  4. `[o]` System program.
+    ///  5. `[o]` Source buffer account.
+    ///  6. `[o]` Destination account.
+    ///  7. `[o]` External account.
     ///
     /// Instruction data:
     ///
@@ -49,7 +82,10 @@ pub enum ProgramMetadataInstruction {
     ///  - `u8`: compression
     ///  - `u8`: format
     ///  - `u8`: data source
-    ///  - `[u8]`: (optional) bytes to write
+    ///  - `u8`: hash option (0 `=` no hash, 1 `=` record hash)
+    ///  - `[u8; 32]`: (optional) content hash
+    ///  - `[u8]`: (optional) bytes to write; when copying from a source buffer, the offset
+    ///    (`u32`) and length (`u32`) of the range of the buffer data to copy
     Initialize,
 
     /// Sets the authority of a buffer or metadata account.
@@ -100,14 +136,37 @@ pub enum ProgramMetadataInstruction {
     /// with the program upgrade authority, both program and program data
     /// accounts are required.
     ///
+    /// When copying from a buffer, a range of the buffer data can be selected,
+    /// so a single buffer can provide the data of multiple metadata accounts.
+    ///
+    /// When a destination account is provided, it receives the lamports of the
+    /// metadata account above its rent-exempt minimum balance, so shrinking the
+    /// data refunds the rent. The buffer (if any) is also closed after its data
+    /// is copied, in which case the authority must also be an authority of the
+    /// buffer account.
+    ///
+    /// Every update that changes the data source or the data increments the revision
+    /// of the metadata account, which can be used with the
+    /// [`SetDataChecked`](Self::SetDataChecked) instruction to detect concurrent updates.
+    /// The revision is stored in an extension, which is added on the first increment, so
+    /// the account must be pre-funded for the additional space.
+    ///
+    /// A content hash can be recorded with the data, like the [`SetHash`](Self::SetHash)
+    /// instruction does. Otherwise, when new data is set, an existing content hash is
+    /// recomputed for `direct` data and removed for other data sources.
+    ///
     /// Note: It is not possible to set data if the account is immutable.
     ///
-    /// There are 2 optional accounts:
+    /// There are 5 optional accounts:
     ///   - `buffer`: used to specify the data to be copied.
     ///   - `program`: required to validate whether the authority is the program upgrade
     ///     authority.
     ///   - `program_data`: required to validate whether the authority is the program upgrade
     ///     authority.
+    ///   - `destination`: receives the excess lamports of the metadata account and the
+    ///     lamports of the buffer account, which is closed.
+    ///   - `external`: account referenced by `external` data that records an expected owner
+    ///     and content digest, which is required to validate the data.
     ///
     /// Accounts expected by this instruction:
     ///
@@ -116,14 +175,20 @@ pub enum ProgramMetadataInstruction {
     ///  2. `[o]` Buffer account to copy data from.
     ///  3. `[o]` Program account.
     ///  4. `[o]` Program data account.
+    ///  5. `[o]` Destination account.
+    ///  6. `[o]` External account.
     ///
     /// Instruction data:
     ///
     ///  - `u8`: encoding
     ///  - `u8`: compression
     ///  - `u8`: format
-    ///  - `u8`: data source
-    ///  - `[u8]`: (optional) bytes to write
+    ///  - `u8`: hash option (0 `=` no hash, 1 `=` record hash)
+    ///  - `[u8; 32]`: (optional) content hash
+    ///  - `u8`: (optional) data source
+    ///  - `[u8]`: (optional) bytes to write, or the range of the buffer data to copy:
+    ///    - `u32`: offset to copy from
+    ///    - `u32`: length to copy
     SetData,
 
     /// Sets the metadata account as immutable.
@@ -199,7 +264,15 @@ pub enum ProgramMetadataInstruction {
     /// written to it. This is required when either initializing or updating a metadata
     /// account with data that exceeds the maximum transaction size.
     ///
-    /// A `seed` value is required for PDA buffer accounts.
+    /// A `seed` value is required for PDA buffer accounts. The seed is either a fixed
+    /// 16-byte seed or a variable-length seed of up to 32 bytes, encoded as its length
+    /// followed by the zero-padded seed bytes (see
+    /// [`InitializeWithSeed`](Self::InitializeWithSeed)).
+    ///
+    /// The buffer account data can optionally be allocated with an initial capacity,
+    /// in addition to the buffer header. Pre-allocated buffer accounts smaller than
+    /// the requested capacity are resized, which is limited by the maximum data
+    /// increase allowed by the runtime within an instruction.
     ///
     /// There are 2 optional accounts:
     ///   - `program`: required to validate whether the authority is the program upgrade
@@ -219,21 +292,29 @@ pub enum ProgramMetadataInstruction {
     ///
     /// Instruction data:
     ///
-    /// - `[u8; 16]`: seed (optional)
+    /// - `[u8; 16]` or `(u8, [u8; 32])`: seed (optional)
+    /// - `u32`: data capacity (optional)
     Allocate,
 
     /// Extends a buffer or metadata account data by the requested length.
     ///
     /// The account is expected to be pre-funded with the required lamports
-    /// for the new size.
+    /// for the new size, unless a payer account is provided to fund the
+    /// missing lamports.
+    ///
+    /// The length is limited by the maximum data increase allowed by the runtime
+    /// within an instruction (`10_240` bytes); larger extensions require multiple
+    /// instructions.
     ///
     /// Note: Immutable metadata accounts cannot be extended.
     ///
-    /// There are 2 optional accounts:
+    /// There are 4 optional accounts:
     ///   - `program`: required to validate whether the authority is the program upgrade
     ///     authority.
     ///   - `program_data`: required to validate whether the authority is the program upgrade
     ///     authority.
+    ///   - `payer`: funds the lamports required for the new size.
+    ///   - `system_program`: required when a `payer` is provided.
     ///
     /// Accounts expected by this instruction:
     ///
@@ -241,11 +322,450 @@ pub enum ProgramMetadataInstruction {
     ///  1. `[s]` Authority account.
     ///  2. `[o]` Program account.
     ///  3. `[o]` Program data account.
+    ///  4. `[o]` Payer account.
+    ///  5. `[o]` System program.
     ///
     /// Instruction data:
     ///
-    ///  - `u16`: length to add the account size
+    ///  - `u32`: length to add the account size (a `u16` length is also accepted)
     Extend,
+
+    /// Nominates a new authority for a buffer or metadata account.
+    ///
+    /// The nominated authority is stored in a pending authority account and only
+    /// takes effect once it signs the [`AcceptAuthority`](Self::AcceptAuthority)
+    /// instruction. Proposing again replaces the previous nomination.
+    ///
+    /// The pending authority account address is a PDA derived from the
+    /// `pending_authority` seed and the buffer or metadata account address. It
+    /// must be pre-funded with enough lamports to cover its storage cost when it
+    /// does not exist yet.
+    ///
+    /// Special cases:
+    ///   - It is not possible to propose an authority if the metadata account
+    ///     is non-canonical or immutable.
+    ///   - It is not possible to propose the removal of the authority; this is
+    ///     done through the [`SetAuthority`](Self::SetAuthority) instruction.
+    ///   - Changing the authority through the [`SetAuthority`](Self::SetAuthority)
+    ///     instruction or closing the account does not remove the pending
+    ///     authority; use the [`CancelAuthority`](Self::CancelAuthority) instruction
+    ///     to discard it.
+    ///
+    /// There are 3 optional accounts:
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `system_program`: required to allocate the pending authority account. When
+    ///     replacing an existing nomination, this is not required.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[ ]` Buffer or metadata account.
+    ///  1. `[s]` Current authority account.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///  4. `[w]` Pending authority account.
+    ///  5. `[o]` System program.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `[u8; 32]`: pending authority
+    ProposeAuthority,
+
+    /// Accepts the authority of a buffer or metadata account.
+    ///
+    /// The pending authority must sign the transaction. Once accepted, the
+    /// pending authority account is closed and its lamports are transferred
+    /// to the destination account.
+    ///
+    /// The authority that proposed the nomination must still be an authority of
+    /// the account, so a nomination cannot be accepted after the account changed
+    /// hands (e.g., through [`SetAuthority`](Self::SetAuthority)).
+    ///
+    /// Note: It is not possible to accept the authority if the metadata account
+    /// has become immutable after the nomination.
+    ///
+    /// There are 2 optional accounts:
+    ///   - `program`: required when the nomination was proposed by the program
+    ///     upgrade authority of a canonical metadata account.
+    ///   - `program_data`: required to validate whether the proposer is the program
+    ///     upgrade authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Buffer or metadata account.
+    ///  1. `[s]` Pending authority.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///  4. `[w]` Pending authority account.
+    ///  5. `[w]` Destination account.
+    AcceptAuthority,
+
+    /// Cancels a pending authority nomination.
+    ///
+    /// The nomination can be cancelled by the current authority of the
+    /// buffer or metadata account (including the program upgrade authority for
+    /// canonical accounts) or declined by the pending authority itself. The
+    /// pending authority account is closed and its lamports are transferred to
+    /// the destination account.
+    ///
+    /// Note: It is possible to cancel a nomination even if the metadata account
+    /// is immutable.
+    ///
+    /// There are 2 optional accounts:
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[ ]` Buffer or metadata account.
+    ///  1. `[s]` Current or pending authority account.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///  4. `[w]` Pending authority account.
+    ///  5. `[w]` Destination account.
+    CancelAuthority,
+
+    /// Sets or removes the content hash of a metadata account.
+    ///
+    /// The content hash is the SHA-256 digest of the metadata content. The hash
+    /// is stored in a section between the header and the data, which requires
+    /// the account to be resized.
+    ///
+    /// When the content is stored in the account (`direct` data source), the
+    /// hash must be the SHA-256 digest of the account data, otherwise the
+    /// instruction fails with a `HashMismatch` error. For other data sources
+    /// (e.g., `url` and `external`), the content is not held in the account:
+    /// the hash is the digest of the referenced content, computed off-chain by
+    /// the authority and not checked by the program. Consumers can compare it
+    /// against the content they retrieved.
+    ///
+    /// Note: The hash can also be recorded with the data by the [`Initialize`](Self::Initialize)
+    /// and [`SetData`](Self::SetData) instructions, which keep the hash of `direct` data up
+    /// to date. It is not possible to set the hash if the account is immutable.
+    ///
+    /// There are 2 optional accounts:
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account.
+    ///  1. `[s]` Authority account.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `u8`: option (0 `=` remove hash, 1 `=` set hash)
+    ///  - `[u8; 32]`: (optional) content hash
+    SetHash,
+
+    /// Verifies the content hash of a metadata account.
+    ///
+    /// This instruction does not modify any account and can be invoked by other
+    /// programs to assert that a metadata account matches an expected content.
+    /// It fails with a `HashMismatch` error if the metadata account does not
+    /// have a content hash or the hash does not match the expected one.
+    ///
+    /// For `direct` data, the content hash is computed from the account data
+    /// when it is set and recomputed whenever the data changes, so a matching hash
+    /// asserts the content of the account. For other data sources, it only asserts
+    /// the digest set by the authority for the referenced content.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[ ]` Metadata account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `[u8; 32]`: expected content hash
+    VerifyHash,
+
+    /// Sets the data to a program metadata account if it has not changed.
+    ///
+    /// This instruction behaves like [`SetData`](Self::SetData), but it first checks
+    /// that the metadata account matches an expected revision or content hash,
+    /// typically taken from the last fetched state of the account. It fails with a
+    /// `MetadataChanged` error when the account has been updated in between.
+    ///
+    /// Note: The revision of a metadata account is incremented every time its data
+    /// changes through the [`SetData`](Self::SetData), `SetDataChecked` or
+    /// [`Patch`](Self::Patch) instructions.
+    ///
+    /// There are 5 optional accounts:
+    ///   - `buffer`: used to specify the data to be copied.
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `destination`: receives the excess lamports of the metadata account and the
+    ///     lamports of the buffer account, which is closed.
+    ///   - `external`: account referenced by `external` data that records an expected owner
+    ///     and content digest, which is required to validate the data.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account.
+    ///  1. `[s]` Authority account.
+    ///  2. `[o]` Buffer account to copy data from.
+    ///  3. `[o]` Program account.
+    ///  4. `[o]` Program data account.
+    ///  5. `[o]` Destination account.
+    ///  6. `[o]` External account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `u8`: expectation (0 `=` revision, 1 `=` content hash)
+    ///  - `u64` | `[u8; 32]`: expected revision or content hash
+    ///  - `u8`: encoding
+    ///  - `u8`: compression
+    ///  - `u8`: format
+    ///  - `u8`: hash option (0 `=` no hash, 1 `=` record hash)
+    ///  - `[u8; 32]`: (optional) content hash
+    ///  - `u8`: (optional) data source
+    ///  - `[u8]`: (optional) bytes to write, or the range of the buffer data to copy
+    ///    (see [`SetData`](Self::SetData))
+    SetDataChecked,
+
+    /// Sets or removes an extension of a metadata account.
+    ///
+    /// Extensions are type-length-value entries stored in an extension area between
+    /// the header (and the content hash section, if present) and the data. They can
+    /// hold small structured attributes (e.g., a content type or tags) without
+    /// changing the header layout. Setting an extension replaces any existing value
+    /// of the same type, while an empty value removes it. The account is resized
+    /// as needed and the area is removed once it holds no extensions.
+    ///
+    /// Note: Extensions are preserved when new data is set with the
+    /// [`SetData`](Self::SetData) instruction. It is not possible to set an extension
+    /// if the account is immutable.
+    ///
+    /// There are 2 optional accounts:
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account.
+    ///  1. `[s]` Authority account.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `u16`: extension type (must not be `0` or a type reserved for the program)
+    ///  - `[u8]`: extension value (empty `=` remove extension)
+    SetExtension,
+
+    /// Upgrades a metadata account to the current account layout version.
+    ///
+    /// Accounts created before layout versioning have version `0`. They differ
+    /// from version `1` accounts in two ways:
+    ///   - the last 5 bytes of the header are reserved padding, where version `1`
+    ///     stores the flags, version and seed length. The padding is zeroed, which
+    ///     reads as an account without optional sections and derived from a fixed
+    ///     seed.
+    ///   - the account might have any amount of bytes after the data (e.g.,
+    ///     pre-funded accounts), while version `1` accounts hold exactly the header,
+    ///     the optional sections and the data.
+    ///
+    /// Migrating a version `0` account resizes it to remove the bytes after the
+    /// data and sets the account to the current version. The lamports of the
+    /// account above its rent-exempt minimum balance, which include the rent of
+    /// the removed bytes, are moved to the destination account when one is
+    /// provided. Migrating an account that already has the current version only
+    /// withdraws its excess lamports.
+    ///
+    /// Note: It is not possible to migrate an immutable account. Accounts with a
+    /// version that is not supported by the program are rejected by all
+    /// instructions that update a metadata account.
+    ///
+    /// There are 3 optional accounts:
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `destination`: receives the lamports of the metadata account above its
+    ///     rent-exempt minimum balance.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account.
+    ///  1. `[s]` Authority account.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///  4. `[o]` Destination account.
+    Migrate,
+
+    /// Writes a patch directly into the data of a metadata account.
+    ///
+    /// The patch bytes are written at the given offset of the data, which is
+    /// then resized to the given data length: the data is truncated when the
+    /// length is shorter, and zero-extended when it is longer. This allows small
+    /// updates to large content without rewriting it through a buffer. The
+    /// patched data must be valid for the data source of the account.
+    ///
+    /// Note: Patching the data increments the revision of the metadata account when
+    /// the data changes and recomputes (`direct` data) or removes the content hash, like
+    /// the [`SetData`](Self::SetData) instruction. It is not possible to patch an immutable account.
+    ///
+    /// There are 4 optional accounts:
+    ///   - `program`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority.
+    ///   - `destination`: receives the lamports of the metadata account above its
+    ///     rent-exempt minimum balance.
+    ///   - `external`: account referenced by `external` data that records an expected owner
+    ///     and content digest, which is required to validate the data.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account.
+    ///  1. `[s]` Authority account.
+    ///  2. `[o]` Program account.
+    ///  3. `[o]` Program data account.
+    ///  4. `[o]` Destination account.
+    ///  5. `[o]` External account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `u32`: offset to write to, relative to the start of the data
+    ///  - `u32`: length of the data after the patch
+    ///  - `[u8]`: (optional) bytes to write
+    Patch,
+
+    /// Promotes a non-canonical metadata account to the canonical metadata account
+    /// of its program.
+    ///
+    /// The program upgrade authority endorses the non-canonical (source) account,
+    /// whose header, optional sections and data are copied to the canonical PDA
+    /// derived from the program ID and the seed of the source account. The canonical
+    /// account is mutable and managed by the program upgrade authority.
+    ///
+    /// The source account can either be moved or copied:
+    ///   - moving closes the source account, whose lamports fund the canonical account;
+    ///     this requires the authority of the source account to sign and the source
+    ///     account to be mutable.
+    ///   - copying leaves the source account unchanged, so it can be immutable; the
+    ///     canonical account must be pre-funded.
+    ///
+    /// There are 2 optional accounts:
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority. This is not required for SVM Loader `v4` programs, since their authority
+    ///     is stored on the program account.
+    ///   - `source_authority`: required when moving the source account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Canonical metadata account to initialize.
+    ///  1. `[s]` Authority account (program upgrade authority).
+    ///  2. `[w]` Source (non-canonical) metadata account.
+    ///  3. `[ ]` Program account.
+    ///  4. `[o]` Program data account.
+    ///  5. `[ ]` System program.
+    ///  6. `[o]` Source authority account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `bool`: whether to copy (`true`) or move (`false`) the source account
+    Promote,
+
+    /// Moves a metadata account to a new seed or, for non-canonical metadata
+    /// accounts, to a new authority.
+    ///
+    /// The new metadata account is created at the PDA derived from the new seed (and
+    /// new authority for non-canonical accounts), the header, optional sections and
+    /// data are copied to it and the old metadata account is closed, with its lamports
+    /// funding the new account.
+    ///
+    /// The new seed uses the same seed mode as the current seed, unless a variable-length
+    /// seed is requested. A fixed seed is zero-padded to 16 bytes, so it cannot be longer
+    /// than that. A variable-length seed is up to 32 bytes long and its length is stored
+    /// in the header of the new account. The seed extension of the current seed (if any)
+    /// is replaced by one holding the bytes of the new seed past the first 16 bytes (if
+    /// any); other extensions are kept. A 16-byte seed derives the same address in both
+    /// modes. When the new account is larger than the old one, it must be pre-funded
+    /// with the difference.
+    ///
+    /// The pending authority account of the old metadata account is closed if it holds
+    /// a nomination, since the nomination is for an address that no longer holds the
+    /// account; its lamports are transferred to the destination account.
+    ///
+    /// Moving a canonical metadata account requires the program upgrade authority.
+    /// Moving a non-canonical metadata account requires its authority and, when the
+    /// authority changes, the new authority must also sign.
+    ///
+    /// Note: Immutable metadata accounts cannot be moved.
+    ///
+    /// There are 2 optional accounts:
+    ///   - `program_data`: required to validate whether the authority is the program upgrade
+    ///     authority. This is not required for SVM Loader `v4` programs, since their authority
+    ///     is stored on the program account.
+    ///   - `new_authority`: the authority of the new non-canonical metadata account. Defaults to
+    ///     the current authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account to move.
+    ///  1. `[s]` Authority account.
+    ///  2. `[w]` New metadata account.
+    ///  3. `[ ]` Program account.
+    ///  4. `[o]` Program data account.
+    ///  5. `[ ]` System program.
+    ///  6. `[w]` Pending authority account of the metadata account to move.
+    ///  7. `[w]` Destination account.
+    ///  8. `[o]` New authority account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `u8`: new seed length (between `1` and `32`)
+    ///  - `[u8; 32]`: new seed (zero-padded)
+    ///  - `bool`: whether to use a variable-length seed (`true`) or keep the seed
+    ///    mode of the metadata account (`false`)
+    Move,
+
+    /// Initializes a metadata account derived from a variable-length seed.
+    ///
+    /// This instruction is equivalent to [`Initialize`](Self::Initialize), except that
+    /// the metadata account address is derived from a seed of up to 32 bytes instead
+    /// of a fixed 16-byte seed. The length of the seed is stored in the header of the
+    /// metadata account; the bytes past the first 16 bytes are stored in a seed
+    /// extension, which cannot be set with the [`SetExtension`](Self::SetExtension)
+    /// instruction.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///  0. `[w]` Metadata account to initialize.
+    ///  1. `[s]` Authority.
+    ///  2. `[ ]` Program account.
+    ///  3. `[o]` Program data account.
+    ///  4. `[o]` System program.
+    ///  5. `[o]` Source buffer account.
+    ///  6. `[o]` Destination account.
+    ///  7. `[o]` External account.
+    ///
+    /// Instruction data:
+    ///
+    ///  - `u8`: seed length (between `1` and `32`)
+    ///  - `[u8; 32]`: seed (zero-padded)
+    ///  - `u8`: encoding
+    ///  - `u8`: compression
+    ///  - `u8`: format
+    ///  - `u8`: data source
+    ///  - `u8`: hash option (0 `=` no hash, 1 `=` record hash)
+    ///  - `[u8; 32]`: (optional) content hash
+    ///  - `[u8]`: (optional) bytes to write; when copying from a source buffer, the offset
+    ///    (`u32`) and length (`u32`) of the range of the buffer data to copy
+    InitializeWithSeed,
 }
 
 impl TryFrom<u8> for ProgramMetadataInstruction {
@@ -262,6 +782,18 @@ impl TryFrom<u8> for ProgramMetadataInstruction {
             6 => Ok(ProgramMetadataInstruction::Close),
             7 => Ok(ProgramMetadataInstruction::Allocate),
             8 => Ok(ProgramMetadataInstruction::Extend),
+            9 => Ok(ProgramMetadataInstruction::ProposeAuthority),
+            10 => Ok(ProgramMetadataInstruction::AcceptAuthority),
+            11 => Ok(ProgramMetadataInstruction::CancelAuthority),
+            12 => Ok(ProgramMetadataInstruction::SetHash),
+            13 => Ok(ProgramMetadataInstruction::VerifyHash),
+            14 => Ok(ProgramMetadataInstruction::SetDataChecked),
+            15 => Ok(ProgramMetadataInstruction::SetExtension),
+            16 => Ok(ProgramMetadataInstruction::Migrate),
+            17 => Ok(ProgramMetadataInstruction::Patch),
+            18 => Ok(ProgramMetadataInstruction::Promote),
+            19 => Ok(ProgramMetadataInstruction::Move),
+            20 => Ok(ProgramMetadataInstruction::InitializeWithSeed),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -0,0 +1,104 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::{
+    error::ProgramMetadataError,
+    state::{
+        buffer::Buffer, header::Header, pending_authority::PendingAuthority, AccountDiscriminator,
+        LAYOUT_VERSION,
+    },
+};
+
+use super::{close_account, is_account_authority};
+
+/// Processor for the [`AcceptAuthority`](`crate::instruction::ProgramMetadataInstruction::AcceptAuthority`)
+/// instruction.
+pub fn accept_authority(accounts: &mut [AccountView]) -> ProgramResult {
+    // Access accounts.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    let [account, new_authority, program, program_data, pending_authority, destination] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // new_authority
+    // - must be a signer
+
+    if !new_authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // pending_authority
+    // - must be owned by the program and hold a pending authority
+    // - must be associated with the account
+    // - must nominate the new authority
+
+    let proposer = {
+        // SAFETY: scoped immutable borrow of `pending_authority` account data.
+        let pending = unsafe { PendingAuthority::from_account_info_unchecked(pending_authority)? };
+
+        if &pending.account != account.address() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if &pending.authority != new_authority.address() {
+            return Err(ProgramError::IncorrectAuthority);
+        }
+
+        pending.proposer
+    };
+
+    // account
+    // - must be a buffer or metadata account
+    // - must be mutable (metadata)
+    // - cannot be a non-canonical metadata account
+    // - proposer must still be an authority of the account; the `program` and
+    //   `program_data` accounts are only required when the proposer is the program
+    //   upgrade authority of a canonical metadata account
+
+    {
+        // SAFETY: scoped mutable borrow of `account` account data.
+        let account_data = unsafe { account.borrow_unchecked_mut() };
+
+        match AccountDiscriminator::try_from_bytes(account_data)? {
+            Some(AccountDiscriminator::Buffer) => {
+                let buffer = Buffer::from_bytes_mut(account_data)?;
+
+                if !is_account_authority(buffer, &proposer, program, program_data)? {
+                    return Err(ProgramError::IncorrectAuthority);
+                }
+
+                buffer.authority = (*new_authority.address()).into();
+            }
+            Some(AccountDiscriminator::Metadata) => {
+                let header = Header::from_bytes_mut(account_data)?;
+
+                if !header.canonical() {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                if !header.mutable() {
+                    return Err(ProgramMetadataError::ImmutableMetadataAccount.into());
+                }
+
+                if header.version() > LAYOUT_VERSION {
+                    return Err(ProgramMetadataError::UnsupportedAccountVersion.into());
+                }
+
+                if !is_account_authority(header, &proposer, program, program_data)? {
+                    return Err(ProgramError::IncorrectAuthority);
+                }
+
+                header.authority = (*new_authority.address()).into();
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    // Move the lamports to the destination account and close the pending
+    // authority account.
+
+    close_account(pending_authority, destination)
+}
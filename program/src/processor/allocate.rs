@@ -1,18 +1,67 @@
-use pinocchio::{cpi::Signer, error::ProgramError, instruction::seeds, AccountView, ProgramResult};
+use pinocchio::{
+    cpi::Signer, error::ProgramError, instruction::seeds, AccountView, ProgramResult, Resize,
+};
 use pinocchio_system::instructions::{Assign, CreateAccountAllowPrefund};
 
 use crate::{
     error::ProgramMetadataError,
-    processor::derive_program_address,
-    state::{buffer::Buffer, AccountDiscriminator, SEED_LEN},
-    ID,
+    state::{buffer::Buffer, AccountDiscriminator, LAYOUT_VERSION, SEED_LEN, VARIABLE_SEED_LEN},
+};
+
+use super::{
+    authority::is_supported, derive_metadata_address, is_authority, seed_prefix, variable_seed,
 };
 
-use super::is_program_authority;
+/// Length of the optional data capacity in the instruction data.
+const CAPACITY_LEN: usize = core::mem::size_of::<u32>();
+
+/// Length of the instruction data for PDA buffers with a data capacity.
+const ALLOCATE_WITH_CAPACITY_LEN: usize = SEED_LEN + CAPACITY_LEN;
+
+/// Length of the instruction data for PDA buffers with a variable-length seed
+/// and a data capacity.
+const ALLOCATE_WITH_VARIABLE_SEED_AND_CAPACITY_LEN: usize = VARIABLE_SEED_LEN + CAPACITY_LEN;
 
 /// Processor for the [`Allocate`](`crate::instruction::ProgramMetadataInstruction::Allocate`)
 /// instruction.
 pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+    //
+    // The instruction data holds an optional `seed` (only for PDA buffers), followed
+    // by an optional `u32` data capacity. The `seed` is either a fixed `[u8; 16]` seed
+    // or an encoded variable-length seed.
+
+    let (seed, variable, capacity) = match instruction_data.len() {
+        0 | SEED_LEN => (instruction_data, false, 0),
+        CAPACITY_LEN | ALLOCATE_WITH_CAPACITY_LEN => {
+            // SAFETY: `instruction_data` is guaranteed to have at least `4` bytes.
+            let (seed, capacity) = unsafe {
+                instruction_data
+                    .split_last_chunk::<CAPACITY_LEN>()
+                    .unwrap_unchecked()
+            };
+            (seed, false, u32::from_le_bytes(*capacity) as usize)
+        }
+        VARIABLE_SEED_LEN | ALLOCATE_WITH_VARIABLE_SEED_AND_CAPACITY_LEN => {
+            // SAFETY: `instruction_data` is guaranteed to have at least `33` bytes.
+            let (seed, capacity) = unsafe {
+                instruction_data
+                    .split_first_chunk::<VARIABLE_SEED_LEN>()
+                    .unwrap_unchecked()
+            };
+            let capacity = match capacity {
+                [c0, c1, c2, c3] => u32::from_le_bytes([*c0, *c1, *c2, *c3]) as usize,
+                _ => 0,
+            };
+            (variable_seed(seed)?, true, capacity)
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    // The length of the buffer account data, including the header.
+    let length = Buffer::LEN
+        .checked_add(capacity)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
     // Access accounts.
 
     let [buffer, authority, program, program_data, _system_program, _remaining @ ..] = accounts
@@ -40,35 +89,30 @@ pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
 
     let (is_pda, bump, canonical) = if buffer.address() == authority.address() {
         // A keypair buffer does not require a `seed` value.
-        if !instruction_data.is_empty() {
+        if !seed.is_empty() {
             return Err(ProgramError::InvalidInstructionData);
         }
 
         (false, 0, false)
     } else {
         // A PDA buffer requires a `seed` value...
-        if instruction_data.len() != SEED_LEN {
+        if seed.is_empty() {
             return Err(ProgramError::InvalidInstructionData);
         }
-        // ...and an executable program account.
-        if !program.executable() {
+        // ...and an executable program account or an account with an authority
+        // prover. Loader `v4` programs are not executable while retracted, but
+        // they still have an authority.
+        if !is_supported(program) {
             return Err(ProgramMetadataError::NotExecutableAccount.into());
         }
 
-        let canonical = is_program_authority(program, program_data, authority.address())?;
-
-        let (derived_metadata, bump) = if canonical {
-            derive_program_address(&[program.address().as_array(), instruction_data], &ID)
-        } else {
-            derive_program_address(
-                &[
-                    program.address().as_array(),
-                    authority.address().as_array(),
-                    instruction_data,
-                ],
-                &ID,
-            )
-        };
+        let canonical = is_authority(program, program_data, authority.address())?;
+
+        let (derived_metadata, bump) = derive_metadata_address(
+            program.address(),
+            (!canonical).then_some(authority.address()),
+            seed,
+        );
 
         if buffer.address() != &derived_metadata {
             return Err(ProgramError::InvalidSeeds);
@@ -82,7 +126,7 @@ pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
             // Allocates the space for the buffer account and assigns it to
             // the program.
 
-            let space = Buffer::LEN as u64;
+            let space = length as u64;
 
             match (is_pda, canonical) {
                 // canonical
@@ -94,7 +138,7 @@ pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
                 }
                 .invoke_signed(&[Signer::from(&seeds!(
                     program.address().as_ref(),
-                    instruction_data,
+                    seed,
                     &[bump]
                 ))])?,
                 // non-canonical
@@ -107,7 +151,7 @@ pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
                 .invoke_signed(&[Signer::from(&seeds!(
                     program.address().as_ref(),
                     authority.address().as_ref(),
-                    instruction_data,
+                    seed,
                     &[bump]
                 ))])?,
                 // keypair
@@ -141,6 +185,12 @@ pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
                     return Err(ProgramError::AccountAlreadyInitialized);
                 }
             }
+
+            // Grows the buffer account to the requested capacity, if needed.
+            if n < length {
+                // SAFETY: `buffer` account is not borrowed at this point.
+                unsafe { buffer.resize_unchecked(length)? };
+            }
         }
         _ => return Err(ProgramError::InvalidAccountData),
     }
@@ -158,13 +208,14 @@ pub fn allocate(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
     let buffer_header = Buffer::from_bytes_mut(unsafe { buffer.borrow_unchecked_mut() })?;
     buffer_header.discriminator = AccountDiscriminator::Buffer as u8;
     buffer_header.authority = (*authority.address()).into();
+    buffer_header.version = LAYOUT_VERSION;
 
     if is_pda {
         buffer_header.program = (*program.address()).into();
         buffer_header.canonical = canonical as u8;
-        buffer_header
-            .seed
-            .copy_from_slice(instruction_data.as_ref());
+        buffer_header.seed = seed_prefix(seed);
+        // Variable-length seeds are at most `32` bytes long.
+        buffer_header.seed_length = if variable { seed.len() as u8 } else { 0 };
     }
 
     Ok(())
@@ -0,0 +1,325 @@
+//! Authority resolution for the accounts that metadata can be attached to.
+//!
+//! Canonical metadata accounts are managed by the authority of the account they
+//! describe — e.g., the upgrade authority of a program or the mint authority of a
+//! token mint. Each supported owner program has an authority prover, which reads
+//! the authority from the account data (and any auxiliary account) of accounts
+//! owned by it.
+
+use pinocchio::{address::ADDRESS_BYTES, error::ProgramError, AccountView, Address};
+
+use crate::error::ProgramMetadataError;
+
+/// The program ID of the SVM Loader `v3`.
+const BPF_LOADER_UPGRABABLE_ID: Address = Address::new_from_array([
+    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61, 22,
+    193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+]);
+
+/// The program ID of the SVM Loader `v4`.
+const LOADER_V4_ID: Address = Address::new_from_array([
+    5, 18, 180, 17, 81, 81, 227, 122, 173, 10, 139, 197, 211, 136, 46, 123, 127, 218, 76, 243, 210,
+    192, 40, 200, 207, 131, 54, 24, 0, 0, 0, 0,
+]);
+
+/// The program ID of the SPL Token program.
+const TOKEN_PROGRAM_ID: Address = Address::new_from_array([
+    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237,
+    95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+]);
+
+/// The program ID of the SPL Token-2022 program.
+const TOKEN_2022_PROGRAM_ID: Address = Address::new_from_array([
+    6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77,
+    131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+]);
+
+/// The length of the SVM Loader `v4` program account state.
+///
+/// The state is composed of a `slot` (`u64`), an `authority_address_or_next_version`
+/// (`Pubkey`) and a `status` (`u64`).
+const LOADER_V4_STATE_LEN: usize = 48;
+
+/// The length of a token `Mint` account.
+const MINT_LEN: usize = 82;
+
+/// The length of a token `Account` account.
+///
+/// Token-2022 stores the account type of extended mints right after this length,
+/// so that mints and token accounts can be told apart.
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Authority prover of the accounts owned by a program.
+///
+/// The prover receives the account, the auxiliary `program_data` account (which
+/// is the program ID when not provided) and the authority to check.
+type AuthorityProver = fn(&AccountView, &AccountView, &Address) -> Result<bool, ProgramError>;
+
+/// Authority provers of the supported owner programs.
+const AUTHORITY_PROVERS: [(Address, AuthorityProver); 4] = [
+    (BPF_LOADER_UPGRABABLE_ID, loader_v3_authority),
+    (LOADER_V4_ID, loader_v4_authority),
+    (TOKEN_PROGRAM_ID, token_mint_authority),
+    (TOKEN_2022_PROGRAM_ID, token_2022_mint_authority),
+];
+
+/// Returns the authority prover of the owner of the `account`, if there is one.
+#[inline(always)]
+fn authority_prover(account: &AccountView) -> Option<AuthorityProver> {
+    AUTHORITY_PROVERS
+        .iter()
+        .find(|(owner, _)| account.owned_by(owner))
+        .map(|(_, prover)| *prover)
+}
+
+/// Checks whether metadata accounts can be attached to the `account` by its
+/// authority, i.e., whether the `account` is an executable program or it is
+/// owned by a program with an authority prover.
+#[inline(always)]
+pub(crate) fn is_supported(account: &AccountView) -> bool {
+    account.executable() || authority_prover(account).is_some()
+}
+
+/// Checks if the provided `authority` is the authority of the `account`.
+/// Fails when providing unexpected input.
+///
+/// The check is delegated to the authority prover of the owner of the `account`:
+///
+/// - SVM Loader `v3`: programs (see `loader_v3_authority`) and buffers.
+///
+/// - SVM Loader `v4`: programs (see `loader_v4_authority`).
+///
+/// - SPL Token and Token-2022: mints (see `mint_authority`).
+///
+/// Accounts owned by other programs have no authority, except for executable
+/// accounts (e.g., SVM Loader `v2` programs): since there is no program data
+/// associated, the keypair used to deploy the program must be the authority and
+/// sign the transaction.
+#[inline(always)]
+pub(crate) fn is_authority(
+    account: &AccountView,
+    program_data: &AccountView,
+    authority: &Address,
+) -> Result<bool, ProgramError> {
+    match authority_prover(account) {
+        Some(prover) => prover(account, program_data, authority),
+        None => Ok(account.executable() && account.address() == authority),
+    }
+}
+
+/// Checks if the provided `authority` is the authority of a SVM Loader `v3` account.
+///
+/// The following validation checks are performed:
+///
+/// - When the `program` account discriminator (first byte) is `1`, i.e., defining
+///   a `Buffer` account, the authority is read from the `program` account itself
+///   and the `program_data` account is ignored.
+///
+/// - Otherwise the `program_data` account must be provided and the `program`
+///   account must be executable.
+///
+/// For SVM Loader `v3` programs:
+///
+/// - `program` account discriminator (first byte) must be `2`, i.e., defining a
+///   `Program` account.
+///
+/// - `program_data` account must be the one set on the `program` account data.
+///
+/// - `program_data` account discriminator (first byte) must be `3`, i.e., defining
+///   a `ProgramData` account.
+///
+/// - `program_data` account must have 32 bytes of data in the range `[13..45]`,
+///   matching the provided `authority`.
+#[allow(clippy::arithmetic_side_effects)]
+fn loader_v3_authority(
+    program: &AccountView,
+    program_data: &AccountView,
+    authority: &Address,
+) -> Result<bool, ProgramError> {
+    // Buffers hold their authority, so they do not need a program data account.
+    {
+        // SAFETY: scoped immutable borrow of the `program` account data.
+        let data = unsafe { program.borrow_unchecked() };
+        // The discriminator is 4 bytes, but we only need to check the first byte
+        // since there are fewer than 256 account types.
+        if let (Some(1 /* buffer discriminator */), false) = (data.first(), program.executable()) {
+            let option_offset: usize = 4 /* discriminator */;
+            return optional_authority(data, option_offset, authority);
+        }
+    }
+
+    // For BPFv3 programs, we need the program data account to check the auhtority.
+    if program_data.address() == &crate::ID {
+        return Ok(false);
+    }
+
+    let expected_program_data = {
+        let data = unsafe { program.borrow_unchecked() };
+        match (data.first(), program.executable()) {
+            (Some(2), true) => {
+                let offset: usize = 4;
+                Address::try_from(&data[offset..offset + ADDRESS_BYTES])
+                    .map_err(|_| ProgramError::InvalidAccountData)?
+            }
+            _ => {
+                return Err(ProgramMetadataError::InvalidProgramState.into());
+            }
+        }
+    };
+
+    // Program <-> Program Data check.
+    if expected_program_data != *program_data.address() {
+        return Err(ProgramMetadataError::InvalidProgramDataAccount.into());
+    }
+
+    // Program Data checks.
+    let data = unsafe { program_data.borrow_unchecked() };
+    match (data.first(), program_data.executable()) {
+        (Some(3 /* program data discriminator */), false) => {
+            let option_offset: usize = 4 /* discriminator */ + 8 /* slot */;
+            optional_authority(data, option_offset, authority)
+        }
+        _ => Err(ProgramMetadataError::InvalidProgramState.into()),
+    }
+}
+
+/// Checks if the provided `authority` is the authority of a SVM Loader `v4` `program`.
+///
+/// The following validation checks are performed:
+///
+/// - `program` account must have at least 48 bytes of data, i.e., the
+///   length of the Loader `v4` state.
+///
+/// - `status` (`u64` in the range `[40..48]`) must be either `Retracted` (`0`),
+///   `Deployed` (`1`) or `Finalized` (`2`).
+///
+/// Both `Retracted` and `Deployed` programs have their authority in the range
+/// `[8..40]`, which must match the provided `authority`. `Retracted` programs
+/// are not necessarily executable, but their authority can still manage their
+/// metadata. `Finalized` programs have no authority — the address stored in the
+/// state refers to the next version of the program.
+#[allow(clippy::arithmetic_side_effects)]
+fn loader_v4_authority(
+    program: &AccountView,
+    _program_data: &AccountView,
+    authority: &Address,
+) -> Result<bool, ProgramError> {
+    // SAFETY: scoped immutable borrow of the `program` account data.
+    let data = unsafe { program.borrow_unchecked() };
+
+    if data.len() < LOADER_V4_STATE_LEN {
+        return Err(ProgramMetadataError::InvalidProgramState.into());
+    }
+
+    let authority_offset: usize = 8 /* slot */;
+    let status_offset: usize = authority_offset + ADDRESS_BYTES;
+
+    // SAFETY: `data` was validated to have at least `LOADER_V4_STATE_LEN` bytes.
+    let status =
+        u64::from_le_bytes(unsafe { *(data.as_ptr().add(status_offset) as *const [u8; 8]) });
+
+    match status {
+        0 /* retracted */ | 1 /* deployed */ => {
+            // The `authority_address_or_next_version` is a `Pubkey`.
+            let authority_key =
+                Address::try_from(&data[authority_offset..authority_offset + ADDRESS_BYTES])
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+            Ok(authority == &authority_key)
+        }
+        2 /* finalized */ => Ok(false),
+        _ => Err(ProgramMetadataError::InvalidProgramState.into()),
+    }
+}
+
+/// Checks if the provided `authority` is the mint authority of a SPL Token `mint`.
+///
+/// SPL Token mints are exactly [`MINT_LEN`] bytes long.
+fn token_mint_authority(
+    mint: &AccountView,
+    _program_data: &AccountView,
+    authority: &Address,
+) -> Result<bool, ProgramError> {
+    // SAFETY: scoped immutable borrow of the `mint` account data.
+    let data = unsafe { mint.borrow_unchecked() };
+
+    if data.len() != MINT_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    mint_authority(data, authority)
+}
+
+/// Checks if the provided `authority` is the mint authority of a SPL Token-2022 `mint`.
+///
+/// SPL Token-2022 mints are either [`MINT_LEN`] bytes long or, when they have
+/// extensions, their account type (at offset [`TOKEN_ACCOUNT_LEN`]) is `1`.
+fn token_2022_mint_authority(
+    mint: &AccountView,
+    _program_data: &AccountView,
+    authority: &Address,
+) -> Result<bool, ProgramError> {
+    // SAFETY: scoped immutable borrow of the `mint` account data.
+    let data = unsafe { mint.borrow_unchecked() };
+
+    if data.len() != MINT_LEN && data.get(TOKEN_ACCOUNT_LEN) != Some(&1 /* mint */) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    mint_authority(data, authority)
+}
+
+/// Checks if the provided `authority` is the mint authority of a token mint.
+///
+/// The following validation checks are performed:
+///
+/// - `is_initialized` (`bool` at offset `45`) must be `true`.
+///
+/// - `mint_authority` (`COption<Pubkey>` in the range `[0..36]`) must be set
+///   and match the provided `authority`. Mints without a mint authority have a
+///   fixed supply and no authority.
+#[allow(clippy::arithmetic_side_effects)]
+#[inline(always)]
+fn mint_authority(data: &[u8], authority: &Address) -> Result<bool, ProgramError> {
+    let is_initialized_offset: usize = 36 /* mint_authority */ + 8 /* supply */ + 1 /* decimals */;
+
+    if data.get(is_initialized_offset) != Some(&1) {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    // The `COption` tag is a `u32`, but we only need to check the first byte
+    // since it is either `0` or `1`.
+    match data.first() {
+        Some(1) => {
+            let pubkey_offset: usize = 4 /* option */;
+            let authority_key =
+                Address::try_from(&data[pubkey_offset..pubkey_offset + ADDRESS_BYTES])
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+            Ok(authority == &authority_key)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Checks if the provided `authority` matches the `Option<Pubkey>` authority
+/// stored at `option_offset` of an SVM Loader `v3` account.
+#[allow(clippy::arithmetic_side_effects)]
+#[inline(always)]
+fn optional_authority(
+    data: &[u8],
+    option_offset: usize,
+    authority: &Address,
+) -> Result<bool, ProgramError> {
+    match data.get(option_offset) {
+        Some(1) => {
+            let pubkey_offset: usize = option_offset + 1 /* option */;
+            // The `authority_key` is a `Pubkey`.
+            let authority_key = data
+                .get(pubkey_offset..pubkey_offset + ADDRESS_BYTES)
+                .and_then(|bytes| Address::try_from(bytes).ok())
+                .ok_or(ProgramError::InvalidAccountData)?;
+            Ok(authority == &authority_key)
+        }
+        Some(_) => Ok(false),
+        None => Err(ProgramError::InvalidAccountData),
+    }
+}
@@ -0,0 +1,75 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::state::{
+    buffer::Buffer, header::Header, pending_authority::PendingAuthority, AccountDiscriminator,
+};
+
+use super::{close_account, validate_authority};
+
+/// Processor for the [`CancelAuthority`](`crate::instruction::ProgramMetadataInstruction::CancelAuthority`)
+/// instruction.
+pub fn cancel_authority(accounts: &mut [AccountView]) -> ProgramResult {
+    // Access accounts.
+
+    let [account, authority, program, program_data, pending_authority, destination] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // authority
+    // - must be a signer
+
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // pending_authority
+    // - must be owned by the program and hold a pending authority
+    // - must be associated with the account
+
+    let is_pending_authority = {
+        // SAFETY: scoped immutable borrow of `pending_authority` account data.
+        let pending = unsafe { PendingAuthority::from_account_info_unchecked(pending_authority)? };
+
+        if &pending.account != account.address() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        &pending.authority == authority.address()
+    };
+
+    // authority
+    // - must either be the pending authority (declining the nomination) OR a valid
+    //   authority for the account
+    //
+    // Note that the account is not required to be mutable, so a nomination can
+    // always be cancelled.
+
+    if !is_pending_authority {
+        // Since we are not writing to the `account`, validate the ownership of
+        // the account.
+        if !account.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // SAFETY: scoped immutable borrow of `account` account data.
+        let account_data = unsafe { account.borrow_unchecked() };
+
+        match AccountDiscriminator::try_from_bytes(account_data)? {
+            Some(AccountDiscriminator::Buffer) => {
+                let buffer = Buffer::from_bytes(account_data)?;
+                validate_authority(buffer, authority, program, program_data)?;
+            }
+            Some(AccountDiscriminator::Metadata) => {
+                let header = Header::from_bytes(account_data)?;
+                validate_authority(header, authority, program, program_data)?;
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    // Move the lamports to the destination account and close the pending
+    // authority account.
+
+    close_account(pending_authority, destination)
+}
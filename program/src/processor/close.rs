@@ -2,7 +2,7 @@ use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
 
 use crate::state::{buffer::Buffer, AccountDiscriminator};
 
-use super::{validate_authority, validate_metadata};
+use super::{close_account, validate_authority, validate_metadata};
 
 /// Processor for the [`Close`](`crate::instruction::ProgramMetadataInstruction::Close`)
 /// instruction.
@@ -46,15 +46,5 @@ pub fn close(accounts: &mut [AccountView]) -> ProgramResult {
 
     // Move the lamports to the destination account and close the account.
 
-    let account_lamports = account.lamports();
-    let destination_lamports = destination.lamports();
-
-    destination.set_lamports(
-        destination_lamports
-            .checked_add(account_lamports)
-            .ok_or(ProgramError::ArithmeticOverflow)?,
-    );
-    account.set_lamports(0);
-
-    account.close()
+    close_account(account, destination)
 }
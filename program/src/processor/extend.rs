@@ -1,9 +1,12 @@
-use core::mem::size_of;
-use pinocchio::{account::AccountView, error::ProgramError, ProgramResult, Resize};
+use pinocchio::{
+    account::{AccountView, MAX_PERMITTED_DATA_INCREASE},
+    error::ProgramError,
+    ProgramResult, Resize,
+};
 
 use crate::state::{buffer::Buffer, AccountDiscriminator};
 
-use super::{validate_authority, validate_metadata};
+use super::{fund_account, validate_authority, validate_metadata};
 
 /// Processor for the [`Extend`](`crate::instruction::ProgramMetadataInstruction::Extend`)
 /// instruction.
@@ -11,18 +14,32 @@ use super::{validate_authority, validate_metadata};
 pub fn extend(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
     // Validates the instruction data.
 
-    let extend_length = if instruction_data.len() != size_of::<u16>() {
-        return Err(ProgramError::InvalidInstructionData);
-    } else {
+    let extend_length = match instruction_data.len() {
+        // SAFETY: `instruction_data` is guranteed to have length equal to `4`.
+        4 => u32::from_le_bytes(unsafe { *(instruction_data.as_ptr() as *const [u8; 4]) }),
+        // Legacy `u16` length.
+        //
         // SAFETY: `instruction_data` is guranteed to have length equal to `2`.
-        u16::from_le_bytes(unsafe { *(instruction_data.as_ptr() as *const [u8; 2]) })
-    };
+        2 => u16::from_le_bytes(unsafe { *(instruction_data.as_ptr() as *const [u8; 2]) }) as u32,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    } as usize;
+
+    // The runtime only allows the account to grow by `MAX_PERMITTED_DATA_INCREASE`
+    // bytes within an instruction.
+    if extend_length > MAX_PERMITTED_DATA_INCREASE {
+        return Err(ProgramError::InvalidRealloc);
+    }
 
     // Access accounts.
 
-    let [account, authority, program, program_data, _remaining @ ..] = accounts else {
+    let [account, authority, program, program_data, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    // The `payer` account is optional and funds the lamports required for the
+    // new size of the account.
+    let payer = remaining
+        .first()
+        .filter(|payer| payer.address() != &crate::ID);
 
     // Account validation.
     //
@@ -33,9 +50,12 @@ pub fn extend(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramR
     // - authority must be a signer (validated by `validate_authority`)
     // - must be a buffer or mutable metadata account
     // - must have a valid authority
-    // - must be rent exempt (pre-funded account) since we are reallocating the
-    //   account (checked by the runtime); both `allocate` and `initialize` ensure
-    //   that the account has at least some lamports
+    // - must be rent exempt (pre-funded account or funded by `payer`) since we
+    //   are reallocating the account (checked by the runtime); both `allocate` and
+    //   `initialize` ensure that the account has at least some lamports
+    //
+    // payer (if provided)
+    // - must be a signer (checked by the system program)
 
     if account.is_data_empty() {
         return Err(ProgramError::InvalidAccountData);
@@ -60,9 +80,14 @@ pub fn extend(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramR
 
     // Reallocates the account size.
 
-    // The length of the data is never more than `10_000_000`; adding a `u16`
-    // will never overflow the `usize` limit.
-    let length = account.data_len() + extend_length as usize;
+    // The length of the data is never more than `10_000_000`; adding at most
+    // `MAX_PERMITTED_DATA_INCREASE` will never overflow the `usize` limit.
+    let length = account.data_len() + extend_length;
+
+    if let Some(payer) = payer {
+        fund_account(account, payer, length)?;
+    }
+
     // SAFETY: `account` is not borrowed at this point.
     unsafe { account.resize_unchecked(length) }
 }
@@ -4,23 +4,27 @@ use pinocchio::{
     cpi::{Seed, Signer},
     error::ProgramError,
     instruction::seeds,
-    AccountView, Address, ProgramResult,
+    AccountView, Address, ProgramResult, Resize,
 };
 use pinocchio_system::instructions::CreateAccountAllowPrefund;
 
-use crate::{
-    processor::derive_program_address,
-    state::{
-        header::Header, AccountDiscriminator, Compression, DataSource, Encoding, Format, Zeroable,
+use crate::state::{
+    buffer::Buffer,
+    extension::{
+        EXTENSIONS_ALIGNMENT, EXTENSIONS_LENGTH_LEN, EXTENSION_PREFIX_LEN, SEED_EXTENSION_TYPE,
     },
-    ID,
+    header::{Header, EXTENSIONS_FLAG},
+    AccountDiscriminator, Compression, DataSource, Encoding, Format, Zeroable, HASH_LEN,
+    LAYOUT_VERSION, SEED_LEN, VARIABLE_SEED_LEN,
 };
 
-use super::is_program_authority;
+use super::{
+    close_account, derive_metadata_address, is_authority, optional_hash, record_hash, seed_prefix,
+    source_range, validate_authority, validate_external_account, variable_seed,
+};
 
 /// Processor for the [`Initialize`](`crate::instruction::ProgramMetadataInstruction::Initialize`)
 /// instruction.
-#[allow(clippy::arithmetic_side_effects)]
 pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
     // Validates the instruction data.
 
@@ -32,12 +36,84 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
         (unsafe { Initialize::load_unchecked(args) }, remaining_data)
     };
 
+    let (hash, remaining_data) = optional_hash(remaining_data)?;
+
+    process_initialize(
+        accounts,
+        &args.seed,
+        false,
+        &args.args,
+        hash,
+        remaining_data,
+    )
+}
+
+/// Processor for the [`InitializeWithSeed`](`crate::instruction::ProgramMetadataInstruction::InitializeWithSeed`)
+/// instruction.
+pub fn initialize_with_seed(
+    accounts: &mut [AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // Validates the instruction data.
+
+    let (args, remaining_data) = if instruction_data.len() < InitializeWithSeed::LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    } else {
+        let (args, remaining_data) = instruction_data.split_at(InitializeWithSeed::LEN);
+        // SAFETY: `instruction_data` length is checked above.
+        (
+            unsafe { InitializeWithSeed::load_unchecked(args) },
+            remaining_data,
+        )
+    };
+
+    let seed = variable_seed(&args.seed)?;
+    let (hash, remaining_data) = optional_hash(remaining_data)?;
+
+    process_initialize(accounts, seed, true, &args.args, hash, remaining_data)
+}
+
+/// Initializes a metadata account derived from the given `seed`.
+///
+/// The `variable` argument indicates whether the `seed` is a variable-length
+/// seed, whose length is stored in the header. The content `hash` (if any) is
+/// recorded once the account is initialized.
+#[allow(clippy::arithmetic_side_effects)]
+fn process_initialize(
+    accounts: &mut [AccountView],
+    seed: &[u8],
+    variable: bool,
+    args: &InitializeArgs,
+    hash: Option<&[u8; HASH_LEN]>,
+    remaining_data: &[u8],
+) -> ProgramResult {
     // Access accounts.
 
-    let [metadata, authority, program, program_data, _system_program, _remaining @ ..] = accounts
+    let [metadata, authority, program, program_data, _system_program, remaining @ ..] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    // The `source_buffer` account is optional and holds the data to copy to the
+    // metadata account; the `destination` account is optional and receives the
+    // lamports of the `source_buffer` account, which is closed after its data is
+    // copied; the `external` account is optional and is the account referenced
+    // by `external` data.
+    let (source_buffer, destination, external) = match remaining {
+        [source_buffer, destination, external, ..] => {
+            (Some(source_buffer), Some(destination), Some(external))
+        }
+        [source_buffer, destination] => (Some(source_buffer), Some(destination), None),
+        [source_buffer] => (Some(source_buffer), None, None),
+        [] => (None, None, None),
+    };
+    let source_buffer = source_buffer.filter(|source_buffer| source_buffer.address() != &crate::ID);
+    let destination = destination.filter(|destination| destination.address() != &crate::ID);
+    let external = external.filter(|external| external.address() != &crate::ID);
+
+    // The `destination` account only receives the lamports of a `source_buffer`.
+    if source_buffer.is_none() && destination.is_some() {
+        return Err(ProgramError::InvalidArgument);
+    }
 
     // Account validation.
     //
@@ -46,7 +122,8 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
 
     // authority
     // - must be a signer
-    // - must either be the program upgrade authority (for canonical metadata accounts)
+    // - must either be the program authority (for canonical metadata accounts), e.g.
+    //   the upgrade authority of a program or the mint authority of a token mint
     //   OR be included in the seeds used to derive the metadata account address (for
     //   non-canonical metadata accounts)
 
@@ -54,7 +131,7 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let canonical: bool = is_program_authority(program, program_data, authority.address())?;
+    let canonical: bool = is_authority(program, program_data, authority.address())?;
 
     // metadata
     // - must be a PDA derived from the program ID and the seed
@@ -64,24 +141,41 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
     //   instruction data is allowed as the data must already be written to the account
     // - must have lamports (pre-funded account); the runtime will ensure that the
     //   account is rent exempt
+    //
+    // source_buffer (if provided)
+    // - metadata account must be empty
+    // - must be a buffer account owned by the program
+    // - authority must be a valid authority for the buffer
+    //
+    // destination (if provided)
+    // - requires a `source_buffer`
+    // - must not be the same account as `source_buffer` or `metadata`
+    //
+    // external (required for `external` data with an owner and digest)
+    // - must be the account referenced by the data
+    // - must be owned by the expected owner
+    // - the referenced range of its data must match the expected digest
 
-    let (derived_metadata, bump) = if canonical {
-        derive_program_address(&[program.address().as_array(), args.seed.as_ref()], &ID)
-    } else {
-        derive_program_address(
-            &[
-                program.address().as_array(),
-                authority.address().as_array(),
-                args.seed.as_ref(),
-            ],
-            &ID,
-        )
-    };
+    let (derived_metadata, bump) = derive_metadata_address(
+        program.address(),
+        (!canonical).then_some(authority.address()),
+        seed,
+    );
 
     if metadata.address() != &derived_metadata {
         return Err(ProgramError::InvalidSeeds);
     }
 
+    // The bytes of a variable-length seed past `SEED_LEN` are stored in the extension
+    // area, which precedes the data.
+    let seed_extension = seed
+        .get(SEED_LEN..)
+        .filter(|seed_extension| !seed_extension.is_empty());
+    let area_len = seed_extension.map_or(0, |seed_extension| {
+        (EXTENSIONS_LENGTH_LEN + EXTENSION_PREFIX_LEN + seed_extension.len())
+            .next_multiple_of(EXTENSIONS_ALIGNMENT)
+    });
+
     let discriminator = {
         // SAFETY: scoped immutable borrow of `metadata` account data.
         AccountDiscriminator::try_from_bytes(unsafe { metadata.borrow_unchecked() })?
@@ -101,43 +195,101 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
             if !remaining_data.is_empty() {
                 return Err(ProgramError::InvalidInstructionData);
             }
+            // The data is already written to the account, so there is nothing
+            // to copy from a source buffer.
+            if source_buffer.is_some() {
+                return Err(ProgramError::InvalidArgument);
+            }
             // A pre-allocated buffer length should be at least the size of the
             // `Header`.
-            metadata
+            let data_length = metadata
                 .data_len()
                 .checked_sub(Header::LEN)
-                .ok_or(ProgramError::InvalidAccountData)?
+                .ok_or(ProgramError::InvalidAccountData)?;
+
+            // Makes room for the extension area (if needed) by moving the data
+            // after it. The runtime will ensure that the account is still rent
+            // exempt.
+            if area_len > 0 {
+                // SAFETY: There are no active borrows to the `metadata` account data.
+                unsafe { metadata.resize_unchecked(metadata.data_len() + area_len)? };
+                // SAFETY: single mutable borrow of `metadata` account data.
+                let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+                metadata_account_data.copy_within(
+                    Header::LEN..Header::LEN + data_length,
+                    Header::LEN + area_len,
+                );
+            }
+
+            data_length
         }
         Some(AccountDiscriminator::Metadata) => {
             return Err(ProgramError::AccountAlreadyInitialized)
         }
+        Some(AccountDiscriminator::PendingAuthority) => {
+            return Err(ProgramError::InvalidAccountData);
+        }
         None => {
-            // Ensure remaining data is provided.
-            if remaining_data.is_empty() {
-                return Err(ProgramError::InvalidInstructionData);
-            }
+            // Get data from the source buffer (if provided) or the remaining instruction
+            // data. When copying from the source buffer, the remaining instruction data
+            // can hold the range of the buffer data to copy.
+            let data = match source_buffer.as_ref() {
+                Some(source_buffer) => {
+                    if destination.as_ref().is_some_and(|destination| {
+                        destination.address() == source_buffer.address()
+                            || destination.address() == metadata.address()
+                    }) {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+                    // Since the `source_buffer` account is not written to unless it
+                    // is closed, validate the ownership of the account.
+                    if !source_buffer.owned_by(&crate::ID) {
+                        return Err(ProgramError::InvalidAccountOwner);
+                    }
+                    // SAFETY: single immutable borrow of `source_buffer` account data.
+                    let buffer_data = unsafe { source_buffer.borrow_unchecked() };
+
+                    if !matches!(
+                        AccountDiscriminator::try_from_bytes(buffer_data)?,
+                        Some(AccountDiscriminator::Buffer)
+                    ) {
+                        return Err(ProgramError::InvalidAccountData);
+                    }
+                    validate_authority(
+                        Buffer::from_bytes(buffer_data)?,
+                        authority,
+                        program,
+                        program_data,
+                    )?;
+
+                    let range = (!remaining_data.is_empty()).then_some(remaining_data);
+                    source_range(&buffer_data[Header::LEN..], range)?
+                }
+                // Ensure remaining data is provided.
+                None if remaining_data.is_empty() => {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                None => remaining_data,
+            };
 
             // Allocate and assign the metadata account.
             let signer_bump = &[bump];
             let signer_seeds: &[Seed] = if canonical {
                 // canonical
-                &seeds!(
-                    program.address().as_array(),
-                    args.seed.as_ref(),
-                    signer_bump
-                )
+                &seeds!(program.address().as_array(), seed, signer_bump)
             } else {
                 // non-canonical
                 &seeds!(
                     program.address().as_array(),
                     authority.address().as_array(),
-                    args.seed.as_ref(),
+                    seed,
                     signer_bump
                 )
             };
             let signer = &[Signer::from(signer_seeds)];
-            // Instruction data is limited to ~1232 bytes.
-            let space = Header::LEN + remaining_data.len();
+            // The data is limited by either the instruction data (~1232 bytes) or
+            // the length of the source buffer data.
+            let space = Header::LEN + area_len + data.len();
 
             CreateAccountAllowPrefund {
                 to: metadata,
@@ -151,21 +303,21 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
             // guaranteed to be allocated and assigned to the program.
             let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
 
-            // Copy the instruction remaining data to the metadata account.
+            // Copy the data to the metadata account.
             //
             // SAFETY: `metadata` account has been allocated and assigned to the program
-            // and the length of the remaining data was checked.
+            // with enough space for the data.
             unsafe {
                 core::ptr::copy_nonoverlapping(
-                    remaining_data.as_ptr(),
+                    data.as_ptr(),
                     metadata_account_data
-                        .get_unchecked_mut(Header::LEN..)
+                        .get_unchecked_mut(Header::LEN + area_len..)
                         .as_mut_ptr(),
-                    remaining_data.len(),
+                    data.len(),
                 );
             }
 
-            remaining_data.len()
+            data.len()
         }
     };
 
@@ -175,6 +327,43 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
         return Err(ProgramError::AccountNotRentExempt);
     }
 
+    // Writes the extension area holding the seed extension.
+
+    if let Some(seed_extension) = seed_extension {
+        // SAFETY: scoped mutable borrow of `metadata` account data. The data has been
+        // validated to hold `Header::LEN + area_len + data_length` bytes.
+        let area = unsafe {
+            metadata
+                .borrow_unchecked_mut()
+                .get_unchecked_mut(Header::LEN..Header::LEN + area_len)
+        };
+        let (length, extension) = area.split_at_mut(EXTENSIONS_LENGTH_LEN);
+        // The area is at most `24` bytes long.
+        length.copy_from_slice(&(area_len as u16).to_le_bytes());
+        extension[..2].copy_from_slice(&SEED_EXTENSION_TYPE.to_le_bytes());
+        extension[2..4].copy_from_slice(&(seed_extension.len() as u16).to_le_bytes());
+
+        let (value, padding) = extension[EXTENSION_PREFIX_LEN..].split_at_mut(seed_extension.len());
+        value.copy_from_slice(seed_extension);
+        padding.fill(0);
+    }
+
+    let data_source = {
+        // SAFETY: scoped immutable borrow of `metadata` account data. The data
+        // has been validated to hold `Header::LEN + area_len + data_length` bytes.
+        let data = unsafe {
+            metadata
+                .borrow_unchecked()
+                .get_unchecked(Header::LEN + area_len..)
+        };
+        let data_source = DataSource::try_from(args.data_source)?;
+        data_source.validate_data(data)?;
+        if data_source == DataSource::External {
+            validate_external_account(data, external.as_deref())?;
+        }
+        data_source
+    };
+
     // Initialize the metadata account.
 
     // SAFETY: there are no other active borrows to `metadata` account data and
@@ -189,16 +378,29 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
     };
     header.mutable = true as u8;
     header.canonical = canonical as u8;
-    header.seed.copy_from_slice(args.seed.as_ref());
+    header.seed = seed_prefix(seed);
+    // Variable-length seeds are at most `32` bytes long.
+    header.seed_length = if variable { seed.len() as u8 } else { 0 };
     header.encoding = Encoding::try_from(args.encoding)? as u8;
     header.compression = Compression::try_from(args.compression)? as u8;
     header.format = Format::try_from(args.format)? as u8;
-    header.data_source = {
-        let data_source = DataSource::try_from(args.data_source)?;
-        data_source.validate_data_length(data_length)?;
-        data_source as u8
-    };
+    header.data_source = data_source as u8;
     header.data_length = (data_length as u32).to_le_bytes();
+    header.flags = if area_len > 0 { EXTENSIONS_FLAG } else { 0 };
+    header._reserved = [0; 2];
+    header.version = LAYOUT_VERSION;
+
+    // Record the content hash (if provided). The runtime will ensure that the
+    // account is still rent exempt.
+
+    record_hash(metadata, hash, false)?;
+
+    // Close the source buffer account (if needed), now that the data has been
+    // copied.
+
+    if let (Some(source_buffer), Some(destination)) = (source_buffer, destination) {
+        close_account(source_buffer, destination)?;
+    }
 
     Ok(())
 }
@@ -206,16 +408,34 @@ pub fn initialize(accounts: &mut [AccountView], instruction_data: &[u8]) -> Prog
 /// The instruction data for the `Initialize` instruction.
 #[repr(C)]
 struct Initialize {
-    pub seed: [u8; 16],
+    pub seed: [u8; SEED_LEN],
+    pub args: InitializeArgs,
+}
+
+/// The instruction data for the `InitializeWithSeed` instruction.
+#[repr(C)]
+struct InitializeWithSeed {
+    /// Encoded variable-length seed.
+    pub seed: [u8; VARIABLE_SEED_LEN],
+    pub args: InitializeArgs,
+}
+
+/// The arguments following the seed in the instruction data.
+#[repr(C)]
+struct InitializeArgs {
     pub encoding: u8,
     pub compression: u8,
     pub format: u8,
     pub data_source: u8,
+    // hash:
+    // - `u8`: option tag
+    // - `[u8; 32]`: content hash (if the tag is `1`)
 }
 
-// Enforces 1-byte alignment for the struct.
+// Enforces 1-byte alignment for the structs.
 const _: () = {
     assert!(align_of::<Initialize>() == 1);
+    assert!(align_of::<InitializeWithSeed>() == 1);
 };
 
 impl Initialize {
@@ -229,3 +449,15 @@ impl Initialize {
         &*(bytes.as_ptr() as *const Self)
     }
 }
+
+impl InitializeWithSeed {
+    const LEN: usize = size_of::<Self>();
+
+    /// # Safety
+    ///
+    /// The `bytes` length is validated on the processor.
+    #[inline(always)]
+    pub(crate) unsafe fn load_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const Self)
+    }
+}
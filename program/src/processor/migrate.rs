@@ -0,0 +1,85 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult, Resize};
+
+use crate::state::{header::Header, LAYOUT_VERSION};
+
+use super::{validate_authority, validate_metadata, withdraw_excess_lamports};
+
+/// Processor for the [`Migrate`](`crate::instruction::ProgramMetadataInstruction::Migrate`)
+/// instruction.
+pub fn migrate(accounts: &mut [AccountView]) -> ProgramResult {
+    // Access accounts.
+
+    let [metadata, authority, program, program_data, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    // The `destination` account is optional and receives the excess lamports
+    // of the `metadata` account.
+    let destination = remaining
+        .first_mut()
+        .filter(|destination| destination.address() != &crate::ID);
+
+    // Account validation.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    let (version, length) = {
+        // metadata
+        // - must be initialized
+        // - must be mutable
+        // - must be rent exempt (pre-funded account) since we might be reallocating
+        //   the account (checked by the runtime)
+
+        // SAFETY: scoped immutable borrow of `metadata` account data for validation.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = validate_metadata(metadata_account_data)?;
+
+        // authority
+        // - must be a signer
+        // - must match the authority set on the `metadata` account OR it must be the
+        //   program upgrade authority if the `metadata` account is canonical
+
+        validate_authority(header, authority, program, program_data)?;
+
+        // destination (if provided)
+        // - must not be the same account as `metadata`
+
+        if destination
+            .as_ref()
+            .is_some_and(|destination| destination.address() == metadata.address())
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        #[allow(clippy::arithmetic_side_effects)]
+        // The length of the data is never more than `10_000_000`.
+        let length = header.data_offset(metadata_account_data)? + header.data_length() as usize;
+
+        (header.version(), length)
+    };
+
+    // Upgrades the account layout, one version at a time.
+
+    if version == 0 {
+        // Version `0` accounts might have any amount of bytes after the data (e.g.,
+        // pre-funded accounts); the account is resized to hold exactly the header,
+        // the optional sections and the data.
+        if metadata.data_len() != length {
+            // SAFETY: There are no other active borrows to the `metadata` account data.
+            unsafe { metadata.resize_unchecked(length)? };
+        }
+    }
+
+    // SAFETY: There are no other active borrows to the `metadata` account data and
+    // the account has been validated to be a mutable metadata account.
+    let header = unsafe { Header::from_bytes_mut_unchecked(metadata.borrow_unchecked_mut()) };
+    header.version = LAYOUT_VERSION;
+
+    // Withdraw the excess lamports of the metadata account (if needed).
+
+    if let Some(destination) = destination {
+        withdraw_excess_lamports(metadata, destination)?;
+    }
+
+    Ok(())
+}
@@ -1,130 +1,489 @@
-use pinocchio::{address::ADDRESS_BYTES, error::ProgramError, AccountView, Address};
+use pinocchio::{
+    cpi::Signer,
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult, Resize,
+};
+use pinocchio_system::instructions::{CreateAccountAllowPrefund, Transfer};
 
+use self::authority::is_authority;
 use crate::{
     error::ProgramMetadataError,
-    state::{header::Header, Account, AccountDiscriminator},
+    state::{
+        data::ExternalDataV2,
+        extension::{
+            Extensions, EXTENSIONS_ALIGNMENT, EXTENSIONS_LENGTH_LEN, EXTENSION_PREFIX_LEN,
+            REVISION_EXTENSION_TYPE,
+        },
+        header::{Header, EXTENSIONS_FLAG, HASH_FLAG},
+        Account, AccountDiscriminator, DataSource, HASH_LEN, LAYOUT_VERSION, SEED_LEN,
+        VARIABLE_SEED_LEN,
+    },
+    ID,
 };
 
+pub mod accept_authority;
 pub mod allocate;
+mod authority;
+pub mod cancel_authority;
 pub mod close;
 pub mod extend;
 pub mod initialize;
+pub mod migrate;
+pub mod move_metadata;
+pub mod patch;
+pub mod promote;
+pub mod propose_authority;
 pub mod set_authority;
 pub mod set_data;
+pub mod set_data_checked;
+pub mod set_extension;
+pub mod set_hash;
 pub mod set_immutable;
 pub mod trim;
+pub mod verify_hash;
 pub mod write;
 
-/// The program ID of the SVM Loader `v3`.
-const BPF_LOADER_UPGRABABLE_ID: Address = Address::new_from_array([
-    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61, 22,
-    193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
-]);
+/// Ensures the `metadata` account is valid and mutable.
+///
+/// The following validation checks are performed:
+///
+/// - The `metadata` account discriminator (first byte) must
+///   be [`AccountDiscriminator::Metadata`].
+/// - The `metadata` account layout version must be supported by the program.
+/// - The `metadata` account must be mutable (`mutable = true`).
+#[inline(always)]
+fn validate_metadata(bytes: &[u8]) -> Result<&Header, ProgramError> {
+    let header = validate_initialized_metadata(bytes)?;
+    if !header.mutable() {
+        return Err(ProgramMetadataError::ImmutableMetadataAccount.into());
+    }
+    Ok(header)
+}
 
-/// Checks if the provided `authority` is the authority allowed to update the `program`.
-/// Fails when providing unexpected input.
+/// Ensures the `metadata` account is valid, without requiring it to be mutable.
 ///
 /// The following validation checks are performed:
 ///
-/// - `program` account must be executable.
+/// - The `metadata` account discriminator (first byte) must
+///   be [`AccountDiscriminator::Metadata`].
+/// - The `metadata` account layout version must be supported by the program.
+#[inline(always)]
+fn validate_initialized_metadata(bytes: &[u8]) -> Result<&Header, ProgramError> {
+    let header = Header::from_bytes(bytes)?;
+    if header.discriminator != AccountDiscriminator::Metadata as u8 {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    if header.version() > LAYOUT_VERSION {
+        return Err(ProgramMetadataError::UnsupportedAccountVersion.into());
+    }
+    Ok(header)
+}
+
+/// Returns the data section of a metadata account.
 ///
-/// - When a program is owned by SVM Loader `v2`, program must match the authority;
-///   otherwise, the `program_data` account must be provided.
+/// The `bytes` are expected to represent a metadata account. The account does
+/// not need to be mutable, since its data is only read.
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+fn metadata_data(bytes: &[u8]) -> Result<&[u8], ProgramError> {
+    let header = Header::from_bytes(bytes)?;
+    if header.version() > LAYOUT_VERSION {
+        return Err(ProgramMetadataError::UnsupportedAccountVersion.into());
+    }
+    let offset = header.data_offset(bytes)?;
+
+    // The data offset is bounded by the account length and the data length
+    // is a `u32` value.
+    bytes
+        .get(offset..offset + header.data_length() as usize)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Returns the range of the `source` data selected by the `range` argument.
 ///
-/// For SVM Loader `v2` programs:
+/// The `range` argument is either `None`, which selects all the `source` data,
+/// or 8 bytes holding a `u32` offset and a `u32` length of the range.
+#[inline(always)]
+fn source_range<'a>(source: &'a [u8], range: Option<&[u8]>) -> Result<&'a [u8], ProgramError> {
+    match range {
+        None => Ok(source),
+        Some(&[o0, o1, o2, o3, l0, l1, l2, l3]) => {
+            let offset = u32::from_le_bytes([o0, o1, o2, o3]) as usize;
+            let length = u32::from_le_bytes([l0, l1, l2, l3]) as usize;
+            source
+                .get(offset..offset.saturating_add(length))
+                .ok_or(ProgramError::InvalidArgument)
+        }
+        Some(_) => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns the seed of an encoded variable-length seed.
 ///
-/// - `program` account discriminator (first byte) must be `2`, i.e., defining a
-///   `Program` account.
+/// The encoded seed holds the seed length (`u8`) followed by [`MAX_SEED_LEN`](crate::state::MAX_SEED_LEN)
+/// zero-padded seed bytes; the length must be between `1` and [`MAX_SEED_LEN`](crate::state::MAX_SEED_LEN).
+#[inline(always)]
+fn variable_seed(bytes: &[u8; VARIABLE_SEED_LEN]) -> Result<&[u8], ProgramError> {
+    let [length, seed @ ..] = bytes;
+
+    match *length as usize {
+        0 => Err(ProgramError::InvalidInstructionData),
+        length => seed
+            .get(..length)
+            .ok_or(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns the optional content hash at the start of the instruction data,
+/// followed by the remaining instruction data.
 ///
-/// - `program_data` account must be the one set on the `program` account data.
+/// The hash is encoded as an `Option<[u8; HASH_LEN]>` — i.e. a `u8` tag (`0` or
+/// `1`) followed by the hash bytes when the tag is `1`.
+#[inline(always)]
+fn optional_hash(bytes: &[u8]) -> Result<(Option<&[u8; HASH_LEN]>, &[u8]), ProgramError> {
+    match bytes.split_first() {
+        Some((0, remaining)) => Ok((None, remaining)),
+        Some((1, remaining)) => remaining
+            .split_first_chunk::<HASH_LEN>()
+            .map(|(hash, remaining)| (Some(hash), remaining))
+            .ok_or(ProgramError::InvalidInstructionData),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns the first [`SEED_LEN`] bytes of a seed, zero-padded.
 ///
-/// - `program_data` account discriminator (first byte) must be `3`, i.e., defining
-///   a `ProgramData` account.
+/// This is the value stored in the `seed` field of metadata and buffer accounts.
+#[inline(always)]
+fn seed_prefix(seed: &[u8]) -> [u8; SEED_LEN] {
+    let mut prefix = [0u8; SEED_LEN];
+    let length = seed.len().min(SEED_LEN);
+    prefix[..length].copy_from_slice(&seed[..length]);
+    prefix
+}
+
+/// Returns the SHA-256 digest of the `data`.
+#[inline(always)]
+fn sha256(data: &[u8]) -> [u8; HASH_LEN] {
+    solana_sha256_hasher::hash(data).to_bytes()
+}
+
+/// Returns the content hash of a metadata account, if there is one.
 ///
-/// - `program_data` account must have 32 bytes of data in the range `[13..45]`,
-///   matching the provided `authority`.
-#[allow(clippy::arithmetic_side_effects)]
+/// The `bytes` are expected to represent a metadata account.
 #[inline(always)]
-fn is_program_authority(
-    program: &AccountView,
-    program_data: &AccountView,
-    authority: &Address,
-) -> Result<bool, ProgramError> {
-    // For BPFv1 and BPF Loader v2 programs, there is no program data associated. In this case,
-    // the keypair used to deploy the program must be the authority and sign the transaction.
-    if !program.owned_by(&BPF_LOADER_UPGRABABLE_ID) {
-        return Ok(program.executable() && program.address() == authority);
-    }
-
-    // For BPFv3 programs, we need the program data account to check the auhtority.
-    if program_data.address() == &crate::ID {
-        return Ok(false);
-    }
-
-    let expected_program_data = {
-        let data = unsafe { program.borrow_unchecked() };
-        match (data.first(), program.executable()) {
-            // The discriminator is 4 bytes, but we only need to check the first byte
-            // since there are fewer than 256 account types.
-            (Some(2), true) => {
-                let offset: usize = 4;
-                Address::try_from(&data[offset..offset + ADDRESS_BYTES])
-                    .map_err(|_| ProgramError::InvalidAccountData)?
-            }
-            _ => {
-                return Err(ProgramMetadataError::InvalidProgramState.into());
-            }
-        }
+fn content_hash(bytes: &[u8]) -> Result<Option<&[u8; HASH_LEN]>, ProgramError> {
+    let header = Header::from_bytes(bytes)?;
+
+    if !header.has_hash() {
+        return Ok(None);
+    }
+
+    bytes
+        .get(Header::LEN..Header::LEN + HASH_LEN)
+        .and_then(|hash| hash.try_into().ok())
+        .map(Some)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Ensures the `external` account matches the external data it is referenced by.
+///
+/// This only applies to external data recording an expected owner and content
+/// digest ([`ExternalDataV2`]): the `external` account must be the referenced
+/// account, be owned by the expected owner, and the SHA-256 digest of the
+/// referenced range of its data must match the expected digest.
+#[inline(always)]
+fn validate_external_account(data: &[u8], external: Option<&AccountView>) -> ProgramResult {
+    let Some(external_data) = ExternalDataV2::read_unaligned(data) else {
+        return Ok(());
     };
 
-    // Program <-> Program Data check.
-    if expected_program_data != *program_data.address() {
-        return Err(ProgramMetadataError::InvalidProgramDataAccount.into());
-    }
-
-    // Program Data checks.
-    let is_program_authority = {
-        let data = unsafe { program_data.borrow_unchecked() };
-        match (data.first(), program_data.executable()) {
-            (Some(3 /* program data discriminator */), false) => {
-                let option_offset: usize = 4 /* discriminator */ + 8 /* slot */;
-                if data[option_offset] == 1 {
-                    let pubkey_offset: usize = option_offset + 1 /* option */;
-                    // The `authority_key` is a `Pubkey`.
-                    let authority_key =
-                        Address::try_from(&data[pubkey_offset..pubkey_offset + ADDRESS_BYTES])
-                            .map_err(|_| ProgramError::InvalidAccountData)?;
-                    authority == &authority_key
-                } else {
-                    false
+    let external = external.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if external.address() != &external_data.address {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !external.owned_by(&external_data.owner) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // SAFETY: scoped immutable borrow of `external` account data.
+    let external_account_data = unsafe { external.borrow_unchecked() };
+    let offset = external_data.offset as usize;
+    let referenced = match external_data.length.as_ref() {
+        Some(length) => external_account_data.get(offset..offset.saturating_add(*length as usize)),
+        None => external_account_data.get(offset..),
+    }
+    .ok_or(ProgramError::InvalidAccountData)?;
+
+    if sha256(referenced) != external_data.digest {
+        return Err(ProgramMetadataError::HashMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Records the content hash of a `metadata` account.
+///
+/// When the data is stored in the `metadata` account (`direct` data source), the
+/// hash is computed from the data and must match the provided `hash` (if any); it
+/// is also recomputed when `keep` is set, so an existing hash stays up to date.
+/// Otherwise, the provided `hash` is recorded as is. Nothing is written when there
+/// is no hash to record.
+///
+/// The `metadata` account must be validated to be initialized and mutable (see
+/// [`write_hash`]).
+#[inline(always)]
+fn record_hash(
+    metadata: &mut AccountView,
+    hash: Option<&[u8; HASH_LEN]>,
+    keep: bool,
+) -> ProgramResult {
+    let hash = {
+        // SAFETY: scoped immutable borrow of `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+
+        match Header::from_bytes(metadata_account_data)?.data_source()? {
+            DataSource::Direct if hash.is_some() || keep => {
+                let digest = sha256(metadata_data(metadata_account_data)?);
+                if hash.is_some_and(|hash| hash != &digest) {
+                    return Err(ProgramMetadataError::HashMismatch.into());
                 }
+                Some(digest)
             }
-            _ => {
-                return Err(ProgramMetadataError::InvalidProgramState.into());
-            }
+            _ => hash.copied(),
         }
     };
 
-    Ok(is_program_authority)
+    match hash {
+        Some(hash) => write_hash(metadata, Some(&hash)),
+        None => Ok(()),
+    }
 }
 
-/// Ensures the `metadata` account is valid and mutable.
+/// Inserts, updates or removes the content hash section of a `metadata` account.
 ///
-/// The following validation checks are performed:
+/// The account is resized as needed, moving the data. The `metadata` account must
+/// be validated to be initialized and mutable. Since the account might be
+/// reallocated, it must be rent exempt with the new size (pre-funded account),
+/// which is checked by the runtime.
+#[allow(clippy::arithmetic_side_effects)]
+fn write_hash(metadata: &mut AccountView, hash: Option<&[u8; HASH_LEN]>) -> ProgramResult {
+    let has_hash = {
+        // SAFETY: scoped immutable borrow of `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = Header::from_bytes(metadata_account_data)?;
+
+        // The hash section must be within the account data.
+        if metadata_account_data.len() < header.data_offset(metadata_account_data)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        header.has_hash()
+    };
+
+    // The data length of a metadata account is never more than `10_000_000`, so
+    // adding the length of the hash will never overflow the `usize` limit.
+
+    let data_len = metadata.data_len();
+
+    match (hash, has_hash) {
+        (Some(hash), true) => {
+            // SAFETY: There are no other active borrows to the `metadata` account
+            // data and the account has been validated to hold the hash section.
+            unsafe {
+                metadata
+                    .borrow_unchecked_mut()
+                    .get_unchecked_mut(Header::LEN..Header::LEN + HASH_LEN)
+                    .copy_from_slice(hash);
+            }
+        }
+        (Some(hash), false) => {
+            // SAFETY: There are no other active borrows to the `metadata` account data.
+            unsafe { metadata.resize_unchecked(data_len + HASH_LEN)? };
+
+            // SAFETY: There are no other active borrows to the `metadata` account
+            // data and the account has been reallocated to accommodate the hash.
+            let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+            // Move the data after the hash section.
+            metadata_account_data.copy_within(Header::LEN..data_len, Header::LEN + HASH_LEN);
+            metadata_account_data[Header::LEN..Header::LEN + HASH_LEN].copy_from_slice(hash);
+
+            // SAFETY: `metadata` has been validated to be initialized and mutable.
+            let header = unsafe { Header::from_bytes_mut_unchecked(metadata_account_data) };
+            header.flags |= HASH_FLAG;
+        }
+        (None, true) => {
+            // SAFETY: There are no other active borrows to the `metadata` account data.
+            let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+            // Move the data over the hash section.
+            metadata_account_data.copy_within(Header::LEN + HASH_LEN..data_len, Header::LEN);
+
+            // SAFETY: `metadata` has been validated to be initialized and mutable.
+            let header = unsafe { Header::from_bytes_mut_unchecked(metadata_account_data) };
+            header.flags &= !HASH_FLAG;
+
+            // SAFETY: There are no other active borrows to the `metadata` account data.
+            unsafe { metadata.resize_unchecked(data_len - HASH_LEN)? };
+        }
+        // Nothing to remove.
+        (None, false) => (),
+    }
+
+    Ok(())
+}
+
+/// Sets the `value` of the extension of the given type of a `metadata` account.
 ///
-/// - The `metadata` account discriminator (first byte) must
-///   be [`AccountDiscriminator::Metadata`].
-/// - The `metadata` account must be mutable (`mutable = true`).
-#[inline(always)]
-fn validate_metadata(bytes: &[u8]) -> Result<&Header, ProgramError> {
-    let header = Header::from_bytes(bytes)?;
-    if header.discriminator != AccountDiscriminator::Metadata as u8 {
-        return Err(ProgramError::UninitializedAccount);
+/// Any existing value of the same type is replaced, while an empty `value` removes
+/// the extension. The account is resized as needed, moving the data, and the
+/// extension area is removed once it holds no extensions.
+///
+/// The `metadata` account must be validated to be initialized and mutable. Since
+/// the account might be reallocated, it must be rent exempt with the new size
+/// (pre-funded account), which is checked by the runtime.
+#[allow(clippy::arithmetic_side_effects)]
+fn write_extension(metadata: &mut AccountView, extension_type: u16, value: &[u8]) -> ProgramResult {
+    let (area_offset, area_len, used_len, existing) = {
+        // SAFETY: scoped immutable borrow of `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = Header::from_bytes(metadata_account_data)?;
+        let area_offset = header.extensions_offset();
+
+        if header.has_extensions() {
+            let extensions = Extensions::from_bytes(
+                metadata_account_data
+                    .get(area_offset..)
+                    .ok_or(ProgramError::InvalidAccountData)?,
+            )?;
+            (
+                area_offset,
+                extensions.len(),
+                extensions.used_len(),
+                extensions.position(extension_type),
+            )
+        } else {
+            if metadata_account_data.len() < area_offset {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            (area_offset, 0, EXTENSIONS_LENGTH_LEN, None)
+        }
+    };
+
+    // Nothing to remove.
+    if value.is_empty() && existing.is_none() {
+        return Ok(());
     }
-    if !header.mutable() {
-        return Err(ProgramMetadataError::ImmutableMetadataAccount.into());
+
+    // Compute the new length of the extension area; the area is removed when
+    // there are no extensions left.
+
+    let mut new_used_len = used_len - existing.as_ref().map_or(0, |range| range.len());
+
+    if !value.is_empty() {
+        new_used_len += EXTENSION_PREFIX_LEN + value.len();
     }
-    Ok(header)
+
+    let new_area_len = if new_used_len == EXTENSIONS_LENGTH_LEN {
+        0
+    } else {
+        new_used_len.next_multiple_of(EXTENSIONS_ALIGNMENT)
+    };
+
+    if new_area_len > u16::MAX as usize {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let value_len = u16::try_from(value.len()).map_err(|_| ProgramError::InvalidArgument)?;
+    let data_offset = area_offset + area_len;
+    let data_len = metadata.data_len();
+
+    // Grow the account (if needed), moving the data to make room for the
+    // extension area.
+
+    if new_area_len > area_len {
+        // SAFETY: There are no other active borrows to the `metadata` account data.
+        unsafe { metadata.resize_unchecked(data_len + (new_area_len - area_len))? };
+        // SAFETY: There are no other active borrows to the `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+        metadata_account_data.copy_within(data_offset..data_len, area_offset + new_area_len);
+    }
+
+    // Update the extension area.
+
+    {
+        // SAFETY: There are no other active borrows to the `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+        let area =
+            &mut metadata_account_data[area_offset..area_offset + new_area_len.max(area_len)];
+
+        // Remove the existing extension, moving the following ones over it.
+        let mut offset = match existing {
+            Some(range) => {
+                area.copy_within(range.end..used_len, range.start);
+                used_len - range.len()
+            }
+            None => used_len,
+        };
+
+        // Append the new value (if any).
+        if !value.is_empty() {
+            area[offset..offset + 2].copy_from_slice(&extension_type.to_le_bytes());
+            area[offset + 2..offset + 4].copy_from_slice(&value_len.to_le_bytes());
+            offset += EXTENSION_PREFIX_LEN;
+            area[offset..offset + value.len()].copy_from_slice(value);
+            offset += value.len();
+        }
+
+        // Clear the padding and store the length of the area.
+        area[offset..].fill(0);
+
+        if new_area_len > 0 {
+            area[..EXTENSIONS_LENGTH_LEN].copy_from_slice(&(new_area_len as u16).to_le_bytes());
+        }
+
+        // SAFETY: `metadata` has been validated to be initialized and mutable.
+        let header = unsafe { Header::from_bytes_mut_unchecked(metadata_account_data) };
+
+        if new_area_len > 0 {
+            header.flags |= EXTENSIONS_FLAG;
+        } else {
+            header.flags &= !EXTENSIONS_FLAG;
+        }
+    }
+
+    // Shrink the account (if needed), moving the data over the unused space.
+
+    if new_area_len < area_len {
+        // SAFETY: There are no other active borrows to the `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+        metadata_account_data.copy_within(data_offset..data_len, area_offset + new_area_len);
+        // SAFETY: There are no other active borrows to the `metadata` account data.
+        unsafe { metadata.resize_unchecked(data_len - (area_len - new_area_len))? };
+    }
+
+    Ok(())
+}
+
+/// Increments the revision of a `metadata` account.
+///
+/// The revision is stored in the [`REVISION_EXTENSION_TYPE`] extension, which is
+/// added on the first increment. The `metadata` account must be validated to be
+/// initialized and mutable (see [`write_extension`]).
+#[inline(always)]
+fn increment_revision(metadata: &mut AccountView) -> ProgramResult {
+    let revision = {
+        // SAFETY: scoped immutable borrow of `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        Header::from_bytes(metadata_account_data)?
+            .revision(metadata_account_data)?
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+    };
+
+    write_extension(metadata, REVISION_EXTENSION_TYPE, &revision.to_le_bytes())
 }
 
 /// Ensures the `metadata` account can be updated by the provided `authority`.
@@ -133,8 +492,8 @@ fn validate_metadata(bytes: &[u8]) -> Result<&Header, ProgramError> {
 ///
 /// - `authority` account must be a signer.
 /// - `authority` account must match the authority set on the `metadata`
-///   account OR it must be the program upgrade authority if the `metadata`
-///   account is canonical (see `is_program_authority`).
+///   account OR it must be the authority of the `program` account if the
+///   `metadata` account is canonical (see `authority::is_authority`).
 #[inline(always)]
 fn validate_authority<T: Account>(
     account: &T,
@@ -147,21 +506,181 @@ fn validate_authority<T: Account>(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    if !is_account_authority(account, authority.address(), program, program_data)? {
+        Err(ProgramError::IncorrectAuthority)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if the provided `authority` can update the `account`, i.e., it
+/// matches the authority set on the `account` OR it is the program authority
+/// if the `account` is canonical (see `authority::is_authority`).
+#[inline(always)]
+fn is_account_authority<T: Account>(
+    account: &T,
+    authority: &Address,
+    program: &AccountView,
+    program_data: &AccountView,
+) -> Result<bool, ProgramError> {
     // The authority is the set authority.
     let explicitly_authorized = match account.get_authority() {
-        Some(metadata_authority) => metadata_authority == authority.address(),
+        Some(account_authority) => account_authority == authority,
         None => false,
     };
 
-    // The authority is the program upgrade authority for canonical metadata accounts.
-    let authorized = explicitly_authorized
+    // The authority is the program authority for canonical metadata accounts.
+    Ok(explicitly_authorized
         || (account.is_canonical(program.address())
-            && is_program_authority(program, program_data, authority.address())?);
+            && is_authority(program, program_data, authority)?))
+}
 
-    if !authorized {
-        Err(ProgramError::IncorrectAuthority)
-    } else {
-        Ok(())
+/// Closes a program-owned `account`, moving all of its lamports to the
+/// `destination` account.
+#[inline(always)]
+fn close_account(account: &mut AccountView, destination: &mut AccountView) -> ProgramResult {
+    let account_lamports = account.lamports();
+    let destination_lamports = destination.lamports();
+
+    destination.set_lamports(
+        destination_lamports
+            .checked_add(account_lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+    account.set_lamports(0);
+
+    account.close()
+}
+
+/// Moves the lamports of a program-owned `account` above its rent-exempt
+/// minimum balance to the `destination` account.
+///
+/// The `destination` account must not be the same account as `account`.
+#[inline(always)]
+fn withdraw_excess_lamports(
+    account: &mut AccountView,
+    destination: &mut AccountView,
+) -> ProgramResult {
+    let minimum_balance = Rent::get()?.try_minimum_balance(account.data_len())?;
+
+    // Current lamports should always be greater than or equal to the minimum
+    // balance since the account must be rent exempt.
+    let excess_lamports = account
+        .lamports()
+        .checked_sub(minimum_balance)
+        .ok_or(ProgramError::AccountNotRentExempt)?;
+    let destination_lamports = destination.lamports();
+
+    destination.set_lamports(
+        destination_lamports
+            .checked_add(excess_lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+    account.set_lamports(minimum_balance);
+
+    Ok(())
+}
+
+/// Transfers the lamports `account` needs to be rent exempt with `length`
+/// bytes of data from the `payer` account.
+///
+/// The `payer` account must be a signer and the system program must be
+/// provided to the instruction, since the lamports are transferred via CPI.
+#[inline(always)]
+fn fund_account(account: &AccountView, payer: &AccountView, length: usize) -> ProgramResult {
+    let minimum_balance = Rent::get()?.try_minimum_balance(length)?;
+    let lamports = minimum_balance.saturating_sub(account.lamports());
+
+    if lamports > 0 {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports,
+        }
+        .invoke()?;
+    }
+
+    Ok(())
+}
+
+/// Creates the `metadata` PDA account with a copy of the first `length` bytes of
+/// the `source` account data.
+///
+/// When `close_source` is `true`, the lamports of the `source` account fund the
+/// `metadata` account and the `source` account is closed; otherwise, the
+/// `metadata` account must be pre-funded. The `length` must be within the bounds
+/// of the `source` account data.
+#[inline(always)]
+fn copy_account(
+    source: &mut AccountView,
+    metadata: &mut AccountView,
+    length: usize,
+    signer: Signer,
+    close_source: bool,
+) -> ProgramResult {
+    // The data of the `source` account is still available after its lamports are
+    // transferred, until it is closed.
+    if close_source {
+        metadata.set_lamports(
+            metadata
+                .lamports()
+                .checked_add(source.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        );
+        source.set_lamports(0);
+    }
+
+    // The metadata account must have lamports. The runtime will
+    // then ensure that the account is rent exempt.
+    if metadata.lamports() == 0 {
+        return Err(ProgramError::AccountNotRentExempt);
+    }
+
+    CreateAccountAllowPrefund {
+        to: metadata,
+        space: length as u64,
+        owner: &crate::ID,
+        funding: None,
+    }
+    .invoke_signed(&[signer])?;
+
+    // SAFETY: scoped borrows of `source` and `metadata` account data. The `metadata`
+    // account has been allocated with `length` bytes, which is within the bounds of
+    // the `source` account data.
+    unsafe {
+        core::ptr::copy_nonoverlapping(
+            source.borrow_unchecked().as_ptr(),
+            metadata.borrow_unchecked_mut().as_mut_ptr(),
+            length,
+        );
+    }
+
+    if close_source {
+        source.close()?;
+    }
+
+    Ok(())
+}
+
+/// Derives the address of a metadata or PDA buffer account.
+///
+/// Canonical accounts are derived from the `program` and the `seed`, while
+/// non-canonical accounts also include their `authority` in the derivation. The
+/// `seed` is either a fixed [`SEED_LEN`]-byte seed or a variable-length seed of
+/// up to [`MAX_SEED_LEN`](crate::state::MAX_SEED_LEN) bytes.
+#[inline(always)]
+fn derive_metadata_address(
+    program: &Address,
+    authority: Option<&Address>,
+    seed: &[u8],
+) -> (Address, u8) {
+    match authority {
+        // canonical
+        None => derive_program_address(&[program.as_array(), seed], &ID),
+        // non-canonical
+        Some(authority) => {
+            derive_program_address(&[program.as_array(), authority.as_array(), seed], &ID)
+        }
     }
 }
 
@@ -0,0 +1,336 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    instruction::seeds,
+    AccountView, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccountAllowPrefund;
+
+use crate::{
+    state::{
+        extension::{
+            Extensions, EXTENSIONS_ALIGNMENT, EXTENSIONS_LENGTH_LEN, EXTENSION_PREFIX_LEN,
+            SEED_EXTENSION_TYPE,
+        },
+        header::{Header, EXTENSIONS_FLAG},
+        pending_authority::PENDING_AUTHORITY_SEED,
+        SEED_LEN, VARIABLE_SEED_LEN,
+    },
+    ID,
+};
+
+use super::{
+    close_account, derive_metadata_address, derive_program_address, is_authority, seed_prefix,
+    validate_authority, validate_metadata, variable_seed,
+};
+
+/// Processor for the [`Move`](`crate::instruction::ProgramMetadataInstruction::Move`)
+/// instruction.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn move_metadata(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let (new_seed, variable) = match instruction_data.split_last() {
+        Some((0, new_seed)) => (new_seed, false),
+        Some((1, new_seed)) => (new_seed, true),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    let new_seed: &[u8; VARIABLE_SEED_LEN] = new_seed
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let new_seed = variable_seed(new_seed)?;
+
+    // Access accounts.
+
+    let [metadata, authority, new_metadata, program, program_data, _system_program, pending_authority, destination, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    // The `new_authority` account is optional and is only used by non-canonical
+    // metadata accounts, since the authority is part of their derivation.
+    let new_authority = remaining
+        .first()
+        .filter(|new_authority| new_authority.address() != &crate::ID);
+
+    // Account validation.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    // metadata
+    // - must be initialized
+    // - must be mutable
+    // - must be a metadata account of the program
+    //
+    // authority
+    // - must be a signer
+    // - must match the authority set on the `metadata` account OR it must be the
+    //   program upgrade authority if the `metadata` account is canonical
+    // - must be the program upgrade authority if the `metadata` account is canonical,
+    //   since a new canonical account is created
+    //
+    // new_authority (if provided)
+    // - must be a signer
+    // - must not be provided for canonical metadata accounts
+
+    let (canonical, variable_source, area_offset, used_len, seed_extension, data) = {
+        // SAFETY: scoped immutable borrow of `metadata` account data for validation.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = validate_metadata(metadata_account_data)?;
+
+        if &header.program != program.address() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        validate_authority(header, authority, program, program_data)?;
+
+        if header.canonical() {
+            if !is_authority(program, program_data, authority.address())? {
+                return Err(ProgramError::IncorrectAuthority);
+            }
+            if new_authority.is_some() {
+                return Err(ProgramError::InvalidArgument);
+            }
+        } else if new_authority.is_some_and(|new_authority| !new_authority.is_signer()) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let area_offset = header.extensions_offset();
+
+        let (area_len, used_len, seed_extension) = if header.has_extensions() {
+            let extensions = Extensions::from_bytes(
+                metadata_account_data
+                    .get(area_offset..)
+                    .ok_or(ProgramError::InvalidAccountData)?,
+            )?;
+            (
+                extensions.len(),
+                extensions.used_len(),
+                extensions.position(SEED_EXTENSION_TYPE),
+            )
+        } else {
+            (0, EXTENSIONS_LENGTH_LEN, None)
+        };
+
+        // Only the header, the optional sections and the data are copied; any
+        // trailing bytes of legacy accounts are discarded.
+        //
+        // The length of the data is never more than `10_000_000`.
+        let data_offset = area_offset + area_len;
+        let data = data_offset..data_offset + header.data_length() as usize;
+
+        if data.end > metadata_account_data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        (
+            header.canonical(),
+            header.has_variable_seed(),
+            area_offset,
+            used_len,
+            seed_extension,
+            data,
+        )
+    };
+
+    // The seed mode of the `metadata` account is kept unless a variable-length
+    // seed is requested: a fixed seed is zero-padded to `SEED_LEN` bytes, so it
+    // cannot be longer than that.
+
+    let variable = variable || variable_source;
+    let fixed_seed;
+
+    let new_seed = if variable {
+        new_seed
+    } else if new_seed.len() <= SEED_LEN {
+        fixed_seed = seed_prefix(new_seed);
+        fixed_seed.as_slice()
+    } else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    // The bytes of the new seed past `SEED_LEN` are stored in the seed extension.
+    let new_seed_extension = new_seed
+        .get(SEED_LEN..)
+        .filter(|new_seed_extension| !new_seed_extension.is_empty());
+
+    // new_metadata
+    // - must be a PDA derived from the program ID and the new seed (canonical) OR
+    //   the program ID, the new authority and the new seed (non-canonical)
+    // - must not already be initialized — i.e. it must be empty
+
+    let new_authority = new_authority.unwrap_or(authority).address();
+
+    let (derived_metadata, bump) = derive_metadata_address(
+        program.address(),
+        (!canonical).then_some(new_authority),
+        new_seed,
+    );
+
+    if new_metadata.address() != &derived_metadata {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if !new_metadata.is_data_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // pending_authority
+    // - must be a PDA derived from the `metadata` account address
+
+    let (derived_pending_authority, _) = derive_program_address(
+        &[PENDING_AUTHORITY_SEED, metadata.address().as_array()],
+        &ID,
+    );
+
+    if pending_authority.address() != &derived_pending_authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // destination
+    // - must not be the `metadata` or `pending_authority` account, since they are
+    //   closed
+
+    if destination.address() == metadata.address()
+        || destination.address() == pending_authority.address()
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Compute the extension area of the new metadata account: the seed extension
+    // of the current seed (if any) is replaced by the one of the new seed (if any),
+    // while other extensions are kept. The area is removed when there are no
+    // extensions left.
+
+    let mut new_used_len = used_len - seed_extension.as_ref().map_or(0, |range| range.len());
+
+    if let Some(new_seed_extension) = new_seed_extension {
+        new_used_len += EXTENSION_PREFIX_LEN + new_seed_extension.len();
+    }
+
+    let new_area_len = if new_used_len == EXTENSIONS_LENGTH_LEN {
+        0
+    } else {
+        new_used_len.next_multiple_of(EXTENSIONS_ALIGNMENT)
+    };
+
+    if new_area_len > u16::MAX as usize {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Move the `metadata` account to the `new_metadata` account, whose lamports
+    // fund the new account.
+
+    new_metadata.set_lamports(
+        new_metadata
+            .lamports()
+            .checked_add(metadata.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+    metadata.set_lamports(0);
+
+    let signer_bump = &[bump];
+    let signer_seeds: &[Seed] = if canonical {
+        // canonical
+        &seeds!(program.address().as_array(), new_seed, signer_bump)
+    } else {
+        // non-canonical
+        &seeds!(
+            program.address().as_array(),
+            new_authority.as_array(),
+            new_seed,
+            signer_bump
+        )
+    };
+
+    CreateAccountAllowPrefund {
+        to: new_metadata,
+        space: (area_offset + new_area_len + data.len()) as u64,
+        owner: &crate::ID,
+        funding: None,
+    }
+    .invoke_signed(&[Signer::from(signer_seeds)])?;
+
+    {
+        // SAFETY: scoped borrows of `metadata` and `new_metadata` account data. The
+        // `new_metadata` account has been allocated to hold the header, the optional
+        // sections and the data of the `metadata` account, with the new extension area.
+        let (metadata_account_data, new_metadata_account_data) = unsafe {
+            (
+                metadata.borrow_unchecked(),
+                new_metadata.borrow_unchecked_mut(),
+            )
+        };
+
+        let (sections, remaining) = new_metadata_account_data.split_at_mut(area_offset);
+        let (area, new_data) = remaining.split_at_mut(new_area_len);
+
+        // Copy the header and the content hash (if any).
+        sections.copy_from_slice(&metadata_account_data[..area_offset]);
+        // Copy the data.
+        new_data.copy_from_slice(&metadata_account_data[data]);
+
+        if new_area_len > 0 {
+            // Copy the extensions other than the seed extension; the new account data
+            // is zeroed, so the padding of the area is already cleared.
+            let extensions = area_offset + EXTENSIONS_LENGTH_LEN..area_offset + used_len;
+            let (before, after) = match seed_extension {
+                Some(range) => (
+                    extensions.start..area_offset + range.start,
+                    area_offset + range.end..extensions.end,
+                ),
+                None => (extensions, 0..0),
+            };
+
+            let mut offset = EXTENSIONS_LENGTH_LEN;
+
+            for range in [before, after] {
+                area[offset..offset + range.len()]
+                    .copy_from_slice(&metadata_account_data[range.clone()]);
+                offset += range.len();
+            }
+
+            // Append the seed extension of the new seed (if any).
+            if let Some(new_seed_extension) = new_seed_extension {
+                area[offset..offset + 2].copy_from_slice(&SEED_EXTENSION_TYPE.to_le_bytes());
+                area[offset + 2..offset + 4]
+                    .copy_from_slice(&(new_seed_extension.len() as u16).to_le_bytes());
+                offset += EXTENSION_PREFIX_LEN;
+                area[offset..offset + new_seed_extension.len()].copy_from_slice(new_seed_extension);
+            }
+
+            area[..EXTENSIONS_LENGTH_LEN].copy_from_slice(&(new_area_len as u16).to_le_bytes());
+        }
+
+        // SAFETY: `new_metadata` holds a copy of the validated `metadata` header.
+        let header = unsafe { Header::from_bytes_mut_unchecked(new_metadata_account_data) };
+
+        header.seed = seed_prefix(new_seed);
+        // Variable-length seeds are at most `32` bytes long; fixed seeds have a
+        // zero length.
+        header.seed_length = if variable { new_seed.len() as u8 } else { 0 };
+
+        if new_area_len > 0 {
+            header.flags |= EXTENSIONS_FLAG;
+        } else {
+            header.flags &= !EXTENSIONS_FLAG;
+        }
+
+        if !canonical {
+            header.authority = (*new_authority).into();
+        }
+    }
+
+    metadata.close()?;
+
+    // Close the pending authority account (if any), since the nomination is for
+    // an account that no longer exists at its address. Its lamports are moved to
+    // the `destination` account.
+
+    if pending_authority.owned_by(&ID) && !pending_authority.is_data_empty() {
+        close_account(pending_authority, destination)?;
+    }
+
+    Ok(())
+}
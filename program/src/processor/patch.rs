@@ -0,0 +1,209 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult, Resize};
+
+use crate::state::{
+    header::{Header, HASH_FLAG},
+    DataSource,
+};
+
+use super::{
+    increment_revision, record_hash, validate_authority, validate_external_account,
+    validate_metadata, withdraw_excess_lamports,
+};
+
+/// Processor for the [`Patch`](`crate::instruction::ProgramMetadataInstruction::Patch`)
+/// instruction.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn patch(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let args = Patch::try_from_bytes(instruction_data)?;
+    // The `offset` and `data_length` values are guaranteed to fit in a `usize`.
+    let offset = args.offset() as usize;
+    let data_length = args.data_length() as usize;
+    let patch = args.data();
+
+    // The patch must be within the new data length.
+    if offset + patch.len() > data_length {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Access accounts.
+
+    let [metadata, authority, program, program_data, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    // The `destination` account is optional and receives the excess lamports
+    // of the `metadata` account; the `external` account is optional and is the
+    // account referenced by `external` data.
+    let (destination, external) = match remaining {
+        [destination, external, ..] => (Some(destination), Some(external)),
+        [destination] => (Some(destination), None),
+        [] => (None, None),
+    };
+    let destination = destination.filter(|destination| destination.address() != &crate::ID);
+    let external = external.filter(|external| external.address() != &crate::ID);
+
+    // Account validation.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    let (extensions, retained, changed, has_hash) = {
+        // metadata
+        // - must be initialized
+        // - must be mutable
+        // - must be rent exempt (pre-funded account) since we might be reallocating
+        //   the account (checked by the runtime)
+
+        // SAFETY: scoped immutable borrow of `metadata` account data for validation.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = validate_metadata(metadata_account_data)?;
+
+        // authority
+        // - must be a signer
+        // - must match the authority set on the `metadata` account OR it must be the
+        //   program upgrade authority if the `metadata` account is canonical
+
+        validate_authority(header, authority, program, program_data)?;
+
+        let extensions = header.extensions_offset()..header.data_offset(metadata_account_data)?;
+        // Current data that is kept, which is limited by the account length for
+        // accounts with an inconsistent data length.
+        let retained = (header.data_length() as usize)
+            .min(data_length)
+            .min(metadata_account_data.len().saturating_sub(extensions.end));
+        // The revision is only incremented when the data actually changes, i.e.,
+        // its length or the patched bytes differ.
+        let changed = retained != data_length
+            || header.data_length() as usize != data_length
+            || metadata_account_data
+                [extensions.end + offset..extensions.end + offset + patch.len()]
+                != *patch;
+
+        // destination (if provided)
+        // - must not be the same account as `metadata`
+
+        if destination
+            .as_ref()
+            .is_some_and(|destination| destination.address() == metadata.address())
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        (extensions, retained, changed, header.has_hash())
+    };
+
+    // Move the extension area and the retained data right after the header,
+    // since the content hash section (if any) is recorded again once the data
+    // is patched.
+
+    if extensions.start != Header::LEN {
+        // SAFETY: There are no other active borrows to the `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+        metadata_account_data.copy_within(extensions.start..extensions.end + retained, Header::LEN);
+    }
+
+    let data_offset = Header::LEN + extensions.len();
+
+    // Resize the metadata account to the new data length.
+
+    // SAFETY: There are no other active borrows to the `metadata` account data.
+    //
+    // The `resize_unchecked` validates that the new size does not exceed the
+    // maximum permitted data increase.
+    unsafe { metadata.resize_unchecked(data_offset + data_length)? };
+
+    // SAFETY: There are no other active borrows to the `metadata` account data
+    // and the account has been reallocated to accommodate the new data.
+    let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+    let (header_bytes, data) = metadata_account_data.split_at_mut(data_offset);
+
+    // Bytes after the retained data are either zero-extended or left over from
+    // moving the data, so they are cleared before applying the patch.
+    data[retained..].fill(0);
+    data[offset..offset + patch.len()].copy_from_slice(patch);
+
+    // SAFETY: `header_bytes` holds at least `Header::LEN` bytes of a validated
+    // metadata account.
+    let header = unsafe { Header::from_bytes_mut_unchecked(header_bytes) };
+
+    // The patched data must be valid for the data source.
+    let data_source = header.data_source()?;
+    data_source.validate_data(data)?;
+
+    // external (required for `external` data with an owner and digest)
+    // - must be the account referenced by the data
+    // - must be owned by the expected owner
+    // - the referenced range of its data must match the expected digest
+
+    if data_source == DataSource::External {
+        validate_external_account(data, external.as_deref())?;
+    }
+
+    header.data_length = (data_length as u32).to_le_bytes();
+    // The hash section is removed while the data is patched.
+    header.flags &= !HASH_FLAG;
+
+    if changed {
+        increment_revision(metadata)?;
+    }
+
+    // Record the content hash (if needed). An existing hash is recomputed when
+    // the data is stored in the metadata account and discarded otherwise.
+
+    record_hash(metadata, None, has_hash)?;
+
+    // Withdraw the excess lamports of the metadata account (if needed).
+
+    if let Some(destination) = destination {
+        withdraw_excess_lamports(metadata, destination)?;
+    }
+
+    Ok(())
+}
+
+/// Instruction data expected by the `Patch` instruction.
+struct Patch<'a> {
+    /// Offset to write to, relative to the start of the data.
+    offset: &'a [u8; 4],
+
+    /// Length of the data after the patch.
+    data_length: &'a [u8; 4],
+
+    /// Bytes to write.
+    data: &'a [u8],
+}
+
+impl Patch<'_> {
+    #[inline]
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Patch<'_>, ProgramError> {
+        // The minimum expected size of the instruction data.
+        // - offset (4 bytes)
+        // - data_length (4 bytes)
+        // - data (...n bytes, optional)
+        if bytes.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Patch {
+            offset: unsafe { &*(bytes.as_ptr() as *const [u8; 4]) },
+            data_length: unsafe { &*(bytes.as_ptr().add(4) as *const [u8; 4]) },
+            data: unsafe { bytes.get_unchecked(8..) },
+        })
+    }
+
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        u32::from_le_bytes(*self.offset)
+    }
+
+    #[inline]
+    pub fn data_length(&self) -> u32 {
+        u32::from_le_bytes(*self.data_length)
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        self.data
+    }
+}
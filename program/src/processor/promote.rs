@@ -0,0 +1,149 @@
+use pinocchio::{
+    cpi::Signer, error::ProgramError, instruction::seeds, AccountView, Address, ProgramResult,
+};
+
+use crate::{
+    error::ProgramMetadataError,
+    state::{header::Header, AccountDiscriminator, Zeroable, LAYOUT_VERSION},
+};
+
+use super::{copy_account, derive_metadata_address, is_authority, validate_authority};
+
+/// Processor for the [`Promote`](`crate::instruction::ProgramMetadataInstruction::Promote`)
+/// instruction.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn promote(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let copy = match instruction_data {
+        [0] => false,
+        [1] => true,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    // Access accounts.
+
+    let [metadata, authority, source, program, program_data, _system_program, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    // The `source_authority` account is only required when moving the `source`
+    // account, since it is closed.
+    let source_authority = remaining
+        .first()
+        .filter(|source_authority| source_authority.address() != &crate::ID);
+
+    // Account validation.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    // authority
+    // - must be a signer
+    // - must be the program upgrade authority
+
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !is_authority(program, program_data, authority.address())? {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    // source
+    // - must be an initialized non-canonical metadata account of the program
+    // - must be mutable when moving the account
+    // - `source_authority` must be a signer and match the authority set on the
+    //   `source` account when moving the account
+    //
+    // metadata
+    // - must be the canonical PDA derived from the program ID and the seed of the
+    //   `source` account
+    // - must not already be initialized — i.e. it must be empty
+    // - must have lamports when copying the account (pre-funded account); when moving
+    //   the account, the lamports of the `source` account are transferred to it (checked
+    //   by `copy_account`)
+
+    // Since the `source` account is not written to unless it is moved, validate
+    // the ownership of the account.
+    if !source.owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let (seed, seed_len, length) = {
+        // SAFETY: scoped immutable borrow of `source` account data for validation.
+        let source_data = unsafe { source.borrow_unchecked() };
+        let header = Header::from_bytes(source_data)?;
+
+        if header.discriminator != AccountDiscriminator::Metadata as u8 {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if header.version() > LAYOUT_VERSION {
+            return Err(ProgramMetadataError::UnsupportedAccountVersion.into());
+        }
+        if header.canonical() || &header.program != program.address() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !copy {
+            // Immutable accounts cannot be closed, so they can only be copied.
+            if !header.mutable() {
+                return Err(ProgramMetadataError::ImmutableMetadataAccount.into());
+            }
+            let source_authority = source_authority.ok_or(ProgramError::NotEnoughAccountKeys)?;
+            validate_authority(header, source_authority, program, program_data)?;
+        }
+
+        // Only the header, the optional sections and the data are copied; any
+        // trailing bytes of legacy accounts are discarded.
+        //
+        // The length of the data is never more than `10_000_000`.
+        let length = header.data_offset(source_data)? + header.data_length() as usize;
+
+        if length > source_data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (seed, seed_len) = header.derivation_seed(source_data)?;
+
+        (seed, seed_len, length)
+    };
+    let seed = &seed[..seed_len];
+
+    let (derived_metadata, bump) = derive_metadata_address(program.address(), None, seed);
+
+    if metadata.address() != &derived_metadata {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if !metadata.is_data_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Copy the `source` account to the `metadata` account, closing the `source`
+    // account when it is moved.
+
+    copy_account(
+        source,
+        metadata,
+        length,
+        Signer::from(&seeds!(program.address().as_ref(), seed, &[bump])),
+        !copy,
+    )?;
+
+    // SAFETY: there are no other active borrows to `metadata` account data and
+    // the account length has been validated to be sufficient to hold a `Header`.
+    let header = unsafe { Header::from_bytes_mut_unchecked(metadata.borrow_unchecked_mut()) };
+
+    // The canonical account is managed by the program upgrade authority, even if
+    // the `source` account was immutable.
+    header.authority = Address::ZERO.into();
+    header.mutable = true as u8;
+    header.canonical = true as u8;
+    // Trailing bytes of legacy accounts are not copied, so the canonical account
+    // always uses the current layout.
+    header.version = LAYOUT_VERSION;
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    instruction::seeds,
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccountAllowPrefund;
+
+use crate::{
+    processor::derive_program_address,
+    state::{
+        buffer::Buffer,
+        pending_authority::{PendingAuthority, PENDING_AUTHORITY_SEED},
+        AccountDiscriminator, Zeroable,
+    },
+    ID,
+};
+
+use super::{validate_authority, validate_metadata};
+
+/// Processor for the [`ProposeAuthority`](`crate::instruction::ProgramMetadataInstruction::ProposeAuthority`)
+/// instruction.
+pub fn propose_authority(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let new_authority: Address = instruction_data
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // Removing the authority is done through the `set_authority` instruction, so
+    // a new authority must always be provided.
+    if new_authority.is_zero() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Access accounts.
+
+    let [account, authority, program, program_data, pending_authority, _system_program, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Account validation.
+
+    // account
+    // - must be owned by the program (it is not written to)
+    // - must be a buffer or metadata account
+    // - must be mutable (metadata)
+    // - cannot be a non-canonical metadata account
+    //
+    // authority
+    // - must be a signer (checked in `validate_authority`)
+    // - must be a valid authority for the account
+
+    if !account.owned_by(&ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    {
+        // SAFETY: scoped immutable borrow of `account` account data.
+        let account_data = unsafe { account.borrow_unchecked() };
+
+        match AccountDiscriminator::try_from_bytes(account_data)? {
+            Some(AccountDiscriminator::Buffer) => {
+                let buffer = Buffer::from_bytes(account_data)?;
+                validate_authority(buffer, authority, program, program_data)?;
+            }
+            Some(AccountDiscriminator::Metadata) => {
+                let header = validate_metadata(account_data)?;
+
+                if !header.canonical() {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                validate_authority(header, authority, program, program_data)?;
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    // pending_authority
+    // - must be a PDA derived from the account address
+    // - must either be empty, in which case it is allocated and assigned to the
+    //   program; OR already hold a pending authority, in which case the previous
+    //   nomination is replaced
+    // - must have lamports (pre-funded account) when empty; the runtime will ensure
+    //   that the account is rent exempt
+
+    let (derived_pending_authority, bump) =
+        derive_program_address(&[PENDING_AUTHORITY_SEED, account.address().as_array()], &ID);
+
+    if pending_authority.address() != &derived_pending_authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let discriminator = {
+        // SAFETY: scoped immutable borrow of `pending_authority` account data.
+        AccountDiscriminator::try_from_bytes(unsafe { pending_authority.borrow_unchecked() })?
+    };
+
+    match discriminator {
+        Some(AccountDiscriminator::PendingAuthority) => (),
+        None => {
+            // Allocate and assign the pending authority account.
+            let signer_bump = &[bump];
+            let signer_seeds: &[Seed] = &seeds!(
+                PENDING_AUTHORITY_SEED,
+                account.address().as_array(),
+                signer_bump
+            );
+            let signer = &[Signer::from(signer_seeds)];
+
+            CreateAccountAllowPrefund {
+                to: pending_authority,
+                space: PendingAuthority::LEN as u64,
+                owner: &ID,
+                funding: None,
+            }
+            .invoke_signed(signer)?;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // Store the nominated authority.
+
+    // SAFETY: single mutable borrow of `pending_authority` account data.
+    let pending_authority_data = unsafe { pending_authority.borrow_unchecked_mut() };
+    let pending = PendingAuthority::from_bytes_mut(pending_authority_data)?;
+
+    pending.discriminator = AccountDiscriminator::PendingAuthority as u8;
+    pending.account = *account.address();
+    pending.authority = new_authority;
+    pending.proposer = *authority.address();
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResul
 use crate::{
     error::ProgramMetadataError,
     processor::validate_authority,
-    state::{buffer::Buffer, header::Header, AccountDiscriminator, Zeroable},
+    state::{buffer::Buffer, header::Header, AccountDiscriminator, Zeroable, LAYOUT_VERSION},
 };
 
 /// Processor for the [`SetAuthority`](`crate::instruction::ProgramMetadataInstruction::SetAuthority`)
@@ -73,6 +73,10 @@ pub fn set_authority(accounts: &mut [AccountView], instruction_data: &[u8]) -> P
                 return Err(ProgramMetadataError::ImmutableMetadataAccount.into());
             }
 
+            if header.version() > LAYOUT_VERSION {
+                return Err(ProgramMetadataError::UnsupportedAccountVersion.into());
+            }
+
             validate_authority(header, authority, program, program_data)?;
 
             header.authority = if *has_new_authority == 0 {
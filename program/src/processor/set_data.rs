@@ -2,16 +2,47 @@ use core::mem::{align_of, size_of};
 
 use pinocchio::{account::AccountView, error::ProgramError, ProgramResult, Resize};
 
-use crate::state::{
-    header::Header, AccountDiscriminator, Compression, DataSource, Encoding, Format,
+use crate::{
+    error::ProgramMetadataError,
+    state::{
+        buffer::Buffer,
+        header::{Header, HASH_FLAG},
+        AccountDiscriminator, Compression, DataSource, Encoding, Format, HASH_LEN,
+    },
 };
 
-use super::{validate_authority, validate_metadata};
+use super::{
+    close_account, content_hash, increment_revision, metadata_data, optional_hash, record_hash,
+    source_range, validate_authority, validate_external_account, validate_metadata,
+    withdraw_excess_lamports,
+};
 
 /// Processor for the [`SetData`](`crate::instruction::ProgramMetadataInstruction::SetData`)
 /// instruction.
-#[allow(clippy::arithmetic_side_effects)]
 pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    process_set_data(accounts, instruction_data, None)
+}
+
+/// Expected state of a metadata account before its data is updated.
+pub(crate) enum DataExpectation<'a> {
+    /// The metadata account must be at the given revision.
+    Revision(u64),
+
+    /// The metadata account must have the given content hash.
+    Hash(&'a [u8; HASH_LEN]),
+}
+
+/// Sets the data of a metadata account.
+///
+/// When an `expectation` is provided, the metadata account must match it;
+/// otherwise, the update fails with a [`ProgramMetadataError::MetadataChanged`]
+/// error.
+#[allow(clippy::arithmetic_side_effects)]
+pub(crate) fn process_set_data(
+    accounts: &mut [AccountView],
+    instruction_data: &[u8],
+    expectation: Option<DataExpectation>,
+) -> ProgramResult {
     // Validates the instruction data.
 
     if instruction_data.len() < SetData::LEN {
@@ -23,7 +54,10 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
 
     // SAFETY: The length of the instruction data is validated above to
     // be at least `SetData::LEN`
-    let optional_data = unsafe { instruction_data.get_unchecked(SetData::LEN..) }
+    let (hash, optional_data) =
+        optional_hash(unsafe { instruction_data.get_unchecked(SetData::LEN..) })?;
+
+    let optional_data = optional_data
         .split_first()
         .map(
             |(data_source, remaining_data)| match remaining_data.is_empty() {
@@ -34,15 +68,26 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
 
     // Access accounts.
 
-    let [metadata, authority, buffer, program, program_data, _remaining @ ..] = accounts else {
+    let [metadata, authority, buffer, program, program_data, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    // The `destination` account is optional and receives the lamports of the
+    // `buffer` account, which is closed after its data is copied, and the excess
+    // lamports of the `metadata` account; the `external` account is optional and
+    // is the account referenced by `external` data.
+    let (destination, external) = match remaining {
+        [destination, external, ..] => (Some(destination), Some(external)),
+        [destination] => (Some(destination), None),
+        [] => (None, None),
+    };
+    let destination = destination.filter(|destination| destination.address() != &crate::ID);
+    let external = external.filter(|external| external.address() != &crate::ID);
 
     // Account validation.
     //
     // Note that program owned and writable checks are done implicitly by writing
     // to the account.
-    {
+    let (extensions, has_hash) = {
         // metadata
         // - must be initialized
         // - must be mutable
@@ -59,18 +104,55 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
         //   program upgrade authority if the `metadata` account is canonical
 
         validate_authority(header, authority, program, program_data)?;
-    }
 
-    // buffer (if `remaining_data` is `None`)
+        // expectation (if any)
+        // - must match the current revision or content hash of the `metadata` account
+
+        if let Some(expectation) = expectation {
+            let unchanged = match expectation {
+                DataExpectation::Revision(revision) => {
+                    header.revision(metadata_account_data)? == revision
+                }
+                DataExpectation::Hash(hash) => content_hash(metadata_account_data)? == Some(hash),
+            };
+
+            if !unchanged {
+                return Err(ProgramMetadataError::MetadataChanged.into());
+            }
+        }
+
+        // Range of the extension area (if any), which is preserved when the
+        // data is updated.
+        (
+            header.extensions_offset()..header.data_offset(metadata_account_data)?,
+            header.has_hash(),
+        )
+    };
+
+    // buffer (if provided)
     // - must be initialized
     // - must be owned by the program
+    // - authority must be a valid authority for the buffer (if `destination` is
+    //   provided)
     //
-    // Get data from buffer or remaining instruction data (if any).
+    // destination (if provided)
+    // - must not be the same account as `buffer` or `metadata`
+
+    if destination
+        .as_ref()
+        .is_some_and(|destination| destination.address() == metadata.address())
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Get data from buffer or remaining instruction data (if any). When copying
+    // from the buffer, the remaining instruction data can hold the range of the
+    // buffer data to copy.
     let data = match (optional_data, buffer.address() != &crate::ID) {
         (Some((data_source, Some(remaining_data))), false) => Some((data_source, remaining_data)),
-        (Some((data_source, None)), true) => {
-            // Since we are not writing to the `buffer` account, validate
-            // the ownership of the account.
+        (Some((data_source, range)), true) => {
+            // Since the `buffer` account is not written to unless it is closed,
+            // validate the ownership of the account.
             if !buffer.owned_by(&crate::ID) {
                 return Err(ProgramError::InvalidAccountOwner);
             }
@@ -79,7 +161,22 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
 
             match AccountDiscriminator::try_from_bytes(buffer_data)? {
                 Some(AccountDiscriminator::Buffer) => {
-                    Some((data_source, &buffer_data[Header::LEN..]))
+                    if let Some(destination) = destination.as_ref() {
+                        if destination.address() == buffer.address() {
+                            return Err(ProgramError::InvalidArgument);
+                        }
+                        validate_authority(
+                            Buffer::from_bytes(buffer_data)?,
+                            authority,
+                            program,
+                            program_data,
+                        )?;
+                    }
+
+                    Some((
+                        data_source,
+                        source_range(&buffer_data[Header::LEN..], range)?,
+                    ))
                 }
                 _ => return Err(ProgramError::InvalidAccountData),
             }
@@ -88,12 +185,49 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
         _ => return Err(ProgramError::InvalidInstructionData),
     };
 
+    // The revision is only incremented when the data source or the data
+    // actually changes.
+
+    let changed = data.is_some_and(|(data_source, data)| {
+        // SAFETY: scoped immutable borrow of `metadata` account data.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        // SAFETY: `metadata` is validated to be initialized.
+        let header = unsafe { Header::from_bytes_unchecked(metadata_account_data) };
+
+        header.data_source != *data_source
+            || metadata_data(metadata_account_data).ok() != Some(data)
+    });
+
     // Update header and data (if needed).
 
-    if let Some(data) = update_header(metadata, args, data).map_err(|err| match err {
+    let updated = update_header(metadata, args, data).map_err(|err| match err {
         ProgramError::InvalidAccountData => ProgramError::InvalidInstructionData,
         _ => err,
-    })? {
+    })?;
+
+    // external (required for `external` data with an owner and digest)
+    // - must be the account referenced by the data
+    // - must be owned by the expected owner
+    // - the referenced range of its data must match the expected digest
+
+    if let Some((data_source, data)) = data {
+        if *data_source == DataSource::External as u8 {
+            validate_external_account(data, external.as_deref())?;
+        }
+    }
+
+    if let Some(data) = updated {
+        // Move the extension area right after the header, since the content
+        // hash section (if any) is recorded again once the data is updated.
+
+        if extensions.start != Header::LEN {
+            // SAFETY: There are no other active borrows to the `metadata` account data.
+            let metadata_account_data = unsafe { metadata.borrow_unchecked_mut() };
+            metadata_account_data.copy_within(extensions.clone(), Header::LEN);
+        }
+
+        let data_offset = Header::LEN + extensions.len();
+
         // Realloc the metadata account if necessary.
 
         // SAFETY: There are no other active borrows to the `metadata` account data.
@@ -104,7 +238,7 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
         // the maximum data length. The runtime also enforces that the total size
         // of the account data after resizing does not exceed the maximum account
         // length.
-        unsafe { metadata.resize_unchecked(Header::LEN + data.len())? };
+        unsafe { metadata.resize_unchecked(data_offset + data.len())? };
 
         // SAFETY: There are no other active borrows to the `metadata`
         // account data and the account has been reallocated to accommodate
@@ -114,13 +248,33 @@ pub fn set_data(accounts: &mut [AccountView], instruction_data: &[u8]) -> Progra
                 data.as_ptr(),
                 metadata
                     .borrow_unchecked_mut()
-                    .get_unchecked_mut(Header::LEN..)
+                    .get_unchecked_mut(data_offset..)
                     .as_mut_ptr(),
                 data.len(),
             );
         }
     }
 
+    if changed {
+        increment_revision(metadata)?;
+    }
+
+    // Record the content hash (if needed). An existing hash is recomputed when
+    // the data is stored in the metadata account and discarded otherwise, unless
+    // a new hash is provided.
+
+    record_hash(metadata, hash, has_hash && updated.is_some())?;
+
+    // Close the buffer account and withdraw the excess lamports of the metadata
+    // account (if needed), now that the data has been copied.
+
+    if let Some(destination) = destination {
+        if buffer.address() != &crate::ID {
+            close_account(buffer, destination)?;
+        }
+        withdraw_excess_lamports(metadata, destination)?;
+    }
+
     Ok(())
 }
 
@@ -146,10 +300,12 @@ fn update_header<'a>(
         // Adjust the data source and length in the header.
         header.data_source = {
             let data_source = DataSource::try_from(*data_source)?;
-            data_source.validate_data_length(data.len())?;
+            data_source.validate_data(data)?;
             data_source as u8
         };
         header.data_length = (data.len() as u32).to_le_bytes();
+        // The hash section is removed while the data is updated.
+        header.flags &= !HASH_FLAG;
 
         Ok(Some(data))
     } else {
@@ -163,6 +319,9 @@ struct SetData {
     pub encoding: u8,
     pub compression: u8,
     pub format: u8,
+    // hash:
+    // - `u8`: option tag
+    // - `[u8; 32]`: content hash (if the tag is `1`)
     // optional data:
     // - `u8`: data_source
     // - `&[u8]`: remaining data
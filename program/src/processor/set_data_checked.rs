@@ -0,0 +1,43 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::state::HASH_LEN;
+
+use super::set_data::{process_set_data, DataExpectation};
+
+/// Length of the expected revision (`u64`).
+const REVISION_LEN: usize = 8;
+
+/// Processor for the [`SetDataChecked`](`crate::instruction::ProgramMetadataInstruction::SetDataChecked`)
+/// instruction.
+pub fn set_data_checked(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+    //
+    // The expectation is followed by the `SetData` instruction data.
+
+    let (expectation, instruction_data) = match instruction_data {
+        [0, remaining @ ..] if remaining.len() >= REVISION_LEN => {
+            let (revision, remaining) = remaining.split_at(REVISION_LEN);
+            (
+                DataExpectation::Revision(u64::from_le_bytes(
+                    revision
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                )),
+                remaining,
+            )
+        }
+        [1, remaining @ ..] if remaining.len() >= HASH_LEN => {
+            let (hash, remaining) = remaining.split_at(HASH_LEN);
+            (
+                DataExpectation::Hash(
+                    hash.try_into()
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                ),
+                remaining,
+            )
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    process_set_data(accounts, instruction_data, Some(expectation))
+}
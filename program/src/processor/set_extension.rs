@@ -0,0 +1,59 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::state::extension::{REVISION_EXTENSION_TYPE, SEED_EXTENSION_TYPE};
+
+use super::{validate_authority, validate_metadata, write_extension};
+
+/// Processor for the [`SetExtension`](`crate::instruction::ProgramMetadataInstruction::SetExtension`)
+/// instruction.
+pub fn set_extension(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let [t0, t1, value @ ..] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let extension_type = u16::from_le_bytes([*t0, *t1]);
+    // The type `0` is reserved to mark the end of the extensions and the seed
+    // and revision extension types are managed by the program.
+    if extension_type == 0
+        || extension_type == SEED_EXTENSION_TYPE
+        || extension_type == REVISION_EXTENSION_TYPE
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Access accounts.
+
+    let [metadata, authority, program, program_data] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Account validation.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    {
+        // metadata
+        // - must be initialized
+        // - must be mutable
+        // - must be rent exempt (pre-funded account) since we are reallocating the
+        //   account (checked by the runtime)
+
+        // SAFETY: scoped immutable borrow of `metadata` account data for validation.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = validate_metadata(metadata_account_data)?;
+
+        // authority
+        // - must be a signer
+        // - must match the authority set on the `metadata` account OR it must be the
+        //   program upgrade authority if the `metadata` account is canonical
+
+        validate_authority(header, authority, program, program_data)?;
+    }
+
+    // Update the extension area.
+
+    write_extension(metadata, extension_type, value)
+}
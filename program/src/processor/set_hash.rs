@@ -0,0 +1,54 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use super::{optional_hash, record_hash, validate_authority, validate_metadata, write_hash};
+
+/// Processor for the [`SetHash`](`crate::instruction::ProgramMetadataInstruction::SetHash`)
+/// instruction.
+pub fn set_hash(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let hash = match optional_hash(instruction_data)? {
+        (hash, []) => hash,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    // Access accounts.
+
+    let [metadata, authority, program, program_data] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Account validation.
+    //
+    // Note that program owned and writable checks are done implicitly by writing
+    // to the account.
+
+    {
+        // metadata
+        // - must be initialized
+        // - must be mutable
+        // - must be rent exempt (pre-funded account) since we are reallocating the
+        //   account (checked by the runtime)
+
+        // SAFETY: scoped immutable borrow of `metadata` account data for validation.
+        let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+        let header = validate_metadata(metadata_account_data)?;
+
+        // authority
+        // - must be a signer
+        // - must match the authority set on the `metadata` account OR it must be the
+        //   program upgrade authority if the `metadata` account is canonical
+
+        validate_authority(header, authority, program, program_data)?;
+    }
+
+    // Insert, update or remove the hash section.
+    //
+    // The hash (if provided) must be the SHA-256 digest of the data if the data is
+    // stored in the `metadata` account (`direct` data source).
+
+    match hash {
+        Some(hash) => record_hash(metadata, Some(hash), false),
+        None => write_hash(metadata, None),
+    }
+}
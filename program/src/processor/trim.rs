@@ -2,7 +2,7 @@ use pinocchio::{
     account::AccountView, error::ProgramError, sysvars::rent::Rent, ProgramResult, Resize,
 };
 
-use crate::state::{buffer::Buffer, header::Header, AccountDiscriminator};
+use crate::state::{buffer::Buffer, AccountDiscriminator};
 
 use super::{validate_authority, validate_metadata};
 
@@ -46,7 +46,7 @@ pub fn trim(accounts: &mut [AccountView]) -> ProgramResult {
                 let header = validate_metadata(data)?;
                 validate_authority(header, authority, program, program_data)?;
                 // The length of the data is never more than `10_000_000`.
-                Header::LEN + header.data_length() as usize
+                header.data_offset(data)? + header.data_length() as usize
             }
             _ => return Err(ProgramError::UninitializedAccount),
         }
@@ -0,0 +1,45 @@
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::{error::ProgramMetadataError, state::HASH_LEN};
+
+use super::{content_hash, validate_initialized_metadata};
+
+/// Processor for the [`VerifyHash`](`crate::instruction::ProgramMetadataInstruction::VerifyHash`)
+/// instruction.
+pub fn verify_hash(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data.
+
+    let expected_hash: &[u8; HASH_LEN] = instruction_data
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // Access accounts.
+
+    let [metadata, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Account validation.
+
+    // metadata
+    // - must be owned by the program (it is not written to)
+    // - must be initialized
+    // - must have a supported layout version
+    //
+    // Note that immutable metadata accounts can be verified.
+
+    if !metadata.owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // SAFETY: single immutable borrow of `metadata` account data.
+    let metadata_account_data = unsafe { metadata.borrow_unchecked() };
+    validate_initialized_metadata(metadata_account_data)?;
+
+    // Compare the content hash.
+
+    match content_hash(metadata_account_data)? {
+        Some(hash) if hash == expected_hash => Ok(()),
+        _ => Err(ProgramMetadataError::HashMismatch.into()),
+    }
+}
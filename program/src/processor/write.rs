@@ -4,6 +4,8 @@ use pinocchio::{error::ProgramError, AccountView, ProgramResult, Resize};
 
 use crate::state::{buffer::Buffer, header::Header, AccountDiscriminator};
 
+use super::{fund_account, metadata_data, source_range};
+
 /// Processor for the [`Write`](`crate::instruction::ProgramMetadataInstruction::Write`)
 /// instruction.
 #[allow(clippy::arithmetic_side_effects)]
@@ -16,9 +18,14 @@ pub fn write(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramRe
 
     // Access accounts.
 
-    let [target_buffer, authority, source_buffer, _remaining @ ..] = accounts else {
+    let [target_buffer, authority, source_buffer, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    // The `payer` account is optional and funds the lamports required for the
+    // new size of the buffer account.
+    let payer = remaining
+        .first()
+        .filter(|payer| payer.address() != &crate::ID);
 
     // Account validation.
 
@@ -31,14 +38,19 @@ pub fn write(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramRe
 
     // target_buffer
     // - must be initialized
-    // - must be rent exempt (pre-funded account) since we are reallocating
-    //   the account (checked by the runtime); both `allocate` and `initialize`
-    //   ensure that the account has at least some lamports
+    // - must be rent exempt (pre-funded account or funded by `payer`) since we
+    //   are reallocating the account (checked by the runtime); both `allocate`
+    //   and `initialize` ensure that the account has at least some lamports
     //
     // source_buffer (if `args.data()` is empty)
     // - must be initialized
     // - must be owned by the program
     // - must not be the same account as `target_buffer`
+    // - can be either a buffer or a (possibly immutable) metadata account, in
+    //   which case its data section is copied
+    //
+    // payer (if provided)
+    // - must be a signer (checked by the system program)
 
     let (required_length, source_data) = {
         // SAFETY: scoped immutable borrow of `buffer` account data. There
@@ -77,13 +89,19 @@ pub fn write(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramRe
 
         let source_data = match (instruction_data, source_buffer_data) {
             (Some(instruction_data), None) => instruction_data,
-            (None, Some(buffer_data)) => match AccountDiscriminator::try_from_bytes(buffer_data)? {
-                // `source_buffer` and `target_buffer` must not be the same account.
-                Some(AccountDiscriminator::Buffer) if source_buffer != target_buffer => {
-                    &buffer_data[Header::LEN..]
-                }
-                _ => return Err(ProgramError::InvalidAccountData),
-            },
+            // When copying from the `source_buffer`, the instruction data can
+            // hold the range of the source data to copy.
+            (range, Some(buffer_data)) => {
+                let buffer_data = match AccountDiscriminator::try_from_bytes(buffer_data)? {
+                    // `source_buffer` and `target_buffer` must not be the same account.
+                    Some(AccountDiscriminator::Buffer) if source_buffer != target_buffer => {
+                        &buffer_data[Header::LEN..]
+                    }
+                    Some(AccountDiscriminator::Metadata) => metadata_data(buffer_data)?,
+                    _ => return Err(ProgramError::InvalidAccountData),
+                };
+                source_range(buffer_data, range)?
+            }
             _ => return Err(ProgramError::InvalidInstructionData),
         };
 
@@ -94,6 +112,10 @@ pub fn write(accounts: &mut [AccountView], instruction_data: &[u8]) -> ProgramRe
 
     // Writes the source data to the buffer account.
 
+    if let Some(payer) = payer {
+        fund_account(target_buffer, payer, required_length)?;
+    }
+
     // SAFETY: `target_buffer` account is not borrowed at this point.
     unsafe { target_buffer.resize_unchecked(required_length)? };
 
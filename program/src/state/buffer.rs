@@ -50,14 +50,26 @@ pub struct Buffer {
 
     /// Seed used to derive the PDA.
     ///
-    /// Only for buffer PDA accounts; otherwise `[0u8; 16]`.
+    /// Only for buffer PDA accounts; otherwise `[0u8; 16]`. For variable-length
+    /// seeds, holds the first [`SEED_LEN`] bytes of the seed (zero-padded).
     pub seed: [u8; SEED_LEN],
 
+    /// Version of the account layout.
+    ///
+    /// Buffers created before layout versioning have version `0`.
+    pub(crate) version: u8,
+
+    /// Length of a variable-length seed.
+    ///
+    /// Buffers derived from a fixed [`SEED_LEN`]-byte seed and keypair buffers
+    /// have length `0`.
+    pub(crate) seed_length: u8,
+
     /// Extra padding for alignment.
     ///
     /// This makes the `Buffer` header section to be the same size as
     /// the metadata [`Header`](`super::Header`).
-    _padding: [u8; 14],
+    _padding: [u8; 12],
 }
 
 // Enforces 1-byte alignment for the struct.
@@ -79,6 +91,11 @@ impl Buffer {
         self.canonical != 0
     }
 
+    #[inline(always)]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
     /// Returns a `Buffer` from its account info.
     ///
     /// This method will perform the following validations on the account info:
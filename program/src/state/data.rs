@@ -1,8 +1,8 @@
 use core::{mem::align_of, str::from_utf8};
 
-use pinocchio::{error::ProgramError, Address};
+use pinocchio::{address::ADDRESS_BYTES, error::ProgramError, Address};
 
-use super::{DataSource, ZeroableOption};
+use super::{DataSource, Zeroable, ZeroableOption, HASH_LEN};
 
 /// Represents the variable data associated with a metadata account.
 pub enum Data<'a> {
@@ -14,6 +14,20 @@ pub enum Data<'a> {
 
     /// Data is stored in an external account.
     External(&'a ExternalData),
+
+    /// Data is stored in an external account with an expected owner and
+    /// content digest.
+    ExternalV2(&'a ExternalDataV2),
+
+    /// Data is the content of another metadata account.
+    Alias(&'a AliasData),
+
+    /// Data is stored on a content-addressed network and identified by its
+    /// content identifier.
+    ContentAddressed(ContentData<'a>),
+
+    /// Data is split in chunks stored across multiple accounts.
+    Chunked(ChunkedData<'a>),
 }
 
 impl<'a> Data<'a> {
@@ -27,6 +41,14 @@ impl<'a> Data<'a> {
             DataSource::Url => Data::Url(UrlData(
                 from_utf8(bytes).map_err(|_| ProgramError::InvalidArgument)?,
             )),
+            DataSource::External if bytes.len() == ExternalDataV2::LEN => {
+                if !(bytes.as_ptr() as usize).is_multiple_of(align_of::<ExternalDataV2>()) {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                // SAFETY: `bytes` was validated to have the expected length
+                // to hold a `Data::ExternalV2` reference.
+                Data::ExternalV2(unsafe { &*(bytes.as_ptr() as *const ExternalDataV2) })
+            }
             DataSource::External => {
                 if bytes.len() < ExternalData::LEN {
                     return Err(ProgramError::InvalidArgument);
@@ -38,6 +60,9 @@ impl<'a> Data<'a> {
                 // to hold a `Data::External` reference.
                 Data::External(unsafe { &*(bytes.as_ptr() as *const ExternalData) })
             }
+            DataSource::Alias => Data::Alias(AliasData::from_bytes(bytes)?),
+            DataSource::ContentAddressed => Data::ContentAddressed(ContentData::from_bytes(bytes)?),
+            DataSource::Chunked => Data::Chunked(ChunkedData::from_bytes(bytes)?),
         })
     }
 }
@@ -84,3 +109,310 @@ const _: () = {
 impl ExternalData {
     pub const LEN: usize = core::mem::size_of::<ExternalData>();
 }
+
+/// Type to represent external data with owner and content constraints.
+///
+/// In addition to the fields of [`ExternalData`], which it extends, it records
+/// the program expected to own the external account and the SHA-256 digest of
+/// the referenced data. Both are checked against the external account when the
+/// data is set, and allow clients to detect when the external account has later
+/// been closed and replaced, or its data modified.
+///
+/// Both versions use the `External` data source and are distinguished by their
+/// length.
+//
+// Note: `ExternalDataV2` may be loaded directly from account data after
+// only a length check (no owner check). All fields must be valid for any
+// bit pattern.
+#[repr(C)]
+pub struct ExternalDataV2 {
+    /// Address of the external account.
+    pub address: Address,
+
+    /// Offset of the data in the external account.
+    ///
+    /// Default to 0.
+    pub offset: u32,
+
+    /// Length of the data in the external account.
+    ///
+    /// Default to 0, which means the whole account.
+    pub length: ZeroableOption<u32>,
+
+    /// Program expected to own the external account.
+    pub owner: Address,
+
+    /// SHA-256 digest of the data in the external account.
+    pub digest: [u8; HASH_LEN],
+}
+
+// Enforces 4-byte alignment for the `ExternalDataV2` struct.
+const _: () = {
+    assert!(align_of::<ExternalDataV2>() == 4);
+};
+
+impl ExternalDataV2 {
+    pub const LEN: usize = core::mem::size_of::<ExternalDataV2>();
+
+    /// Offset of the `owner` field.
+    const OWNER_OFFSET: usize = ExternalData::LEN;
+
+    /// Validates the fields of an `ExternalDataV2` stored in `bytes`.
+    ///
+    /// This method does not require `bytes` to be aligned, so it can validate
+    /// instruction data. It checks that `bytes` has the expected length and
+    /// that the owner is not the default address. The external account itself
+    /// is validated by the processors that set the data.
+    pub fn validate_bytes(bytes: &[u8]) -> Result<(), ProgramError> {
+        match bytes.get(Self::OWNER_OFFSET..Self::OWNER_OFFSET + ADDRESS_BYTES) {
+            Some(owner) if bytes.len() == Self::LEN && owner != Address::ZERO.as_ref() => Ok(()),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    /// Reads an `ExternalDataV2` from `bytes`, if `bytes` has the expected length.
+    ///
+    /// This method does not require `bytes` to be aligned.
+    pub fn read_unaligned(bytes: &[u8]) -> Option<Self> {
+        (bytes.len() == Self::LEN).then(|| {
+            // SAFETY: `bytes` holds `Self::LEN` bytes and all fields of the struct
+            // are valid for any bit pattern.
+            unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const Self) }
+        })
+    }
+}
+
+/// Type to represent alias data.
+///
+/// Alias data contains a reference (address) to another metadata account,
+/// whose content is used in place of the content of the aliasing account.
+/// Aliases are followed by clients, which must detect cycles.
+//
+// Note: `AliasData` may be loaded directly from account data after only a
+// length check (no owner check). All fields must be valid for any bit
+// pattern.
+#[repr(C)]
+pub struct AliasData {
+    /// Address of the aliased metadata account.
+    pub address: Address,
+}
+
+// Enforces 1-byte alignment for the `AliasData` struct.
+const _: () = {
+    assert!(align_of::<AliasData>() == 1);
+};
+
+impl AliasData {
+    pub const LEN: usize = core::mem::size_of::<AliasData>();
+
+    /// Return an `AliasData` from the given bytes.
+    ///
+    /// This method validates that `bytes` has the expected length and that
+    /// the aliased address is not the default address.
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // SAFETY: `bytes` was validated to have the expected length and
+        // `AliasData` has an alignment of 1.
+        let alias = unsafe { &*(bytes.as_ptr() as *const AliasData) };
+
+        if alias.address.is_zero() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(alias)
+    }
+}
+
+/// Type to represent content-addressed data.
+///
+/// Content-addressed data is stored as the protocol identifier, followed by
+/// the SHA-256 digest of the content and its content identifier (`str`):
+///
+/// ```text
+/// [ protocol (u8) | digest ([u8; 32]) | identifier (str) ]
+/// ```
+///
+/// Unlike URLs, a content identifier cannot be repointed to different content;
+/// the digest allows clients to verify the content after downloading it.
+pub struct ContentData<'a> {
+    /// Network where the content is stored.
+    pub protocol: ContentProtocol,
+
+    /// SHA-256 digest of the content.
+    pub digest: &'a [u8; HASH_LEN],
+
+    /// Content identifier on the network.
+    pub identifier: &'a str,
+}
+
+impl<'a> ContentData<'a> {
+    /// Length of the fixed part of the data (protocol and digest).
+    pub const PREFIX_LEN: usize = 1 + HASH_LEN;
+
+    /// Maximum length of a content identifier.
+    pub const MAX_IDENTIFIER_LEN: usize = 128;
+
+    /// Return a `ContentData` from the given bytes.
+    ///
+    /// This method validates that the protocol is known and that the
+    /// identifier has the expected shape for the protocol.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        if bytes.len() <= Self::PREFIX_LEN
+            || bytes.len() > Self::PREFIX_LEN + Self::MAX_IDENTIFIER_LEN
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let protocol = ContentProtocol::try_from(bytes[0])?;
+        // SAFETY: `bytes` was validated to hold the digest.
+        let digest = unsafe { &*(bytes.as_ptr().add(1) as *const [u8; HASH_LEN]) };
+        let identifier =
+            from_utf8(&bytes[Self::PREFIX_LEN..]).map_err(|_| ProgramError::InvalidArgument)?;
+
+        if !protocol.is_valid_identifier(identifier) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            protocol,
+            digest,
+            identifier,
+        })
+    }
+}
+
+/// Networks supported by content-addressed data.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContentProtocol {
+    /// InterPlanetary File System, identified by a CID.
+    Ipfs,
+
+    /// Arweave, identified by a transaction id.
+    Arweave,
+}
+
+impl ContentProtocol {
+    /// Indicates whether `identifier` has the shape of a content identifier
+    /// for the protocol.
+    ///
+    /// IPFS accepts CIDv0 (`Qm...` base58btc) and CIDv1 using the default
+    /// base32 multibase (`b...`); Arweave transaction ids are 43 base64url
+    /// characters.
+    pub fn is_valid_identifier(&self, identifier: &str) -> bool {
+        let bytes = identifier.as_bytes();
+
+        match self {
+            ContentProtocol::Ipfs => match bytes {
+                [b'Q', b'm', rest @ ..] if bytes.len() == 46 => rest
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() && !matches!(b, b'0' | b'O' | b'I' | b'l')),
+                [b'b', rest @ ..] if !rest.is_empty() => rest
+                    .iter()
+                    .all(|b| b.is_ascii_lowercase() || (b'2'..=b'7').contains(b)),
+                _ => false,
+            },
+            ContentProtocol::Arweave => {
+                bytes.len() == 43
+                    && bytes
+                        .iter()
+                        .all(|b| b.is_ascii_alphanumeric() || *b == b'-' || *b == b'_')
+            }
+        }
+    }
+}
+
+impl TryFrom<u8> for ContentProtocol {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ContentProtocol::Ipfs),
+            1 => Ok(ContentProtocol::Arweave),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+/// Type to represent chunked data.
+///
+/// Chunked data is stored as an ordered list of [`ChunkData`] values, each
+/// referencing a range of bytes in an account. The content is the
+/// concatenation of all chunks, which allows content larger than the size
+/// limit of a single account.
+pub struct ChunkedData<'a>(pub &'a [ChunkData]);
+
+impl<'a> ChunkedData<'a> {
+    /// Maximum number of chunks.
+    pub const MAX_CHUNKS: usize = 64;
+
+    /// Return a `ChunkedData` from the given bytes.
+    ///
+    /// This method validates that `bytes` holds between one and
+    /// [`Self::MAX_CHUNKS`] chunks and that every chunk references a
+    /// non-empty range of an account.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        if bytes.is_empty()
+            || !bytes.len().is_multiple_of(ChunkData::LEN)
+            || bytes.len() > ChunkData::LEN * Self::MAX_CHUNKS
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // SAFETY: `bytes` was validated to hold a whole number of chunks and
+        // `ChunkData` has an alignment of 1.
+        let chunks = unsafe {
+            core::slice::from_raw_parts(
+                bytes.as_ptr() as *const ChunkData,
+                bytes.len() / ChunkData::LEN,
+            )
+        };
+
+        if chunks
+            .iter()
+            .any(|chunk| chunk.address.is_zero() || chunk.length() == 0)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self(chunks))
+    }
+}
+
+/// Type to represent a chunk of chunked data.
+///
+/// A chunk contains a reference (address) to an account and the offset and
+/// length of the chunk bytes in the account.
+//
+// Note: `ChunkData` may be loaded directly from account data after only a
+// length check (no owner check). All fields must be valid for any bit
+// pattern.
+#[repr(C)]
+pub struct ChunkData {
+    /// Address of the account holding the chunk.
+    pub address: Address,
+
+    /// Offset of the chunk in the account.
+    pub(crate) offset: [u8; 4],
+
+    /// Length of the chunk.
+    pub(crate) length: [u8; 4],
+}
+
+// Enforces 1-byte alignment for the `ChunkData` struct.
+const _: () = {
+    assert!(align_of::<ChunkData>() == 1);
+};
+
+impl ChunkData {
+    pub const LEN: usize = core::mem::size_of::<ChunkData>();
+
+    pub fn offset(&self) -> u32 {
+        u32::from_le_bytes(self.offset)
+    }
+
+    pub fn length(&self) -> u32 {
+        u32::from_le_bytes(self.length)
+    }
+}
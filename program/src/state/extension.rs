@@ -0,0 +1,178 @@
+use core::ops::Range;
+
+use pinocchio::error::ProgramError;
+
+/// Length of the extension area length prefix.
+pub const EXTENSIONS_LENGTH_LEN: usize = 2;
+
+/// Length of the type and length prefix of an extension.
+pub const EXTENSION_PREFIX_LEN: usize = 4;
+
+/// Alignment of the extension area length.
+///
+/// This keeps the data section of the metadata account at a 8-byte boundary.
+pub const EXTENSIONS_ALIGNMENT: usize = 8;
+
+/// Type of the extension holding the bytes of a variable-length seed that do not
+/// fit in the `seed` field of the header.
+///
+/// This extension type is reserved for the program and cannot be set with the
+/// `SetExtension` instruction.
+pub const SEED_EXTENSION_TYPE: u16 = u16::MAX;
+
+/// Type of the extension holding the revision of the metadata (`u64`), which is
+/// incremented every time the data changes.
+///
+/// A metadata account without this extension is at revision `0`. This extension
+/// type is reserved for the program and cannot be set with the `SetExtension`
+/// instruction.
+pub const REVISION_EXTENSION_TYPE: u16 = u16::MAX - 1;
+
+/// A type-length-value extension of a metadata account.
+pub struct Extension<'a> {
+    /// Type of the extension.
+    ///
+    /// The type `0` is reserved to mark the end of the extensions.
+    pub extension_type: u16,
+
+    /// Value of the extension.
+    pub value: &'a [u8],
+}
+
+/// Extension area of a metadata account.
+///
+/// The extension area sits between the header (and the content hash section, if
+/// present) and the data of a metadata account. It starts with its total length
+/// (`u16`), followed by a sequence of extensions. Each extension is encoded as:
+///
+///  - `u16`: extension type
+///  - `u16`: length of the value
+///  - `[u8]`: value
+///
+/// The area is zero-padded to a multiple of [`EXTENSIONS_ALIGNMENT`] bytes.
+pub struct Extensions<'a>(&'a [u8]);
+
+impl<'a> Extensions<'a> {
+    /// Return the `Extensions` from the given bytes.
+    ///
+    /// The `bytes` must start at the extension area. This method validates that
+    /// the area length is valid and that all extensions are within the area.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let length = match bytes {
+            [l0, l1, ..] => u16::from_le_bytes([*l0, *l1]) as usize,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        if length < EXTENSIONS_LENGTH_LEN
+            || !length.is_multiple_of(EXTENSIONS_ALIGNMENT)
+            || bytes.len() < length
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let extensions = Self(&bytes[..length]);
+        // Walks the extensions to validate their lengths.
+        let mut iter = extensions.iter();
+        for _ in iter.by_ref() {}
+
+        if iter.invalid {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(extensions)
+    }
+
+    /// Returns the total length of the extension area, including its length
+    /// prefix and padding.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Indicates whether the extension area has no extensions.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Returns the number of bytes used by the extensions, including the length
+    /// prefix of the area but excluding the padding.
+    pub fn used_len(&self) -> usize {
+        let mut iter = self.iter();
+        for _ in iter.by_ref() {}
+        iter.offset
+    }
+
+    /// Returns the value of the extension of the given type, if present.
+    pub fn get(&self, extension_type: u16) -> Option<&'a [u8]> {
+        self.iter()
+            .find(|extension| extension.extension_type == extension_type)
+            .map(|extension| extension.value)
+    }
+
+    /// Returns the range of the extension of the given type in the area, if present.
+    ///
+    /// The range includes the type and length prefix of the extension.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn position(&self, extension_type: u16) -> Option<Range<usize>> {
+        let mut iter = self.iter();
+        loop {
+            let start = iter.offset;
+            let extension = iter.next()?;
+            if extension.extension_type == extension_type {
+                return Some(start..iter.offset);
+            }
+        }
+    }
+
+    /// Returns an iterator over the extensions.
+    pub fn iter(&self) -> ExtensionsIter<'a> {
+        ExtensionsIter {
+            area: self.0,
+            offset: EXTENSIONS_LENGTH_LEN,
+            invalid: false,
+        }
+    }
+}
+
+/// Iterator over the extensions of an extension area.
+pub struct ExtensionsIter<'a> {
+    /// Bytes of the extension area.
+    area: &'a [u8],
+
+    /// Offset of the next extension in the area.
+    offset: usize,
+
+    /// Indicates whether an extension exceeding the area was found.
+    invalid: bool,
+}
+
+impl<'a> Iterator for ExtensionsIter<'a> {
+    type Item = Extension<'a>;
+
+    #[allow(clippy::arithmetic_side_effects)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let [t0, t1, l0, l1, remaining @ ..] = self.area.get(self.offset..)? else {
+            return None;
+        };
+
+        let extension_type = u16::from_le_bytes([*t0, *t1]);
+        // A zero extension type marks the start of the padding.
+        if extension_type == 0 {
+            return None;
+        }
+
+        let length = u16::from_le_bytes([*l0, *l1]) as usize;
+
+        let Some(value) = remaining.get(..length) else {
+            self.invalid = true;
+            return None;
+        };
+
+        self.offset += EXTENSION_PREFIX_LEN + length;
+
+        Some(Extension {
+            extension_type,
+            value,
+        })
+    }
+}
@@ -7,16 +7,30 @@ use pinocchio::{
 };
 
 use super::{
+    extension::{Extensions, REVISION_EXTENSION_TYPE, SEED_EXTENSION_TYPE},
     Account, AccountDiscriminator, Compression, DataSource, Encoding, Format, ZeroableOption,
-    SEED_LEN,
+    HASH_LEN, MAX_SEED_LEN, SEED_LEN,
 };
 
+/// Flag indicating that the content hash section follows the header.
+pub const HASH_FLAG: u8 = 0b0000_0001;
+
+/// Flag indicating that the extension area precedes the data.
+pub const EXTENSIONS_FLAG: u8 = 0b0000_0010;
+
 /// Metadata account header.
 ///
 /// A metadata account holds a variable amount of data after its header information.
+/// Optional sections can sit between the header and the data, in the following order:
+///  - content hash section of [`HASH_LEN`] bytes, when the [`HASH_FLAG`] is set.
+///  - extension area (see [`Extensions`]), when the [`EXTENSIONS_FLAG`] is set.
 ///
 /// A metadata account address is a PDA derived from a seed and program;
 /// non-canonical metadata accounts also include an authority in the derivation.
+/// The seed is either a fixed [`SEED_LEN`]-byte seed or a variable-length seed of
+/// up to [`MAX_SEED_LEN`] bytes, in which case its length is stored in the header
+/// and the bytes past [`SEED_LEN`] are stored in the [`SEED_EXTENSION_TYPE`]
+/// extension.
 /// Once a metadata account is initialized, it is not possible to change its seed
 /// and program values; however, it is possible to change its authority (in the
 /// case of a canonical metadata account) and mutable flag.
@@ -30,6 +44,9 @@ pub struct Header {
     pub(crate) discriminator: u8,
 
     /// Program ID that this metadata is associated with.
+    ///
+    /// This is usually a program, but it can be any account with an authority
+    /// supported by the program (e.g., a token mint).
     pub program: Address,
 
     /// Authority that can update this metadata.
@@ -43,10 +60,14 @@ pub struct Header {
     /// Indicates whether the metadata is canonical.
     ///
     /// Canonical metadata accounts are accounts created by the
-    /// program upgrade authority.
+    /// program upgrade authority (or the authority of the account
+    /// that the metadata is associated with).
     pub(crate) canonical: u8,
 
     /// Seed used to derive the PDA.
+    ///
+    /// For variable-length seeds, holds the first [`SEED_LEN`] bytes of the seed
+    /// (zero-padded).
     pub seed: [u8; SEED_LEN],
 
     /// Encoding of the data.
@@ -64,10 +85,24 @@ pub struct Header {
     // Length of the data after the header.
     pub(crate) data_length: [u8; 4],
 
-    /// Extra padding for alignment.
+    /// Flags indicating the optional sections present after the header.
+    pub(crate) flags: u8,
+
+    /// Reserved for future use.
     ///
-    /// This allows the data section to start at a 8-byte boundary.
-    _padding: [u8; 5],
+    /// The revision of the metadata is stored in the [`REVISION_EXTENSION_TYPE`]
+    /// extension.
+    pub(crate) _reserved: [u8; 2],
+
+    /// Version of the account layout.
+    ///
+    /// Accounts created before layout versioning have version `0`.
+    pub(crate) version: u8,
+
+    /// Length of a variable-length seed.
+    ///
+    /// Accounts derived from a fixed [`SEED_LEN`]-byte seed have length `0`.
+    pub(crate) seed_length: u8,
 }
 
 // Enforces 1-byte alignment for the struct.
@@ -111,6 +146,133 @@ impl Header {
         u32::from_le_bytes(self.data_length)
     }
 
+    /// Returns the revision of the metadata.
+    ///
+    /// Since the revision is stored in the [`REVISION_EXTENSION_TYPE`] extension,
+    /// `bytes` must be the account data from which the header was loaded. An
+    /// account without the extension is at revision `0`.
+    pub fn revision(&self, bytes: &[u8]) -> Result<u64, ProgramError> {
+        if !self.has_extensions() {
+            return Ok(0);
+        }
+
+        let area = bytes
+            .get(self.extensions_offset()..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        match Extensions::from_bytes(area)?.get(REVISION_EXTENSION_TYPE) {
+            Some(revision) => Ok(u64::from_le_bytes(
+                revision
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            )),
+            None => Ok(0),
+        }
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the length of the seed used to derive the PDA.
+    pub fn seed_len(&self) -> usize {
+        match self.seed_length {
+            0 => SEED_LEN,
+            length => length as usize,
+        }
+    }
+
+    /// Indicates whether the seed is a variable-length seed.
+    pub fn has_variable_seed(&self) -> bool {
+        self.seed_length != 0
+    }
+
+    /// Indicates whether the seed is a variable-length seed with bytes stored in
+    /// the [`SEED_EXTENSION_TYPE`] extension.
+    pub fn has_seed_extension(&self) -> bool {
+        self.seed_len() > SEED_LEN
+    }
+
+    /// Returns the seed used to derive the PDA and its length.
+    ///
+    /// Since the bytes of a variable-length seed past [`SEED_LEN`] are stored in
+    /// the extension area, `bytes` must be the account data from which the header
+    /// was loaded.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn derivation_seed(
+        &self,
+        bytes: &[u8],
+    ) -> Result<([u8; MAX_SEED_LEN], usize), ProgramError> {
+        let length = self.seed_len();
+
+        if length > MAX_SEED_LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut seed = [0u8; MAX_SEED_LEN];
+
+        if self.has_seed_extension() {
+            seed[..SEED_LEN].copy_from_slice(&self.seed);
+
+            if !self.has_extensions() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let area = bytes
+                .get(self.extensions_offset()..)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            let remaining = Extensions::from_bytes(area)?
+                .get(SEED_EXTENSION_TYPE)
+                .filter(|remaining| remaining.len() == length - SEED_LEN)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            seed[SEED_LEN..length].copy_from_slice(remaining);
+        } else {
+            seed[..length].copy_from_slice(&self.seed[..length]);
+        }
+
+        Ok((seed, length))
+    }
+
+    pub fn has_hash(&self) -> bool {
+        self.flags & HASH_FLAG != 0
+    }
+
+    pub fn has_extensions(&self) -> bool {
+        self.flags & EXTENSIONS_FLAG != 0
+    }
+
+    /// Returns the offset of the extension area in the account data.
+    ///
+    /// When the account has no extension area, this is the offset where
+    /// the area would be inserted.
+    pub fn extensions_offset(&self) -> usize {
+        if self.has_hash() {
+            Self::LEN + HASH_LEN
+        } else {
+            Self::LEN
+        }
+    }
+
+    /// Returns the offset of the data section in the account data.
+    ///
+    /// The data starts after the header and any optional section present. Since
+    /// the length of the extension area is stored in the area itself, `bytes`
+    /// must be the account data from which the header was loaded.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn data_offset(&self, bytes: &[u8]) -> Result<usize, ProgramError> {
+        let offset = self.extensions_offset();
+
+        if self.has_extensions() {
+            let area = bytes
+                .get(offset..)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            // The extension area length is at most `u16::MAX`.
+            Ok(offset + Extensions::from_bytes(area)?.len())
+        } else {
+            Ok(offset)
+        }
+    }
+
     /// Returns a `Header` from a metadata account info.
     ///
     /// This method will perform the following validations on the account info:
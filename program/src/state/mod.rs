@@ -1,6 +1,8 @@
 pub mod buffer;
 pub mod data;
+pub mod extension;
 pub mod header;
+pub mod pending_authority;
 
 use core::mem::size_of;
 
@@ -10,7 +12,10 @@ use crate::{
     error::ProgramMetadataError,
     state::{
         buffer::Buffer,
-        data::{Data, ExternalData},
+        data::{
+            AliasData, ChunkData, ChunkedData, ContentData, Data, ExternalData, ExternalDataV2,
+        },
+        extension::Extensions,
         header::Header,
     },
 };
@@ -26,11 +31,36 @@ const _: () = {
 /// The length of the seed used to derive the metadata account address.
 pub const SEED_LEN: usize = 16;
 
+/// The maximum length of a variable-length seed used to derive the metadata
+/// account address.
+///
+/// This is the maximum length of a PDA seed.
+pub const MAX_SEED_LEN: usize = 32;
+
+/// The length of an encoded variable-length seed in the instruction data: the
+/// seed length (`u8`) followed by the zero-padded seed bytes.
+pub const VARIABLE_SEED_LEN: usize = 1 + MAX_SEED_LEN;
+
+/// The length of the content hash of a metadata account.
+pub const HASH_LEN: usize = 32;
+
+/// The current version of the account layout.
+///
+/// Version `0` identifies accounts created before layout versioning, which can
+/// be upgraded with the `Migrate` instruction.
+pub const LAYOUT_VERSION: u8 = 1;
+
 /// Struct to represent the contents of a `Metadata` account.
 pub struct Metadata<'a> {
     /// The header of the metadata account.
     pub header: &'a Header,
 
+    /// The content hash of the metadata account, if any.
+    pub hash: Option<&'a [u8; HASH_LEN]>,
+
+    /// The extensions of the metadata account, if any.
+    pub extensions: Option<Extensions<'a>>,
+
     /// The data associated with the account.
     pub data: Data<'a>,
 }
@@ -42,9 +72,42 @@ impl<'a> Metadata<'a> {
     /// length.
     pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
         let header = Header::from_bytes(bytes)?;
-        // `bytes` has at least `Header::LEN` at this point.
-        let data = Data::from_bytes(header.data_source()?, &bytes[Header::LEN..])?;
-        Ok(Self { header, data })
+        let offset = header
+            .data_offset(bytes)
+            .map_err(|_| ProgramError::InvalidArgument)?;
+
+        if bytes.len() < offset {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let hash = if header.has_hash() {
+            // SAFETY: `bytes` was validated to hold the hash section.
+            Some(unsafe { &*(bytes.as_ptr().add(Header::LEN) as *const [u8; HASH_LEN]) })
+        } else {
+            None
+        };
+        let extensions = if header.has_extensions() {
+            // `bytes` was validated to hold the extension area.
+            Some(Extensions::from_bytes(
+                &bytes[header.extensions_offset()..offset],
+            )?)
+        } else {
+            None
+        };
+        // `bytes` has at least `offset` length at this point. Data is limited
+        // to `data_length` bytes, since legacy accounts might have trailing bytes.
+        let data = &bytes[offset..];
+        let data = Data::from_bytes(
+            header.data_source()?,
+            data.get(..header.data_length() as usize).unwrap_or(data),
+        )?;
+
+        Ok(Self {
+            header,
+            hash,
+            extensions,
+            data,
+        })
     }
 }
 
@@ -65,6 +128,7 @@ pub enum AccountDiscriminator {
     Empty,
     Buffer,
     Metadata,
+    PendingAuthority,
 }
 
 impl AccountDiscriminator {
@@ -85,6 +149,7 @@ impl TryFrom<u8> for AccountDiscriminator {
             0 => Ok(AccountDiscriminator::Empty),
             1 => Ok(AccountDiscriminator::Buffer),
             2 => Ok(AccountDiscriminator::Metadata),
+            3 => Ok(AccountDiscriminator::PendingAuthority),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -98,6 +163,7 @@ pub enum Encoding {
     Utf8,
     Base58,
     Base64,
+    Base16,
 }
 
 impl TryFrom<u8> for Encoding {
@@ -109,6 +175,7 @@ impl TryFrom<u8> for Encoding {
             1 => Ok(Encoding::Utf8),
             2 => Ok(Encoding::Base58),
             3 => Ok(Encoding::Base64),
+            4 => Ok(Encoding::Base16),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -121,6 +188,9 @@ pub enum Compression {
     None,
     Gzip,
     Zlib,
+    Zstd,
+    Brotli,
+    Lz4,
 }
 
 impl TryFrom<u8> for Compression {
@@ -131,6 +201,9 @@ impl TryFrom<u8> for Compression {
             0 => Ok(Compression::None),
             1 => Ok(Compression::Gzip),
             2 => Ok(Compression::Zlib),
+            3 => Ok(Compression::Zstd),
+            4 => Ok(Compression::Brotli),
+            5 => Ok(Compression::Lz4),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -144,6 +217,10 @@ pub enum Format {
     Json,
     Yaml,
     Toml,
+    Borsh,
+    Protobuf,
+    Cbor,
+    MessagePack,
 }
 
 impl TryFrom<u8> for Format {
@@ -155,6 +232,10 @@ impl TryFrom<u8> for Format {
             1 => Ok(Format::Json),
             2 => Ok(Format::Yaml),
             3 => Ok(Format::Toml),
+            4 => Ok(Format::Borsh),
+            5 => Ok(Format::Protobuf),
+            6 => Ok(Format::Cbor),
+            7 => Ok(Format::MessagePack),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -167,6 +248,9 @@ pub enum DataSource {
     Direct,
     Url,
     External,
+    ContentAddressed,
+    Chunked,
+    Alias,
 }
 
 impl DataSource {
@@ -174,10 +258,49 @@ impl DataSource {
     pub fn validate_data_length(&self, length: usize) -> ProgramResult {
         match (self, length) {
             (DataSource::Direct | DataSource::Url, l) if l > 0 => Ok(()),
-            (DataSource::External, ExternalData::LEN) => Ok(()),
+            (DataSource::External, ExternalData::LEN | ExternalDataV2::LEN) => Ok(()),
+            (DataSource::ContentAddressed, l)
+                if l > ContentData::PREFIX_LEN
+                    && l <= ContentData::PREFIX_LEN + ContentData::MAX_IDENTIFIER_LEN =>
+            {
+                Ok(())
+            }
+            (DataSource::Chunked, l)
+                if l > 0
+                    && l.is_multiple_of(ChunkData::LEN)
+                    && l <= ChunkData::LEN * ChunkedData::MAX_CHUNKS =>
+            {
+                Ok(())
+            }
+            (DataSource::Alias, AliasData::LEN) => Ok(()),
             _ => Err(ProgramMetadataError::InvalidDataLength.into()),
         }
     }
+
+    /// Validates the length of `data` and, for sources with a typed
+    /// representation validated beyond its length, its shape.
+    #[inline(always)]
+    pub fn validate_data(&self, data: &[u8]) -> ProgramResult {
+        self.validate_data_length(data.len())?;
+
+        match self {
+            DataSource::External if data.len() == ExternalDataV2::LEN => {
+                ExternalDataV2::validate_bytes(data)?;
+            }
+            DataSource::ContentAddressed => {
+                ContentData::from_bytes(data)?;
+            }
+            DataSource::Chunked => {
+                ChunkedData::from_bytes(data)?;
+            }
+            DataSource::Alias => {
+                AliasData::from_bytes(data)?;
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
 }
 
 impl TryFrom<u8> for DataSource {
@@ -188,6 +311,9 @@ impl TryFrom<u8> for DataSource {
             0 => Ok(DataSource::Direct),
             1 => Ok(DataSource::Url),
             2 => Ok(DataSource::External),
+            3 => Ok(DataSource::ContentAddressed),
+            4 => Ok(DataSource::Chunked),
+            5 => Ok(DataSource::Alias),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
@@ -0,0 +1,151 @@
+use core::mem::align_of;
+
+use pinocchio::{
+    account::{AccountView, Ref},
+    error::ProgramError,
+    Address,
+};
+
+use super::AccountDiscriminator;
+
+/// Seed prefix used to derive the pending authority account address.
+pub const PENDING_AUTHORITY_SEED: &[u8] = b"pending_authority";
+
+/// Pending authority account.
+///
+/// A pending authority account stores the authority nominated to take over a
+/// buffer or metadata account. Its address is a PDA derived from the
+/// [`PENDING_AUTHORITY_SEED`] and the address of the buffer or metadata
+/// account, so there is at most one pending authority per account.
+///
+/// The nomination only takes effect once the pending authority signs the
+/// `accept_authority` instruction; until then, it can be cancelled by either
+/// the current authority or the pending authority. The authority that proposed
+/// the nomination must still be an authority of the account when it is accepted,
+/// so a nomination does not survive the account changing hands.
+//
+// Note: `PendingAuthority` may be loaded directly from account data after only a
+// length check (no owner check). All fields must be valid for any bit
+// pattern.
+#[repr(C)]
+pub struct PendingAuthority {
+    /// Account discriminator.
+    pub(crate) discriminator: u8,
+
+    /// Buffer or metadata account that this pending authority is associated with.
+    pub account: Address,
+
+    /// Authority nominated to take over the account.
+    pub authority: Address,
+
+    /// Authority that proposed the nomination.
+    pub proposer: Address,
+}
+
+// Enforces 1-byte alignment for the struct.
+const _: () = {
+    assert!(align_of::<PendingAuthority>() == 1);
+};
+
+impl PendingAuthority {
+    /// Length of the pending authority account (`97` bytes).
+    pub const LEN: usize = core::mem::size_of::<PendingAuthority>();
+
+    #[inline(always)]
+    pub fn discriminator(&self) -> Result<AccountDiscriminator, ProgramError> {
+        self.discriminator.try_into()
+    }
+
+    /// Returns a `PendingAuthority` from its account info.
+    ///
+    /// This method will perform the following validations on the account info:
+    ///  1. Owner check: it must match the `ProgramMetadata` program.
+    ///  2. Account discriminator: it must match [`AccountDiscriminator::PendingAuthority`].
+    ///  3. Borrow data: it must be allowed to borrow the account data.
+    #[inline]
+    pub fn from_account_info(account_info: &AccountView) -> Result<Ref<'_, Self>, ProgramError> {
+        if !account_info.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let data = account_info.try_borrow()?;
+        if data.len() < Self::LEN || data[0] != AccountDiscriminator::PendingAuthority as u8 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `data` was validated to have the correct owner and discriminator.
+        Ok(Ref::map(data, |data| unsafe {
+            Self::from_bytes_unchecked(data)
+        }))
+    }
+
+    /// Returns a `PendingAuthority` from its account info.
+    ///
+    /// This method will perform the following validations on the account info:
+    ///  1. Owner check: it must match the `ProgramMetadata` program.
+    ///  2. Account discriminator: it must match [`AccountDiscriminator::PendingAuthority`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data, e.g., there are
+    /// no mutable borrows of the account data.
+    #[inline]
+    pub unsafe fn from_account_info_unchecked(
+        account_info: &AccountView,
+    ) -> Result<&Self, ProgramError> {
+        if !account_info.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let data = account_info.borrow_unchecked();
+        if data.len() < Self::LEN || data[0] != AccountDiscriminator::PendingAuthority as u8 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self::from_bytes_unchecked(data))
+    }
+
+    /// Return a `PendingAuthority` from the given bytes.
+    ///
+    /// This method validates that `bytes` has at least the minimum required
+    /// length.
+    #[inline(always)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // SAFETY: `bytes` was validated to have the expected length
+        // to hold a `PendingAuthority` reference.
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
+    /// Return a `PendingAuthority` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains a valid representation of `PendingAuthority`.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const Self)
+    }
+
+    /// Return a mutable `PendingAuthority` from the given bytes.
+    ///
+    /// This method validates that `bytes` has at least the minimum required
+    /// length.
+    #[inline(always)]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() < Self::LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // SAFETY: `bytes` was validated to have the expected length
+        // to hold a `PendingAuthority` reference.
+        Ok(unsafe { Self::from_bytes_mut_unchecked(bytes) })
+    }
+
+    /// Return a mutable `PendingAuthority` from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains a valid representation of `PendingAuthority`.
+    #[inline(always)]
+    pub(crate) unsafe fn from_bytes_mut_unchecked(bytes: &mut [u8]) -> &mut Self {
+        &mut *(bytes.as_mut_ptr() as *mut Self)
+    }
+}
@@ -0,0 +1,527 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{buffer::Buffer, header::Header, pending_authority::PendingAuthority, SEED_LEN},
+};
+
+#[test]
+fn test_accept_authority_buffer() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let new_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+    let pending_authority_lamports = minimum_balance_for(PendingAuthority::LEN);
+    let pending_authority_account =
+        create_funded_account(pending_authority_lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(&buffer_key, &buffer_key, None, None, &new_authority_key),
+                &[Check::success()],
+            ),
+            (
+                &accept_authority(
+                    &buffer_key,
+                    &new_authority_key,
+                    None,
+                    None,
+                    &destination_key,
+                ),
+                &[
+                    Check::success(),
+                    // buffer authority
+                    Check::account(&buffer_key)
+                        .data_slice(33, new_authority_key.as_ref())
+                        .build(),
+                    // pending authority account
+                    Check::account(&pending_authority_key).closed().build(),
+                    // destination lamports
+                    Check::account(&destination_key)
+                        .lamports(pending_authority_lamports)
+                        .build(),
+                ],
+            ),
+            (
+                &set_authority(
+                    &buffer_key,
+                    &new_authority_key,
+                    None,
+                    None,
+                    Some(&buffer_key),
+                ),
+                &[Check::success()],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (new_authority_key, Account::default()),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_accept_authority_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = [4u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + initial_data.len()),
+        system_program::ID,
+    );
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &new_authority_key,
+                ),
+                &[Check::success()],
+            ),
+            (
+                &accept_authority(
+                    &metadata_key,
+                    &new_authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &destination_key,
+                ),
+                &[
+                    Check::success(),
+                    // metadata authority
+                    Check::account(&metadata_key)
+                        .data_slice(33, new_authority_key.as_ref())
+                        .build(),
+                    // pending authority account
+                    Check::account(&pending_authority_key).closed().build(),
+                ],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &new_authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (new_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_accept_authority_with_wrong_signer() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let new_authority_key = Pubkey::new_unique();
+    let wrong_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(&buffer_key, &buffer_key, None, None, &new_authority_key),
+                &[Check::success()],
+            ),
+            (
+                &accept_authority(
+                    &buffer_key,
+                    &wrong_authority_key,
+                    None,
+                    None,
+                    &destination_key,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (wrong_authority_key, Account::default()),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_accept_authority_without_nomination() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let new_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &accept_authority(
+                    &buffer_key,
+                    &new_authority_key,
+                    None,
+                    None,
+                    &destination_key,
+                ),
+                &[Check::err(ProgramError::InvalidAccountOwner)],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (new_authority_key, Account::default()),
+            (pending_authority_key, Account::default()),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_accept_authority_immutable_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &new_authority_key,
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &accept_authority(
+                    &metadata_key,
+                    &new_authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &destination_key,
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::ImmutableMetadataAccount as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (new_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_accept_authority_after_set_authority() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let new_authority_key = Pubkey::new_unique();
+    let other_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(&buffer_key, &buffer_key, None, None, &new_authority_key),
+                &[
+                    Check::success(),
+                    // proposer
+                    Check::account(&pending_authority_key)
+                        .data_slice(65, buffer_key.as_ref())
+                        .build(),
+                ],
+            ),
+            // The buffer changes hands after the nomination.
+            (
+                &set_authority(
+                    &buffer_key,
+                    &buffer_key,
+                    None,
+                    None,
+                    Some(&other_authority_key),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &accept_authority(
+                    &buffer_key,
+                    &new_authority_key,
+                    None,
+                    None,
+                    &destination_key,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (new_authority_key, Account::default()),
+            (other_authority_key, Account::default()),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_accept_authority_metadata_without_program_accounts() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &new_authority_key,
+                ),
+                &[Check::success()],
+            ),
+            // The proposer is the program upgrade authority, which can only be
+            // validated with the program accounts.
+            (
+                &accept_authority(
+                    &metadata_key,
+                    &new_authority_key,
+                    None,
+                    None,
+                    &destination_key,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (new_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
@@ -55,6 +55,89 @@ fn test_allocate_canonical() {
     );
 }
 
+#[test]
+fn test_allocate_canonical_retracted_loader_v4() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    // Retracted programs are not executable.
+    let program_account =
+        setup_loader_v4_program_account(&authority_key, LoaderV4Status::Retracted);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (buffer_key, _) = Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    process_instruction(
+        (
+            &allocate(
+                &buffer_key,
+                &authority_key,
+                Some(&program_key),
+                None,
+                Some(&seed),
+            ),
+            &[
+                Check::success(),
+                // account discriminator
+                Check::account(&buffer_key).data_slice(0, &[1]).build(),
+                // canonical
+                Check::account(&buffer_key).data_slice(65, &[1]).build(),
+            ],
+        ),
+        &[
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_allocate_canonical_mint() {
+    let authority_key = Pubkey::new_unique();
+
+    let mint_key = Pubkey::new_unique();
+    // Mints are not executable, but they have an authority.
+    let mint_account = setup_mint_account(Some(&authority_key), &TOKEN_PROGRAM_ID, false);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..5].copy_from_slice("token".as_bytes());
+
+    let (buffer_key, _) = Pubkey::find_program_address(&[mint_key.as_ref(), &seed], &PROGRAM_ID);
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    process_instruction(
+        (
+            &allocate(
+                &buffer_key,
+                &authority_key,
+                Some(&mint_key),
+                None,
+                Some(&seed),
+            ),
+            &[
+                Check::success(),
+                // account discriminator
+                Check::account(&buffer_key).data_slice(0, &[1]).build(),
+                // canonical
+                Check::account(&buffer_key).data_slice(65, &[1]).build(),
+            ],
+        ),
+        &[
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (mint_key, mint_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
 #[test]
 fn test_allocate_non_canonical() {
     let authority_key = Pubkey::new_unique();
@@ -538,3 +621,148 @@ fn fail_allocate_already_initialized_buffer() {
         ],
     );
 }
+
+#[test]
+fn test_allocate_keypair_with_capacity() {
+    const CAPACITY: usize = 1_000;
+
+    // "keypair" buffer account
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + CAPACITY),
+        system_program::ID,
+    );
+
+    process_instruction(
+        (
+            &with_capacity(
+                allocate(&buffer_key, &buffer_key, None, None, None),
+                CAPACITY as u32,
+            ),
+            &[
+                Check::success(),
+                // data length
+                Check::account(&buffer_key)
+                    .space(Buffer::LEN + CAPACITY)
+                    .build(),
+                // account discriminator
+                Check::account(&buffer_key).data_slice(0, &[1]).build(),
+            ],
+        ),
+        &[
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_allocate_canonical_with_capacity() {
+    const CAPACITY: usize = 1_000;
+
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "canonical" buffer account
+    let (buffer_key, _) = Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + CAPACITY),
+        system_program::ID,
+    );
+
+    process_instruction(
+        (
+            &with_capacity(
+                allocate(
+                    &buffer_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    Some(&seed),
+                ),
+                CAPACITY as u32,
+            ),
+            &[
+                Check::success(),
+                // data length
+                Check::account(&buffer_key)
+                    .space(Buffer::LEN + CAPACITY)
+                    .build(),
+                // account discriminator
+                Check::account(&buffer_key).data_slice(0, &[1]).build(),
+                // seed
+                Check::account(&buffer_key).data_slice(66, &seed).build(),
+            ],
+        ),
+        &[
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_allocate_with_allocated_account_and_capacity() {
+    const CAPACITY: usize = 1_000;
+
+    // Pre-allocated buffer account smaller than the requested capacity, but
+    // funded for it.
+    let buffer_key = Pubkey::new_unique();
+    let mut buffer_account = create_empty_account(Buffer::LEN, PROGRAM_ID);
+    buffer_account.lamports = minimum_balance_for(Buffer::LEN + CAPACITY);
+
+    process_instruction(
+        (
+            &with_capacity(
+                allocate(&buffer_key, &buffer_key, None, None, None),
+                CAPACITY as u32,
+            ),
+            &[
+                Check::success(),
+                // data length
+                Check::account(&buffer_key)
+                    .space(Buffer::LEN + CAPACITY)
+                    .build(),
+                // account discriminator
+                Check::account(&buffer_key).data_slice(0, &[1]).build(),
+            ],
+        ),
+        &[
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_allocate_with_invalid_capacity() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let mut instruction = allocate(&buffer_key, &buffer_key, None, None, None);
+    // Capacity must be a `u32` value.
+    instruction.data.extend_from_slice(&[1, 0]);
+
+    process_instruction(
+        (
+            &instruction,
+            &[Check::err(ProgramError::InvalidInstructionData)],
+        ),
+        &[
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
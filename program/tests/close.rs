@@ -47,6 +47,7 @@ fn test_close_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -231,6 +232,7 @@ fn fail_close_immutable_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
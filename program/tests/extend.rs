@@ -10,6 +10,9 @@ use spl_program_metadata::state::{buffer::Buffer, header::Header, SEED_LEN};
 
 const EXTEND_LENGTH: usize = 200;
 
+/// Maximum number of bytes an account can grow within an instruction.
+const MAX_PERMITTED_DATA_INCREASE: usize = 10_240;
+
 #[test]
 fn test_extend_canonical_buffer() {
     let authority_key = Pubkey::new_unique();
@@ -54,7 +57,7 @@ fn test_extend_canonical_buffer() {
                     &authority_key,
                     Some(&program_key),
                     Some(&program_data_key),
-                    EXTEND_LENGTH as u16,
+                    EXTEND_LENGTH as u32,
                 ),
                 &[
                     Check::success(),
@@ -126,7 +129,7 @@ fn test_extend_non_canonical_buffer() {
                     &authority_key,
                     Some(&program_key),
                     Some(&program_data_key),
-                    EXTEND_LENGTH as u16,
+                    EXTEND_LENGTH as u32,
                 ),
                 &[
                     Check::success(),
@@ -172,7 +175,7 @@ fn test_extend_keypair_buffer() {
                 ],
             ),
             (
-                &extend(&buffer_key, &buffer_key, None, None, EXTEND_LENGTH as u16),
+                &extend(&buffer_key, &buffer_key, None, None, EXTEND_LENGTH as u32),
                 &[
                     Check::success(),
                     // data lenght
@@ -228,6 +231,7 @@ fn test_extend_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -239,7 +243,7 @@ fn test_extend_metadata() {
                     &authority_key,
                     Some(&program_key),
                     Some(&program_data_key),
-                    EXTEND_LENGTH as u16,
+                    EXTEND_LENGTH as u32,
                 ),
                 &[
                     Check::success(),
@@ -283,7 +287,7 @@ fn fail_extend_with_wrong_authority() {
                     &wrong_authority_key,
                     None,
                     None,
-                    EXTEND_LENGTH as u16,
+                    EXTEND_LENGTH as u32,
                 ),
                 &[Check::err(ProgramError::IncorrectAuthority)],
             ),
@@ -302,7 +306,7 @@ fn fail_extend_uninitialized_account() {
 
     process_instruction(
         (
-            &extend(&account_key, &account_key, None, None, EXTEND_LENGTH as u16),
+            &extend(&account_key, &account_key, None, None, EXTEND_LENGTH as u32),
             &[Check::err(ProgramError::InvalidAccountData)],
         ),
         &[(account_key, Account::default())],
@@ -312,7 +316,7 @@ fn fail_extend_uninitialized_account() {
 #[test]
 fn fail_extend_with_invalid_instruction_data() {
     let buffer_key = Pubkey::new_unique();
-    let mut instruction = extend(&buffer_key, &buffer_key, None, None, EXTEND_LENGTH as u16);
+    let mut instruction = extend(&buffer_key, &buffer_key, None, None, EXTEND_LENGTH as u32);
     instruction.data.truncate(1);
 
     process_instruction(
@@ -323,3 +327,159 @@ fn fail_extend_with_invalid_instruction_data() {
         &[(buffer_key, Account::default())],
     );
 }
+
+#[test]
+fn test_extend_funded_by_payer() {
+    let buffer_key = Pubkey::new_unique();
+    // Only funded for the initial size of the buffer.
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let payer_key = Pubkey::new_unique();
+    let payer_lamports = 1_000_000_000;
+    let payer_account = create_funded_account(payer_lamports, system_program::ID);
+
+    let required_lamports =
+        minimum_balance_for(Buffer::LEN + EXTEND_LENGTH) - minimum_balance_for(Buffer::LEN);
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &with_payer(
+                    extend(&buffer_key, &buffer_key, None, None, EXTEND_LENGTH as u32),
+                    &payer_key,
+                ),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&buffer_key)
+                        .space(Buffer::LEN + EXTEND_LENGTH)
+                        .build(),
+                    // lamports
+                    Check::account(&buffer_key)
+                        .lamports(minimum_balance_for(Buffer::LEN + EXTEND_LENGTH))
+                        .build(),
+                    // payer lamports
+                    Check::account(&payer_key)
+                        .lamports(payer_lamports - required_lamports)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (payer_key, payer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_extend_with_u16_length() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + EXTEND_LENGTH),
+        system_program::ID,
+    );
+
+    // Legacy instruction data with a `u16` length.
+    let mut instruction = extend(&buffer_key, &buffer_key, None, None, EXTEND_LENGTH as u32);
+    instruction.data.truncate(3);
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &instruction,
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&buffer_key)
+                        .space(Buffer::LEN + EXTEND_LENGTH)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_extend_by_max_permitted_data_increase() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + MAX_PERMITTED_DATA_INCREASE),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &extend(
+                    &buffer_key,
+                    &buffer_key,
+                    None,
+                    None,
+                    MAX_PERMITTED_DATA_INCREASE as u32,
+                ),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&buffer_key)
+                        .space(Buffer::LEN + MAX_PERMITTED_DATA_INCREASE)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_extend_beyond_max_permitted_data_increase() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + MAX_PERMITTED_DATA_INCREASE + 1),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &extend(
+                    &buffer_key,
+                    &buffer_key,
+                    None,
+                    None,
+                    MAX_PERMITTED_DATA_INCREASE as u32 + 1,
+                ),
+                &[Check::err(ProgramError::InvalidRealloc)],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
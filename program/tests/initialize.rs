@@ -8,7 +8,7 @@ use solana_pubkey::Pubkey;
 use solana_sdk_ids::system_program;
 use spl_program_metadata::{
     error::ProgramMetadataError,
-    state::{buffer::Buffer, header::Header, SEED_LEN},
+    state::{buffer::Buffer, data::ExternalDataV2, header::Header, HASH_LEN, SEED_LEN},
 };
 
 #[test]
@@ -40,6 +40,7 @@ fn test_initialize_canonical() {
             compression: 0,
             format: 0,
             data_source: 0,
+            hash: None,
         },
         Some(&[1u8; 10]),
     );
@@ -98,6 +99,7 @@ fn test_initialize_non_canonical() {
             compression: 0,
             format: 0,
             data_source: 0,
+            hash: None,
         },
         Some(&[1u8; 10]),
     );
@@ -125,6 +127,565 @@ fn test_initialize_non_canonical() {
     );
 }
 
+#[test]
+fn test_initialize_with_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; 16];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + 10),
+        system_program::ID,
+    );
+
+    let instruction = initialize(
+        &authority_key,
+        &program_key,
+        None,
+        InitializeArgs {
+            canonical: false,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: Some(sha256(&[1u8; 10])),
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[
+                Check::success(),
+                // account size
+                Check::account(&metadata_key)
+                    .space(Header::LEN + HASH_LEN + 10)
+                    .build(),
+                // flags (hash section)
+                Check::account(&metadata_key).data_slice(91, &[1]).build(),
+                // content hash
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &sha256(&[1u8; 10]))
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN + HASH_LEN, &[1u8; 10])
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_with_mismatched_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; 16];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + 10),
+        system_program::ID,
+    );
+
+    let instruction = initialize(
+        &authority_key,
+        &program_key,
+        None,
+        InitializeArgs {
+            canonical: false,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: Some([7u8; HASH_LEN]), // <- not the digest of the data
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[Check::err(ProgramError::Custom(
+                ProgramMetadataError::HashMismatch as u32,
+            ))],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_canonical_loader_v4() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_loader_v4_program_account(&authority_key, LoaderV4Status::Deployed);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &program_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[
+                Check::success(),
+                // account discriminator
+                Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                // mutable and canonical
+                Check::account(&metadata_key)
+                    .data_slice(65, &[1, 1])
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &[1u8; 10])
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_canonical_retracted_loader_v4() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account =
+        setup_loader_v4_program_account(&authority_key, LoaderV4Status::Retracted);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &program_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[
+                Check::success(),
+                // account discriminator
+                Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &[1u8; 10])
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_canonical_finalized_loader_v4() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    // The address stored on finalized programs refers to the next version
+    // of the program, not to an authority.
+    let program_account =
+        setup_loader_v4_program_account(&authority_key, LoaderV4Status::Finalized);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &program_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (&instruction, &[Check::err(ProgramError::InvalidSeeds)]),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_canonical_loader_v4_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+    let wrong_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_loader_v4_program_account(&authority_key, LoaderV4Status::Deployed);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &wrong_authority_key,
+        &program_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (&instruction, &[Check::err(ProgramError::InvalidSeeds)]),
+        &[
+            (metadata_key, metadata_account),
+            (wrong_authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_canonical_mint() {
+    let authority_key = Pubkey::new_unique();
+
+    let mint_key = Pubkey::new_unique();
+    let mint_account = setup_mint_account(Some(&authority_key), &TOKEN_PROGRAM_ID, false);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..5].copy_from_slice("token".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(&[mint_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &mint_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[
+                Check::success(),
+                // account discriminator
+                Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                // program
+                Check::account(&metadata_key)
+                    .data_slice(1, mint_key.as_ref())
+                    .build(),
+                // mutable and canonical
+                Check::account(&metadata_key)
+                    .data_slice(65, &[1, 1])
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (mint_key, mint_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_canonical_token_2022_mint_with_extensions() {
+    let authority_key = Pubkey::new_unique();
+
+    let mint_key = Pubkey::new_unique();
+    let mint_account = setup_mint_account(Some(&authority_key), &TOKEN_2022_PROGRAM_ID, true);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..5].copy_from_slice("token".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(&[mint_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &mint_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[
+                Check::success(),
+                // mutable and canonical
+                Check::account(&metadata_key)
+                    .data_slice(65, &[1, 1])
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (mint_key, mint_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_canonical_mint_without_mint_authority() {
+    let authority_key = Pubkey::new_unique();
+
+    let mint_key = Pubkey::new_unique();
+    // Mints without a mint authority have a fixed supply and no authority.
+    let mint_account = setup_mint_account(None, &TOKEN_PROGRAM_ID, false);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..5].copy_from_slice("token".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(&[mint_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &mint_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (&instruction, &[Check::err(ProgramError::InvalidSeeds)]),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (mint_key, mint_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_canonical_token_account() {
+    let authority_key = Pubkey::new_unique();
+
+    let token_account_key = Pubkey::new_unique();
+    let token_account = create_account(vec![0; 165], false, TOKEN_PROGRAM_ID);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..5].copy_from_slice("token".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[token_account_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &token_account_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (token_account_key, token_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_canonical_loader_v3_buffer() {
+    let authority_key = Pubkey::new_unique();
+
+    let loader_buffer_key = Pubkey::new_unique();
+    let loader_buffer_account = setup_loader_v3_buffer_account(Some(&authority_key));
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[loader_buffer_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let instruction = initialize(
+        &authority_key,
+        &loader_buffer_key,
+        None,
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+
+    process_instruction(
+        (
+            &instruction,
+            &[
+                Check::success(),
+                // mutable and canonical
+                Check::account(&metadata_key)
+                    .data_slice(65, &[1, 1])
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (loader_buffer_key, loader_buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
 #[test]
 fn test_initialize_from_buffer() {
     let authority_key = Pubkey::new_unique();
@@ -140,60 +701,371 @@ fn test_initialize_from_buffer() {
 
     let (metadata_key, _) =
         Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
-    let data = [7u8; 12];
-    let metadata_account = create_funded_account(
-        minimum_balance_for(Buffer::LEN + data.len()),
-        system_program::ID,
-    );
+    let data = [7u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    Some(&seed),
+                ),
+                &[
+                    Check::success(),
+                    Check::account(&metadata_key).data_slice(0, &[1]).build(),
+                ],
+            ),
+            (
+                &write(&metadata_key, &authority_key, None, 0, &data),
+                &[
+                    Check::success(),
+                    Check::account(&metadata_key)
+                        .data_slice(Buffer::LEN, &data)
+                        .build(),
+                ],
+            ),
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    None,
+                ),
+                &[
+                    Check::success(),
+                    Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_already_initialized_metadata() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let instruction = initialize(
+        &authority_key,
+        &program_key,
+        Some(&program_data_key),
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&data),
+    );
+
+    process_instructions(
+        &[
+            (&instruction, &[Check::success()]),
+            // second attempt should fail since the metadata account is already initialized
+            (
+                &instruction,
+                &[Check::err(ProgramError::AccountAlreadyInitialized)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_from_buffer_with_instruction_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let buffer_data = [7u8; 12];
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + buffer_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    Some(&seed),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &write(&metadata_key, &authority_key, None, 0, &buffer_data),
+                &[Check::success()],
+            ),
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&[8u8; 4]), // instruction data
+                ),
+                &[Check::err(ProgramError::InvalidInstructionData)],
+            ),
+        ],
+        &[
+            (metadata_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_with_wrong_metadata_pda() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let wrong_metadata_key = Pubkey::new_unique();
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+
+    let mut instruction = initialize(
+        &authority_key,
+        &program_key,
+        Some(&program_data_key),
+        InitializeArgs {
+            canonical: true,
+            seed,
+            encoding: 0,
+            compression: 0,
+            format: 0,
+            data_source: 0,
+            hash: None,
+        },
+        Some(&[1u8; 10]),
+    );
+    instruction.accounts[0].pubkey = wrong_metadata_key;
+
+    process_instruction(
+        (&instruction, &[Check::err(ProgramError::InvalidSeeds)]),
+        &[
+            (wrong_metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_unfunded_metadata() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account = create_funded_account(0, system_program::ID);
+
+    process_instruction(
+        (
+            &initialize(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                InitializeArgs {
+                    canonical: true,
+                    seed,
+                    encoding: 0,
+                    compression: 0,
+                    format: 0,
+                    data_source: 0,
+                    hash: None,
+                },
+                Some(&[1u8; 10]),
+            ),
+            &[Check::err(ProgramError::AccountNotRentExempt)],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_without_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN), system_program::ID);
+
+    process_instruction(
+        (
+            &initialize(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                InitializeArgs {
+                    canonical: true,
+                    seed,
+                    encoding: 0,
+                    compression: 0,
+                    format: 0,
+                    data_source: 0,
+                    hash: None,
+                },
+                None,
+            ),
+            &[Check::err(ProgramError::InvalidInstructionData)],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_with_invalid_encoding() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
 
-    process_instructions(
-        &[
-            (
-                &allocate(
-                    &metadata_key,
-                    &authority_key,
-                    Some(&program_key),
-                    Some(&program_data_key),
-                    Some(&seed),
-                ),
-                &[
-                    Check::success(),
-                    Check::account(&metadata_key).data_slice(0, &[1]).build(),
-                ],
-            ),
-            (
-                &write(&metadata_key, &authority_key, None, 0, &data),
-                &[
-                    Check::success(),
-                    Check::account(&metadata_key)
-                        .data_slice(Buffer::LEN, &data)
-                        .build(),
-                ],
-            ),
-            (
-                &initialize(
-                    &authority_key,
-                    &program_key,
-                    Some(&program_data_key),
-                    InitializeArgs {
-                        canonical: true,
-                        seed,
-                        encoding: 0,
-                        compression: 0,
-                        format: 0,
-                        data_source: 0,
-                    },
-                    None,
-                ),
-                &[
-                    Check::success(),
-                    Check::account(&metadata_key).data_slice(0, &[2]).build(),
-                    Check::account(&metadata_key)
-                        .data_slice(Header::LEN, &data)
-                        .build(),
-                ],
+    process_instruction(
+        (
+            &initialize(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                InitializeArgs {
+                    canonical: true,
+                    seed,
+                    encoding: 99,
+                    compression: 0,
+                    format: 0,
+                    data_source: 0,
+                    hash: None,
+                },
+                Some(&[1u8; 10]),
             ),
-        ],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        ),
         &[
             (metadata_key, metadata_account),
             (authority_key, Account::default()),
@@ -205,7 +1077,7 @@ fn test_initialize_from_buffer() {
 }
 
 #[test]
-fn fail_initialize_already_initialized_metadata() {
+fn fail_initialize_external_data_with_wrong_length() {
     let authority_key = Pubkey::new_unique();
 
     let program_data_key = Pubkey::new_unique();
@@ -219,49 +1091,115 @@ fn fail_initialize_already_initialized_metadata() {
 
     let (metadata_key, _) =
         Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let metadata_account =
+        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
 
-    let data = [1u8; 10];
+    process_instruction(
+        (
+            &initialize(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                InitializeArgs {
+                    canonical: true,
+                    seed,
+                    encoding: 0,
+                    compression: 0,
+                    format: 0,
+                    data_source: 2,
+                    hash: None,
+                },
+                Some(&[1u8; 10]),
+            ),
+            &[Check::err(ProgramError::Custom(
+                ProgramMetadataError::InvalidDataLength as u32,
+            ))],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_external_data_v2() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
     let metadata_account = create_funded_account(
-        minimum_balance_for(Header::LEN + data.len()),
+        minimum_balance_for(Header::LEN + ExternalDataV2::LEN),
         system_program::ID,
     );
 
-    let instruction = initialize(
-        &authority_key,
-        &program_key,
-        Some(&program_data_key),
-        InitializeArgs {
-            canonical: true,
-            seed,
-            encoding: 0,
-            compression: 0,
-            format: 0,
-            data_source: 0,
-        },
-        Some(&data),
-    );
+    let external_key = Pubkey::new_unique();
+    let external_owner = Pubkey::new_unique();
+    let external_data = [9u8; 128];
+    let external_account = create_account(external_data.to_vec(), false, external_owner);
 
-    process_instructions(
-        &[
-            (&instruction, &[Check::success()]),
-            // second attempt should fail since the metadata account is already initialized
-            (
-                &instruction,
-                &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    // address + offset + length (whole account) + owner + digest
+    let mut data = external_key.to_bytes().to_vec();
+    data.extend_from_slice(&[0u8; 8]);
+    data.extend_from_slice(external_owner.as_ref());
+    data.extend_from_slice(&sha256(&external_data));
+
+    process_instruction(
+        (
+            &with_external(
+                initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 2,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &external_key,
+                7,
             ),
-        ],
+            &[
+                Check::success(),
+                // data source
+                Check::account(&metadata_key).data_slice(86, &[2]).build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &data)
+                    .build(),
+            ],
+        ),
         &[
             (metadata_key, metadata_account),
             (authority_key, Account::default()),
             (program_key, program_account),
             (program_data_key, program_data_account),
+            (external_key, external_account),
             keyed_account_for_system_program(),
         ],
     );
 }
 
 #[test]
-fn fail_initialize_from_buffer_with_instruction_data() {
+fn fail_initialize_external_data_v2_with_mismatched_digest() {
     let authority_key = Pubkey::new_unique();
 
     let program_data_key = Pubkey::new_unique();
@@ -275,31 +1213,26 @@ fn fail_initialize_from_buffer_with_instruction_data() {
 
     let (metadata_key, _) =
         Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
-
-    let buffer_data = [7u8; 12];
-    let buffer_account = create_funded_account(
-        minimum_balance_for(Buffer::LEN + buffer_data.len()),
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + ExternalDataV2::LEN),
         system_program::ID,
     );
 
-    process_instructions(
-        &[
-            (
-                &allocate(
-                    &metadata_key,
-                    &authority_key,
-                    Some(&program_key),
-                    Some(&program_data_key),
-                    Some(&seed),
-                ),
-                &[Check::success()],
-            ),
-            (
-                &write(&metadata_key, &authority_key, None, 0, &buffer_data),
-                &[Check::success()],
-            ),
-            (
-                &initialize(
+    let external_key = Pubkey::new_unique();
+    let external_owner = Pubkey::new_unique();
+    let external_data = [9u8; 128];
+    let external_account = create_account(external_data.to_vec(), false, external_owner);
+
+    // address + offset + length (whole account) + owner + digest
+    let mut data = external_key.to_bytes().to_vec();
+    data.extend_from_slice(&[0u8; 8]);
+    data.extend_from_slice(external_owner.as_ref());
+    data.extend_from_slice(&[7u8; 32]); // <- not the digest of the external data
+
+    process_instruction(
+        (
+            &with_external(
+                initialize(
                     &authority_key,
                     &program_key,
                     Some(&program_data_key),
@@ -309,25 +1242,31 @@ fn fail_initialize_from_buffer_with_instruction_data() {
                         encoding: 0,
                         compression: 0,
                         format: 0,
-                        data_source: 0,
+                        data_source: 2,
+                        hash: None,
                     },
-                    Some(&[8u8; 4]), // instruction data
+                    Some(&data),
                 ),
-                &[Check::err(ProgramError::InvalidInstructionData)],
+                &external_key,
+                7,
             ),
-        ],
+            &[Check::err(ProgramError::Custom(
+                ProgramMetadataError::HashMismatch as u32,
+            ))],
+        ),
         &[
-            (metadata_key, buffer_account),
+            (metadata_key, metadata_account),
             (authority_key, Account::default()),
             (program_key, program_account),
             (program_data_key, program_data_account),
+            (external_key, external_account),
             keyed_account_for_system_program(),
         ],
     );
 }
 
 #[test]
-fn fail_initialize_with_wrong_metadata_pda() {
+fn test_initialize_content_addressed_data() {
     let authority_key = Pubkey::new_unique();
 
     let program_data_key = Pubkey::new_unique();
@@ -339,30 +1278,48 @@ fn fail_initialize_with_wrong_metadata_pda() {
     let mut seed = [0u8; SEED_LEN];
     seed[0..3].copy_from_slice("idl".as_bytes());
 
-    let wrong_metadata_key = Pubkey::new_unique();
-    let metadata_account =
-        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
 
-    let mut instruction = initialize(
-        &authority_key,
-        &program_key,
-        Some(&program_data_key),
-        InitializeArgs {
-            canonical: true,
-            seed,
-            encoding: 0,
-            compression: 0,
-            format: 0,
-            data_source: 0,
-        },
-        Some(&[1u8; 10]),
+    // protocol (IPFS) + digest + CIDv0
+    let mut data = vec![0u8];
+    data.extend_from_slice(&[7u8; 32]);
+    data.extend_from_slice(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
     );
-    instruction.accounts[0].pubkey = wrong_metadata_key;
 
     process_instruction(
-        (&instruction, &[Check::err(ProgramError::InvalidSeeds)]),
+        (
+            &initialize(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                InitializeArgs {
+                    canonical: true,
+                    seed,
+                    encoding: 0,
+                    compression: 0,
+                    format: 0,
+                    data_source: 3,
+                    hash: None,
+                },
+                Some(&data),
+            ),
+            &[
+                Check::success(),
+                // data source
+                Check::account(&metadata_key).data_slice(86, &[3]).build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &data)
+                    .build(),
+            ],
+        ),
         &[
-            (wrong_metadata_key, metadata_account),
+            (metadata_key, metadata_account),
             (authority_key, Account::default()),
             (program_key, program_account),
             (program_data_key, program_data_account),
@@ -372,7 +1329,7 @@ fn fail_initialize_with_wrong_metadata_pda() {
 }
 
 #[test]
-fn fail_initialize_unfunded_metadata() {
+fn fail_initialize_content_addressed_data_with_invalid_identifier() {
     let authority_key = Pubkey::new_unique();
 
     let program_data_key = Pubkey::new_unique();
@@ -386,7 +1343,16 @@ fn fail_initialize_unfunded_metadata() {
 
     let (metadata_key, _) =
         Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
-    let metadata_account = create_funded_account(0, system_program::ID);
+
+    // protocol (Arweave) + digest + identifier that is not a transaction id
+    let mut data = vec![1u8];
+    data.extend_from_slice(&[7u8; 32]);
+    data.extend_from_slice(b"https://arweave.net/not-a-transaction-id");
+
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
 
     process_instruction(
         (
@@ -400,11 +1366,12 @@ fn fail_initialize_unfunded_metadata() {
                     encoding: 0,
                     compression: 0,
                     format: 0,
-                    data_source: 0,
+                    data_source: 3,
+                    hash: None,
                 },
-                Some(&[1u8; 10]),
+                Some(&data),
             ),
-            &[Check::err(ProgramError::AccountNotRentExempt)],
+            &[Check::err(ProgramError::InvalidArgument)],
         ),
         &[
             (metadata_key, metadata_account),
@@ -417,7 +1384,7 @@ fn fail_initialize_unfunded_metadata() {
 }
 
 #[test]
-fn fail_initialize_without_data() {
+fn fail_initialize_content_addressed_data_without_identifier() {
     let authority_key = Pubkey::new_unique();
 
     let program_data_key = Pubkey::new_unique();
@@ -432,7 +1399,7 @@ fn fail_initialize_without_data() {
     let (metadata_key, _) =
         Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
     let metadata_account =
-        create_funded_account(minimum_balance_for(Header::LEN), system_program::ID);
+        create_funded_account(minimum_balance_for(Header::LEN + 33), system_program::ID);
 
     process_instruction(
         (
@@ -446,11 +1413,14 @@ fn fail_initialize_without_data() {
                     encoding: 0,
                     compression: 0,
                     format: 0,
-                    data_source: 0,
+                    data_source: 3,
+                    hash: None,
                 },
-                None,
+                Some(&[0u8; 33]),
             ),
-            &[Check::err(ProgramError::InvalidInstructionData)],
+            &[Check::err(ProgramError::Custom(
+                ProgramMetadataError::InvalidDataLength as u32,
+            ))],
         ),
         &[
             (metadata_key, metadata_account),
@@ -463,94 +1433,391 @@ fn fail_initialize_without_data() {
 }
 
 #[test]
-fn fail_initialize_with_invalid_encoding() {
+fn test_initialize_from_source_buffer() {
     let authority_key = Pubkey::new_unique();
 
-    let program_data_key = Pubkey::new_unique();
-    let program_data_account = setup_program_data_account(Some(&authority_key));
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+    // "keypair" buffer account
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &set_authority(&buffer_key, &buffer_key, None, None, Some(&authority_key)),
+                &[Check::success()],
+            ),
+            (
+                &with_source_buffer(
+                    initialize(
+                        &authority_key,
+                        &program_key,
+                        None,
+                        InitializeArgs {
+                            canonical: false,
+                            seed,
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: 0,
+                            hash: None,
+                        },
+                        None,
+                    ),
+                    &buffer_key,
+                    None,
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // account discriminator
+                    Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                    // data length
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                    // buffer account is not closed
+                    Check::account(&buffer_key)
+                        .data_slice(Buffer::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_closes_source_buffer() {
+    let authority_key = Pubkey::new_unique();
 
     let program_key = Pubkey::new_unique();
-    let program_account = setup_program_account(&program_data_key);
+    let program_account = setup_program_account(&Pubkey::new_unique());
 
     let mut seed = [0u8; SEED_LEN];
     seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
 
-    let (metadata_key, _) =
-        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let data = [5u8; 12];
     let metadata_account =
-        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+        create_funded_account(minimum_balance_for(Header::LEN + 4), system_program::ID);
+    // "keypair" buffer account
+    let buffer_key = Pubkey::new_unique();
+    let buffer_lamports = minimum_balance_for(Buffer::LEN + data.len());
+    let buffer_account = create_funded_account(buffer_lamports, system_program::ID);
+    let destination_key = Pubkey::new_unique();
+
+    // Copies 4 bytes starting at offset 2 of the buffer data.
+    let mut range = [0u8; 8];
+    range[..4].copy_from_slice(&2u32.to_le_bytes());
+    range[4..].copy_from_slice(&4u32.to_le_bytes());
 
-    process_instruction(
-        (
-            &initialize(
-                &authority_key,
-                &program_key,
-                Some(&program_data_key),
-                InitializeArgs {
-                    canonical: true,
-                    seed,
-                    encoding: 99,
-                    compression: 0,
-                    format: 0,
-                    data_source: 0,
-                },
-                Some(&[1u8; 10]),
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
             ),
-            &[Check::err(ProgramError::InvalidAccountData)],
-        ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &set_authority(&buffer_key, &buffer_key, None, None, Some(&authority_key)),
+                &[Check::success()],
+            ),
+            (
+                &with_source_buffer(
+                    initialize(
+                        &authority_key,
+                        &program_key,
+                        None,
+                        InitializeArgs {
+                            canonical: false,
+                            seed,
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: 0,
+                            hash: None,
+                        },
+                        Some(&range),
+                    ),
+                    &buffer_key,
+                    Some(&destination_key),
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&metadata_key).space(Header::LEN + 4).build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data[2..6])
+                        .build(),
+                    // buffer account
+                    Check::account(&buffer_key).closed().build(),
+                    // destination lamports
+                    Check::account(&destination_key)
+                        .lamports(buffer_lamports)
+                        .build(),
+                ],
+            ),
+        ],
         &[
             (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
             (authority_key, Account::default()),
             (program_key, program_account),
-            (program_data_key, program_data_account),
+            (destination_key, Account::default()),
             keyed_account_for_system_program(),
         ],
     );
 }
 
 #[test]
-fn fail_initialize_external_data_with_wrong_length() {
+fn fail_initialize_from_source_buffer_with_wrong_authority() {
     let authority_key = Pubkey::new_unique();
 
-    let program_data_key = Pubkey::new_unique();
-    let program_data_account = setup_program_data_account(Some(&authority_key));
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+    // "keypair" buffer account
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                // The buffer is its own authority, but it is not a signer.
+                &with_source_buffer(
+                    initialize(
+                        &authority_key,
+                        &program_key,
+                        None,
+                        InitializeArgs {
+                            canonical: false,
+                            seed,
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: 0,
+                            hash: None,
+                        },
+                        None,
+                    ),
+                    &buffer_key,
+                    None,
+                    false,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_closing_source_buffer_signed_by_previous_authority() {
+    let authority_key = Pubkey::new_unique();
 
     let program_key = Pubkey::new_unique();
-    let program_account = setup_program_account(&program_data_key);
+    let program_account = setup_program_account(&Pubkey::new_unique());
 
     let mut seed = [0u8; SEED_LEN];
     seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
 
-    let (metadata_key, _) =
-        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
-    let metadata_account =
-        create_funded_account(minimum_balance_for(Header::LEN + 10), system_program::ID);
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+    // "keypair" buffer account
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+    let new_buffer_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &set_authority(
+                    &buffer_key,
+                    &buffer_key,
+                    None,
+                    None,
+                    Some(&new_buffer_authority_key),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The buffer keypair signs, but it is no longer the buffer authority.
+                &with_source_buffer(
+                    initialize(
+                        &authority_key,
+                        &program_key,
+                        None,
+                        InitializeArgs {
+                            canonical: false,
+                            seed,
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: 0,
+                            hash: None,
+                        },
+                        None,
+                    ),
+                    &buffer_key,
+                    Some(&destination_key),
+                    true,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+#[test]
+fn fail_initialize_with_destination_without_source_buffer() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+    let destination_key = Pubkey::new_unique();
 
     process_instruction(
         (
-            &initialize(
-                &authority_key,
-                &program_key,
-                Some(&program_data_key),
-                InitializeArgs {
-                    canonical: true,
-                    seed,
-                    encoding: 0,
-                    compression: 0,
-                    format: 0,
-                    data_source: 2,
-                },
-                Some(&[1u8; 10]),
+            // The source buffer is omitted, so there is nothing to close.
+            &with_source_buffer(
+                initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &PROGRAM_ID,
+                Some(&destination_key),
+                false,
             ),
-            &[Check::err(ProgramError::Custom(
-                ProgramMetadataError::InvalidDataLength as u32,
-            ))],
+            &[Check::err(ProgramError::InvalidArgument)],
         ),
         &[
             (metadata_key, metadata_account),
             (authority_key, Account::default()),
             (program_key, program_account),
-            (program_data_key, program_data_account),
+            (destination_key, Account::default()),
             keyed_account_for_system_program(),
         ],
     );
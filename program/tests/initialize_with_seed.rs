@@ -0,0 +1,534 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::state::{
+    buffer::Buffer, extension::SEED_EXTENSION_TYPE, header::Header, SEED_LEN,
+};
+
+/// Offset of the `seed` field in the metadata header.
+const SEED_OFFSET: usize = 67;
+
+/// Offset of the `flags` field in the metadata header.
+const FLAGS_OFFSET: usize = 91;
+
+/// Offset of the `seed_length` field in the metadata header.
+const SEED_LENGTH_OFFSET: usize = 95;
+
+/// Offset of the `seed_length` field in the buffer header.
+const BUFFER_SEED_LENGTH_OFFSET: usize = 83;
+
+/// A seed longer than `SEED_LEN` bytes.
+const LONG_SEED: &[u8] = b"com.acme.audit-report-2025";
+
+/// Returns the extension area holding the bytes of `seed` past `SEED_LEN`.
+#[allow(clippy::arithmetic_side_effects)]
+fn seed_extension_area(seed: &[u8]) -> Vec<u8> {
+    let remaining = &seed[SEED_LEN..];
+    let area_len = (6 + remaining.len()).next_multiple_of(8);
+
+    let mut area = Vec::with_capacity(area_len);
+    area.extend_from_slice(&(area_len as u16).to_le_bytes());
+    area.extend_from_slice(&SEED_EXTENSION_TYPE.to_le_bytes());
+    area.extend_from_slice(&(remaining.len() as u16).to_le_bytes());
+    area.extend_from_slice(remaining);
+    area.resize(area_len, 0);
+    area
+}
+
+#[test]
+fn test_initialize_canonical_with_long_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), LONG_SEED], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let area = seed_extension_area(LONG_SEED);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + area.len() + data.len()),
+        system_program::ID,
+    );
+
+    process_instruction(
+        (
+            &initialize_with_seed(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                true,
+                LONG_SEED,
+                Some(&data),
+            ),
+            &[
+                Check::success(),
+                // account discriminator
+                Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                // seed (first 16 bytes)
+                Check::account(&metadata_key)
+                    .data_slice(SEED_OFFSET, &LONG_SEED[..SEED_LEN])
+                    .build(),
+                // flags (extension area)
+                Check::account(&metadata_key)
+                    .data_slice(FLAGS_OFFSET, &[0b0000_0010])
+                    .build(),
+                // seed length
+                Check::account(&metadata_key)
+                    .data_slice(SEED_LENGTH_OFFSET, &[LONG_SEED.len() as u8])
+                    .build(),
+                // seed extension
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &area)
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN + area.len(), &data)
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_non_canonical_with_short_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    // A variable-length seed shorter than `SEED_LEN` bytes is not zero-padded in the
+    // derivation.
+    let seed = b"idl";
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let mut header_seed = [0u8; SEED_LEN];
+    header_seed[..seed.len()].copy_from_slice(seed);
+
+    process_instruction(
+        (
+            &initialize_with_seed(
+                &authority_key,
+                &program_key,
+                Some(&program_data_key),
+                false,
+                seed,
+                Some(&data),
+            ),
+            &[
+                Check::success(),
+                // seed
+                Check::account(&metadata_key)
+                    .data_slice(SEED_OFFSET, &header_seed)
+                    .build(),
+                // flags (no optional sections)
+                Check::account(&metadata_key)
+                    .data_slice(FLAGS_OFFSET, &[0])
+                    .build(),
+                // seed length
+                Check::account(&metadata_key)
+                    .data_slice(SEED_LENGTH_OFFSET, &[seed.len() as u8])
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &data)
+                    .build(),
+            ],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_initialize_from_buffer_with_long_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), LONG_SEED], &PROGRAM_ID);
+
+    let data = [7u8; 12];
+    let area = seed_extension_area(LONG_SEED);
+    // The account is funded for the extension area inserted on initialization.
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + area.len() + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &with_variable_seed(
+                    allocate(
+                        &metadata_key,
+                        &authority_key,
+                        Some(&program_key),
+                        Some(&program_data_key),
+                        None,
+                    ),
+                    LONG_SEED,
+                ),
+                &[
+                    Check::success(),
+                    Check::account(&metadata_key).data_slice(0, &[1]).build(),
+                    // seed length
+                    Check::account(&metadata_key)
+                        .data_slice(BUFFER_SEED_LENGTH_OFFSET, &[LONG_SEED.len() as u8])
+                        .build(),
+                ],
+            ),
+            (
+                &write(&metadata_key, &authority_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &initialize_with_seed(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    true,
+                    LONG_SEED,
+                    None,
+                ),
+                &[
+                    Check::success(),
+                    Check::account(&metadata_key).data_slice(0, &[2]).build(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + area.len() + data.len())
+                        .build(),
+                    // seed extension
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &area)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + area.len(), &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_promote_with_long_seed() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[
+            program_key.as_ref(),
+            source_authority_key.as_ref(),
+            LONG_SEED,
+        ],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), LONG_SEED], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let area = seed_extension_area(LONG_SEED);
+    let source_account = create_funded_account(
+        minimum_balance_for(Header::LEN + area.len() + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize_with_seed(
+                    &source_authority_key,
+                    &program_key,
+                    None,
+                    false,
+                    LONG_SEED,
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &promote(
+                    &metadata_key,
+                    &upgrade_authority_key,
+                    &source_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    Some(&source_authority_key),
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    Check::account(&source_key).closed().build(),
+                    // seed extension
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &area)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + area.len(), &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (metadata_key, Account::default()),
+            (upgrade_authority_key, Account::default()),
+            (source_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_initialize_with_invalid_seed_length() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), LONG_SEED],
+        &PROGRAM_ID,
+    );
+
+    let mut zero_length = initialize_with_seed(
+        &authority_key,
+        &program_key,
+        None,
+        false,
+        LONG_SEED,
+        Some(&[1u8; 10]),
+    );
+    zero_length.data[1] = 0;
+
+    let mut too_long = zero_length.clone();
+    too_long.data[1] = 33;
+
+    for instruction in [zero_length, too_long] {
+        process_instruction(
+            (
+                &instruction,
+                &[Check::err(ProgramError::InvalidInstructionData)],
+            ),
+            &[
+                (
+                    metadata_key,
+                    create_funded_account(
+                        minimum_balance_for(Header::LEN + 10),
+                        system_program::ID,
+                    ),
+                ),
+                (authority_key, Account::default()),
+                (program_key, program_account.clone()),
+                (program_data_key, program_data_account.clone()),
+                keyed_account_for_system_program(),
+            ],
+        );
+    }
+}
+
+#[test]
+fn fail_set_seed_extension() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), LONG_SEED],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let area = seed_extension_area(LONG_SEED);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + area.len() + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize_with_seed(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    false,
+                    LONG_SEED,
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The seed extension is managed by the program.
+                &set_extension(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    SEED_EXTENSION_TYPE,
+                    &[1; 4],
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_move_keeps_variable_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), LONG_SEED],
+        &PROGRAM_ID,
+    );
+
+    let mut new_seed = [0u8; SEED_LEN];
+    new_seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &new_seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let area = seed_extension_area(LONG_SEED);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + area.len() + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize_with_seed(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    false,
+                    LONG_SEED,
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &authority_key,
+                    None,
+                    &new_seed,
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // account size (the seed extension is removed)
+                    Check::account(&new_metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // flags
+                    Check::account(&new_metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[0])
+                        .build(),
+                    // seed length (variable-length seed)
+                    Check::account(&new_metadata_key)
+                        .data_slice(SEED_LENGTH_OFFSET, &[SEED_LEN as u8])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
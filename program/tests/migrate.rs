@@ -0,0 +1,438 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{data::ExternalData, header::Header, LAYOUT_VERSION, SEED_LEN},
+};
+
+/// Offset of the `version` field in the metadata header.
+const VERSION_OFFSET: usize = 94;
+
+#[test]
+fn test_migrate_direct_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    // Legacy canonical account with trailing bytes after the data.
+    let metadata_account = setup_legacy_metadata_account(&program_key, None, &seed, 0, &data, 11);
+
+    process_instructions(
+        &[(
+            &migrate(
+                &metadata_key,
+                &authority_key,
+                Some(&program_key),
+                Some(&program_data_key),
+            ),
+            &[
+                Check::success(),
+                // account size
+                Check::account(&metadata_key)
+                    .space(Header::LEN + data.len())
+                    .build(),
+                // version
+                Check::account(&metadata_key)
+                    .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &data)
+                    .build(),
+            ],
+        )],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+        ],
+    );
+}
+
+#[test]
+fn test_migrate_url_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let url = "https://example.com/idl.json".as_bytes();
+    let metadata_account =
+        setup_legacy_metadata_account(&program_key, Some(&authority_key), &seed, 1, url, 0);
+
+    process_instructions(
+        &[
+            (
+                &migrate(&metadata_key, &authority_key, None, None),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + url.len())
+                        .build(),
+                    // version
+                    Check::account(&metadata_key)
+                        .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, url)
+                        .build(),
+                ],
+            ),
+            (
+                // Migrated accounts can be updated as usual.
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(1),
+                        hash: None,
+                    },
+                    Some(url),
+                ),
+                &[Check::success()],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_migrate_external_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let mut external_data = [0u8; ExternalData::LEN];
+    external_data[0..32].copy_from_slice(Pubkey::new_unique().as_ref());
+    external_data[32..36].copy_from_slice(&8u32.to_le_bytes());
+
+    let metadata_account = setup_legacy_metadata_account(
+        &program_key,
+        Some(&authority_key),
+        &seed,
+        2,
+        &external_data,
+        0,
+    );
+
+    process_instructions(
+        &[(
+            &migrate(&metadata_key, &authority_key, None, None),
+            &[
+                Check::success(),
+                // account size
+                Check::account(&metadata_key)
+                    .space(Header::LEN + ExternalData::LEN)
+                    .build(),
+                // version
+                Check::account(&metadata_key)
+                    .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &external_data)
+                    .build(),
+            ],
+        )],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+        ],
+    );
+}
+
+#[test]
+fn test_migrate_current_version() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[
+                    Check::success(),
+                    // version
+                    Check::account(&metadata_key)
+                        .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                        .build(),
+                ],
+            ),
+            (
+                &migrate(&metadata_key, &authority_key, None, None),
+                &[
+                    Check::success(),
+                    // account size (unchanged)
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // version
+                    Check::account(&metadata_key)
+                        .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_migrate_refunds_excess_lamports() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    // Legacy account with trailing bytes after the data.
+    let metadata_account =
+        setup_legacy_metadata_account(&program_key, Some(&authority_key), &seed, 0, &data, 200);
+    let metadata_lamports = metadata_account.lamports;
+    let destination_key = Pubkey::new_unique();
+
+    let migrated_lamports = minimum_balance_for(Header::LEN + data.len());
+
+    process_instructions(
+        &[(
+            &with_destination(
+                migrate(&metadata_key, &authority_key, None, None),
+                &destination_key,
+            ),
+            &[
+                Check::success(),
+                // account size
+                Check::account(&metadata_key)
+                    .space(Header::LEN + data.len())
+                    .build(),
+                // version
+                Check::account(&metadata_key)
+                    .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                    .build(),
+                // metadata lamports
+                Check::account(&metadata_key)
+                    .lamports(migrated_lamports)
+                    .build(),
+                // destination lamports
+                Check::account(&destination_key)
+                    .lamports(metadata_lamports - migrated_lamports)
+                    .build(),
+            ],
+        )],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+        ],
+    );
+}
+
+#[test]
+fn fail_migrate_with_metadata_as_destination() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let metadata_account =
+        setup_legacy_metadata_account(&program_key, Some(&authority_key), &seed, 0, &[1u8; 5], 10);
+
+    process_instructions(
+        &[(
+            &with_destination(
+                migrate(&metadata_key, &authority_key, None, None),
+                &metadata_key,
+            ),
+            &[Check::err(ProgramError::InvalidArgument)],
+        )],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+        ],
+    );
+}
+
+#[test]
+fn fail_migrate_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+    let wrong_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let metadata_account =
+        setup_legacy_metadata_account(&program_key, Some(&authority_key), &seed, 0, &[1u8; 5], 0);
+
+    process_instructions(
+        &[(
+            &migrate(&metadata_key, &wrong_authority_key, None, None),
+            &[Check::err(ProgramError::IncorrectAuthority)],
+        )],
+        &[
+            (metadata_key, metadata_account),
+            (wrong_authority_key, Account::default()),
+            (program_key, program_account),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_unsupported_version() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let mut metadata_account =
+        setup_legacy_metadata_account(&program_key, Some(&authority_key), &seed, 0, &data, 0);
+    // Simulates an account created by a newer version of the program.
+    metadata_account.data[VERSION_OFFSET] = LAYOUT_VERSION + 1;
+
+    process_instructions(
+        &[(
+            &set_data(
+                &metadata_key,
+                &authority_key,
+                None,
+                None,
+                None,
+                SetDataArgs {
+                    encoding: 0,
+                    compression: 0,
+                    format: 0,
+                    data_source: Some(0),
+                    hash: None,
+                },
+                Some(&data),
+            ),
+            &[Check::err(ProgramError::Custom(
+                ProgramMetadataError::UnsupportedAccountVersion as u32,
+            ))],
+        )],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
@@ -0,0 +1,931 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::state::{header::Header, pending_authority::PendingAuthority, SEED_LEN};
+
+/// Offset of the `authority` field in the metadata header.
+const AUTHORITY_OFFSET: usize = 33;
+
+/// Offset of the `mutable` field in the metadata header, followed by the
+/// `canonical` field and the `seed` field.
+const MUTABLE_OFFSET: usize = 65;
+
+/// Offset of the `flags` field in the metadata header.
+const FLAGS_OFFSET: usize = 91;
+
+/// Offset of the `seed_length` field in the metadata header.
+const SEED_LENGTH_OFFSET: usize = 95;
+
+const LONG_SEED: &[u8] = b"com.acme.audit-report-2025";
+
+#[test]
+fn test_move_canonical() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let mut new_seed = [0u8; SEED_LEN];
+    new_seed[0..7].copy_from_slice("idl-old".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let (new_metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &new_seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    &authority_key,
+                    None,
+                    &new_seed,
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // old metadata account
+                    Check::account(&metadata_key).closed().build(),
+                    // account size
+                    Check::account(&new_metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // lamports
+                    Check::account(&new_metadata_key).lamports(lamports).build(),
+                    // mutable, canonical and seed
+                    Check::account(&new_metadata_key)
+                        .data_slice(MUTABLE_OFFSET, &[[1, 1].as_slice(), &new_seed].concat())
+                        .build(),
+                    // seed length (fixed seed)
+                    Check::account(&new_metadata_key)
+                        .data_slice(SEED_LENGTH_OFFSET, &[0])
+                        .build(),
+                    // metadata data
+                    Check::account(&new_metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_move_non_canonical_to_new_authority() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), new_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &authority_key,
+                    Some(&new_authority_key),
+                    &seed,
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // old metadata account
+                    Check::account(&metadata_key).closed().build(),
+                    // lamports
+                    Check::account(&new_metadata_key).lamports(lamports).build(),
+                    // authority
+                    Check::account(&new_metadata_key)
+                        .data_slice(AUTHORITY_OFFSET, new_authority_key.as_ref())
+                        .build(),
+                    // mutable and canonical
+                    Check::account(&new_metadata_key)
+                        .data_slice(MUTABLE_OFFSET, &[1, 0])
+                        .build(),
+                    // metadata data
+                    Check::account(&new_metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (new_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_move_canonical_with_metadata_authority() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let mut new_seed = [0u8; SEED_LEN];
+    new_seed[0..7].copy_from_slice("idl-old".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+    let (new_metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &new_seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &upgrade_authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_authority(
+                    &metadata_key,
+                    &upgrade_authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    Some(&authority_key),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The metadata authority is not the program upgrade authority.
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    &authority_key,
+                    None,
+                    &new_seed,
+                    false,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (upgrade_authority_key, Account::default()),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_move_non_canonical_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), new_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The new authority is not the authority of the metadata account.
+                &move_metadata(
+                    &metadata_key,
+                    &new_authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &new_authority_key,
+                    None,
+                    &seed,
+                    false,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (new_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_move_with_wrong_new_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(None);
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // The new metadata account is derived from the current authority.
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[
+            program_key.as_ref(),
+            authority_key.as_ref(),
+            &[1u8; SEED_LEN],
+        ],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &authority_key,
+                    Some(&new_authority_key),
+                    &[1u8; SEED_LEN],
+                    false,
+                ),
+                &[Check::err(ProgramError::InvalidSeeds)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (new_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_move_to_variable_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), LONG_SEED],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    // Header, extension area with a 2-byte extension and data.
+    let lamports = minimum_balance_for(Header::LEN + 8 + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    // The seed extension grows the extension area, so the new account is
+    // pre-funded with the difference.
+    let new_lamports = minimum_balance_for(Header::LEN + 24 + data.len());
+    let new_metadata_account = create_funded_account(new_lamports - lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 1, &[3; 2]),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &authority_key,
+                    None,
+                    LONG_SEED,
+                    true,
+                ),
+                &[
+                    Check::success(),
+                    // old metadata account
+                    Check::account(&metadata_key).closed().build(),
+                    // account size
+                    Check::account(&new_metadata_key)
+                        .space(Header::LEN + 24 + data.len())
+                        .build(),
+                    // lamports
+                    Check::account(&new_metadata_key)
+                        .lamports(new_lamports)
+                        .build(),
+                    // seed
+                    Check::account(&new_metadata_key)
+                        .data_slice(MUTABLE_OFFSET + 2, &LONG_SEED[..SEED_LEN])
+                        .build(),
+                    // flags
+                    Check::account(&new_metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[2])
+                        .build(),
+                    // seed length
+                    Check::account(&new_metadata_key)
+                        .data_slice(SEED_LENGTH_OFFSET, &[LONG_SEED.len() as u8])
+                        .build(),
+                    // extension area: the existing extension followed by the seed extension
+                    Check::account(&new_metadata_key)
+                        .data_slice(
+                            Header::LEN,
+                            &[
+                                [24, 0, 1, 0, 2, 0, 3, 3, 0xff, 0xff, 10, 0].as_slice(),
+                                &LONG_SEED[SEED_LEN..],
+                                &[0; 2],
+                            ]
+                            .concat(),
+                        )
+                        .build(),
+                    // metadata data
+                    Check::account(&new_metadata_key)
+                        .data_slice(Header::LEN + 24, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, new_metadata_account),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_move_from_variable_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), LONG_SEED],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), b"idl"],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    // Header, extension area with the seed extension and data.
+    let lamports = minimum_balance_for(Header::LEN + 16 + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize_with_seed(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    false,
+                    LONG_SEED,
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &authority_key,
+                    None,
+                    b"idl",
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // old metadata account
+                    Check::account(&metadata_key).closed().build(),
+                    // account size (the extension area is removed)
+                    Check::account(&new_metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // lamports
+                    Check::account(&new_metadata_key).lamports(lamports).build(),
+                    // seed
+                    Check::account(&new_metadata_key)
+                        .data_slice(MUTABLE_OFFSET + 2, &[b"idl".as_slice(), &[0; 13]].concat())
+                        .build(),
+                    // flags
+                    Check::account(&new_metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[0])
+                        .build(),
+                    // seed length
+                    Check::account(&new_metadata_key)
+                        .data_slice(SEED_LENGTH_OFFSET, &[3])
+                        .build(),
+                    // metadata data
+                    Check::account(&new_metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_move_closes_pending_authority() {
+    let authority_key = Pubkey::new_unique();
+    let nominated_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let mut new_seed = [0u8; SEED_LEN];
+    new_seed[0..7].copy_from_slice("idl-old".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &new_seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(lamports, system_program::ID);
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_lamports = minimum_balance_for(PendingAuthority::LEN);
+    let pending_authority_account = create_funded_account(pending_lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    &nominated_authority_key,
+                ),
+                &[Check::success()],
+            ),
+            (
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &destination_key,
+                    None,
+                    &new_seed,
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // old metadata account
+                    Check::account(&metadata_key).closed().build(),
+                    // pending authority account
+                    Check::account(&pending_authority_key).closed().build(),
+                    // lamports
+                    Check::account(&new_metadata_key).lamports(lamports).build(),
+                    // lamports of the pending authority account
+                    Check::account(&destination_key)
+                        .lamports(pending_lamports)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (pending_authority_key, pending_authority_account),
+            (destination_key, Account::default()),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_move_with_wrong_pending_authority() {
+    let authority_key = Pubkey::new_unique();
+    let wrong_pending_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let mut new_seed = [0u8; SEED_LEN];
+    new_seed[0..7].copy_from_slice("idl-old".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &new_seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let mut instruction = move_metadata(
+        &metadata_key,
+        &authority_key,
+        &new_metadata_key,
+        &program_key,
+        None,
+        &authority_key,
+        None,
+        &new_seed,
+        false,
+    );
+    // The pending authority account must be derived from the metadata account.
+    instruction.accounts[6].pubkey = wrong_pending_authority_key;
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (&instruction, &[Check::err(ProgramError::InvalidSeeds)]),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (wrong_pending_authority_key, Account::default()),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_move_fixed_seed_to_long_seed() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let (new_metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), LONG_SEED],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The fixed seed mode is kept unless a variable-length seed is
+                // requested, so the new seed cannot be longer than 16 bytes.
+                &move_metadata(
+                    &metadata_key,
+                    &authority_key,
+                    &new_metadata_key,
+                    &program_key,
+                    None,
+                    &authority_key,
+                    None,
+                    LONG_SEED,
+                    false,
+                ),
+                &[Check::err(ProgramError::InvalidInstructionData)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (new_metadata_key, Account::default()),
+            (
+                find_pending_authority_address(&metadata_key),
+                Account::default(),
+            ),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
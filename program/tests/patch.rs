@@ -0,0 +1,689 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{
+        header::{Header, EXTENSIONS_FLAG, HASH_FLAG},
+        HASH_LEN, SEED_LEN,
+    },
+};
+
+/// Offset of the `data_length` field in the metadata header.
+const DATA_LENGTH_OFFSET: usize = 87;
+
+/// Offset of the `flags` field in the metadata header.
+const FLAGS_OFFSET: usize = 91;
+
+#[test]
+fn test_patch_in_place() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &patch(&metadata_key, &authority_key, None, None, 3, 10, &[2u8; 4]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + data.len())
+                        .build(),
+                    // data length
+                    Check::account(&metadata_key)
+                        .data_slice(DATA_LENGTH_OFFSET, &10u32.to_le_bytes())
+                        .build(),
+                    // revision
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &revision_area(1))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(
+                            Header::LEN + REVISION_AREA_LEN,
+                            &[1, 1, 1, 2, 2, 2, 2, 1, 1, 1],
+                        )
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_patch_unchanged_data_keeps_revision() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 10];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &patch(&metadata_key, &authority_key, None, None, 3, 10, &[1u8; 4]),
+                &[
+                    Check::success(),
+                    // account size (no revision extension)
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[0])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_patch_extends_and_truncates_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 4];
+    // Funded for the extended data.
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + 8),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // Appends bytes after a zero-extended gap.
+                &patch(&metadata_key, &authority_key, None, None, 6, 8, &[2u8; 2]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + 8)
+                        .build(),
+                    // data length
+                    Check::account(&metadata_key)
+                        .data_slice(DATA_LENGTH_OFFSET, &8u32.to_le_bytes())
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &[1, 1, 1, 1, 0, 0, 2, 2])
+                        .build(),
+                ],
+            ),
+            (
+                // Truncates the data without writing any bytes.
+                &patch(&metadata_key, &authority_key, None, None, 0, 3, &[]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + 3)
+                        .build(),
+                    // data length
+                    Check::account(&metadata_key)
+                        .data_slice(DATA_LENGTH_OFFSET, &3u32.to_le_bytes())
+                        .build(),
+                    // revision
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &revision_area(2))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &[1, 1, 1])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_patch_canonical_with_upgrade_authority() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 6];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &patch(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    0,
+                    6,
+                    &[3u8; 2],
+                ),
+                &[
+                    Check::success(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &[3, 3, 1, 1, 1, 1])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_patch_recomputes_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let hash = sha256(&data);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + REVISION_AREA_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(&metadata_key, &authority_key, None, None, Some(&hash)),
+                &[Check::success()],
+            ),
+            (
+                &patch(&metadata_key, &authority_key, None, None, 4, 5, &[2]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + REVISION_AREA_LEN + data.len())
+                        .build(),
+                    // flags (hash section and extension area)
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[HASH_FLAG | EXTENSIONS_FLAG])
+                        .build(),
+                    // recomputed hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &sha256(&[1, 1, 1, 1, 2]))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN + REVISION_AREA_LEN, &[1, 1, 1, 1, 2])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_patch_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+    let wrong_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 6];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &patch(&metadata_key, &wrong_authority_key, None, None, 0, 6, &[2]),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (wrong_authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_patch_immutable_metadata() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 6];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(&metadata_key, &authority_key, None, None),
+                &[Check::success()],
+            ),
+            (
+                &patch(&metadata_key, &authority_key, None, None, 0, 6, &[2]),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::ImmutableMetadataAccount as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_patch_out_of_data_length() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 6];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &patch(&metadata_key, &authority_key, None, None, 5, 6, &[2u8; 2]),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_patch_at_maximum_revision() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let metadata_key = Pubkey::new_unique();
+    // The revision does not wrap around.
+    let metadata_account = setup_metadata_account_at_revision(
+        &program_key,
+        &authority_key,
+        &seed,
+        &[1u8; 6],
+        u64::MAX,
+    );
+
+    process_instruction(
+        (
+            &patch(&metadata_key, &authority_key, None, None, 0, 6, &[2]),
+            &[Check::err(ProgramError::ArithmeticOverflow)],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+        ],
+    );
+}
+
+#[test]
+fn test_patch_refunds_excess_lamports() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 100];
+    let metadata_lamports = minimum_balance_for(Header::LEN + data.len());
+    let metadata_account = create_funded_account(metadata_lamports, system_program::ID);
+    let destination_key = Pubkey::new_unique();
+
+    let truncated_lamports = minimum_balance_for(Header::LEN + REVISION_AREA_LEN + 10);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &with_destination(
+                    patch(&metadata_key, &authority_key, None, None, 0, 10, &[2u8; 2]),
+                    &destination_key,
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + 10)
+                        .build(),
+                    // metadata lamports
+                    Check::account(&metadata_key)
+                        .lamports(truncated_lamports)
+                        .build(),
+                    // destination lamports
+                    Check::account(&destination_key)
+                        .lamports(metadata_lamports - truncated_lamports)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
@@ -0,0 +1,520 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{header::Header, LAYOUT_VERSION, SEED_LEN},
+};
+
+/// Offset of the `authority` field in the metadata header.
+const AUTHORITY_OFFSET: usize = 33;
+
+/// Offset of the `mutable` field in the metadata header, followed by the
+/// `canonical` field.
+const MUTABLE_OFFSET: usize = 65;
+
+/// Offset of the `version` field in the metadata header.
+const VERSION_OFFSET: usize = 94;
+
+#[test]
+fn test_promote_move() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), source_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let source_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &source_authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &promote(
+                    &metadata_key,
+                    &upgrade_authority_key,
+                    &source_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    Some(&source_authority_key),
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // source account
+                    Check::account(&source_key).closed().build(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // lamports
+                    Check::account(&metadata_key).lamports(lamports).build(),
+                    // authority
+                    Check::account(&metadata_key)
+                        .data_slice(AUTHORITY_OFFSET, &[0u8; 32])
+                        .build(),
+                    // mutable and canonical
+                    Check::account(&metadata_key)
+                        .data_slice(MUTABLE_OFFSET, &[1, 1])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (metadata_key, Account::default()),
+            (upgrade_authority_key, Account::default()),
+            (source_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_promote_copy_immutable() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), source_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let source_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &source_authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(&source_key, &source_authority_key, None, None),
+                &[Check::success()],
+            ),
+            (
+                &promote(
+                    &metadata_key,
+                    &upgrade_authority_key,
+                    &source_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    None,
+                    true,
+                ),
+                &[
+                    Check::success(),
+                    // source account is unchanged (immutable and non-canonical)
+                    Check::account(&source_key)
+                        .data_slice(MUTABLE_OFFSET, &[0, 0])
+                        .build(),
+                    // mutable and canonical
+                    Check::account(&metadata_key)
+                        .data_slice(MUTABLE_OFFSET, &[1, 1])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (
+                metadata_key,
+                create_funded_account(lamports, system_program::ID),
+            ),
+            (upgrade_authority_key, Account::default()),
+            (source_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_promote_copy_legacy_account() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), source_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    // Legacy (version `0`) source account with trailing bytes after the data.
+    let source_account = setup_legacy_metadata_account(
+        &program_key,
+        Some(&source_authority_key),
+        &seed,
+        0,
+        &data,
+        11,
+    );
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+
+    process_instruction(
+        (
+            &promote(
+                &metadata_key,
+                &upgrade_authority_key,
+                &source_key,
+                &program_key,
+                Some(&program_data_key),
+                None,
+                true,
+            ),
+            &[
+                Check::success(),
+                // source account is unchanged
+                Check::account(&source_key)
+                    .data_slice(VERSION_OFFSET, &[0])
+                    .build(),
+                // account size (trailing bytes are not copied)
+                Check::account(&metadata_key)
+                    .space(Header::LEN + data.len())
+                    .build(),
+                // version
+                Check::account(&metadata_key)
+                    .data_slice(VERSION_OFFSET, &[LAYOUT_VERSION])
+                    .build(),
+                // mutable and canonical
+                Check::account(&metadata_key)
+                    .data_slice(MUTABLE_OFFSET, &[1, 1])
+                    .build(),
+                // metadata data
+                Check::account(&metadata_key)
+                    .data_slice(Header::LEN, &data)
+                    .build(),
+            ],
+        ),
+        &[
+            (source_key, source_account),
+            (
+                metadata_key,
+                create_funded_account(lamports, system_program::ID),
+            ),
+            (upgrade_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_promote_move_immutable() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), source_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let source_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &source_authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(&source_key, &source_authority_key, None, None),
+                &[Check::success()],
+            ),
+            (
+                &promote(
+                    &metadata_key,
+                    &upgrade_authority_key,
+                    &source_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    Some(&source_authority_key),
+                    false,
+                ),
+                &[Check::err(
+                    ProgramMetadataError::ImmutableMetadataAccount.into(),
+                )],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (metadata_key, Account::default()),
+            (upgrade_authority_key, Account::default()),
+            (source_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_promote_move_with_wrong_source_authority() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), source_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let source_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &source_authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The upgrade authority is not the authority of the source account.
+                &promote(
+                    &metadata_key,
+                    &upgrade_authority_key,
+                    &source_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    Some(&upgrade_authority_key),
+                    false,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (metadata_key, Account::default()),
+            (upgrade_authority_key, Account::default()),
+            (source_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_promote_with_wrong_authority() {
+    let upgrade_authority_key = Pubkey::new_unique();
+    let source_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&upgrade_authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    // "non-canonical" source metadata account
+    let (source_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), source_authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    // "canonical" metadata account
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 10];
+    let lamports = minimum_balance_for(Header::LEN + data.len());
+    let source_account = create_funded_account(lamports, system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &source_authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The source authority is not the program upgrade authority.
+                &promote(
+                    &metadata_key,
+                    &source_authority_key,
+                    &source_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    None,
+                    true,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (
+                metadata_key,
+                create_funded_account(lamports, system_program::ID),
+            ),
+            (upgrade_authority_key, Account::default()),
+            (source_authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
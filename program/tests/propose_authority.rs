@@ -0,0 +1,379 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{buffer::Buffer, header::Header, pending_authority::PendingAuthority, SEED_LEN},
+};
+
+#[test]
+fn test_propose_authority_buffer() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let new_authority_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(&buffer_key, &buffer_key, None, None, &new_authority_key),
+                &[
+                    Check::success(),
+                    // account discriminator
+                    Check::account(&pending_authority_key)
+                        .data_slice(0, &[3])
+                        .build(),
+                    // account
+                    Check::account(&pending_authority_key)
+                        .data_slice(1, buffer_key.as_ref())
+                        .build(),
+                    // pending authority
+                    Check::account(&pending_authority_key)
+                        .data_slice(33, new_authority_key.as_ref())
+                        .build(),
+                    // buffer authority (unchanged)
+                    Check::account(&buffer_key)
+                        .data_slice(33, buffer_key.as_ref())
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (pending_authority_key, pending_authority_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_propose_authority_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &new_authority_key,
+                ),
+                &[
+                    Check::success(),
+                    // pending authority
+                    Check::account(&pending_authority_key)
+                        .data_slice(33, new_authority_key.as_ref())
+                        .build(),
+                    // metadata authority (unchanged)
+                    Check::account(&metadata_key)
+                        .data_slice(33, &[0u8; 32])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (pending_authority_key, pending_authority_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_propose_authority_replaces_nomination() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let first_authority_key = Pubkey::new_unique();
+    let second_authority_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(&buffer_key, &buffer_key, None, None, &first_authority_key),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(&buffer_key, &buffer_key, None, None, &second_authority_key),
+                &[
+                    Check::success(),
+                    // pending authority
+                    Check::account(&pending_authority_key)
+                        .data_slice(33, second_authority_key.as_ref())
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (pending_authority_key, pending_authority_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_propose_authority_with_wrong_authority() {
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let wrong_authority_key = Pubkey::new_unique();
+
+    let pending_authority_key = find_pending_authority_address(&buffer_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &buffer_key,
+                    &wrong_authority_key,
+                    None,
+                    None,
+                    &wrong_authority_key,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (wrong_authority_key, Account::default()),
+            (pending_authority_key, pending_authority_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_propose_authority_immutable_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    &new_authority_key,
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::ImmutableMetadataAccount as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (pending_authority_key, pending_authority_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_propose_authority_non_canonical_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let new_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let pending_authority_key = find_pending_authority_address(&metadata_key);
+    let pending_authority_account = create_funded_account(
+        minimum_balance_for(PendingAuthority::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &propose_authority(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    &new_authority_key,
+                ),
+                &[Check::err(ProgramError::InvalidAccountData)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (pending_authority_key, pending_authority_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
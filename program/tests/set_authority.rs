@@ -31,7 +31,7 @@ fn test_set_authority_metadata() {
     let initial_data = [1u8; 5];
     let updated_data = [4u8; 8];
     let metadata_account = create_funded_account(
-        minimum_balance_for(Header::LEN + updated_data.len()),
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
         system_program::ID,
     );
 
@@ -49,6 +49,7 @@ fn test_set_authority_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&initial_data),
                 ),
@@ -76,6 +77,7 @@ fn test_set_authority_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&updated_data),
                 ),
@@ -83,7 +85,7 @@ fn test_set_authority_metadata() {
                     Check::success(),
                     // metadata data
                     Check::account(&metadata_key)
-                        .data_slice(Header::LEN, &updated_data)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
                         .build(),
                 ],
             ),
@@ -119,7 +121,7 @@ fn test_remove_metadata_authority() {
     let initial_data = [1u8; 5];
     let updated_data = [8u8; 7];
     let metadata_account = create_funded_account(
-        minimum_balance_for(Header::LEN + updated_data.len()),
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
         system_program::ID,
     );
 
@@ -137,6 +139,7 @@ fn test_remove_metadata_authority() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&initial_data),
                 ),
@@ -171,13 +174,14 @@ fn test_remove_metadata_authority() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&updated_data),
                 ),
                 &[
                     Check::success(),
                     Check::account(&metadata_key)
-                        .data_slice(Header::LEN, &updated_data)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
                         .build(),
                 ],
             ),
@@ -276,6 +280,7 @@ fn fail_set_authority_immutable_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&[1u8; 5]),
                 ),
@@ -348,6 +353,7 @@ fn test_set_authority_non_canonical_metadata() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&[1u8; 5]),
                 ),
@@ -408,6 +414,7 @@ fn fail_set_authority_with_wrong_authority() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&[1u8; 5]),
                 ),
@@ -8,7 +8,12 @@ use solana_pubkey::Pubkey;
 use solana_sdk_ids::system_program;
 use spl_program_metadata::{
     error::ProgramMetadataError,
-    state::{buffer::Buffer, header::Header, SEED_LEN},
+    state::{
+        buffer::Buffer,
+        data::{AliasData, ExternalDataV2},
+        header::Header,
+        SEED_LEN,
+    },
 };
 
 #[test]
@@ -30,7 +35,7 @@ fn test_set_data_instruction_data() {
     let initial_data = [1u8; 5];
     let updated_data = [2u8; 12];
     let metadata_account = create_funded_account(
-        minimum_balance_for(Header::LEN + updated_data.len()),
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
         system_program::ID,
     );
 
@@ -48,6 +53,7 @@ fn test_set_data_instruction_data() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&initial_data),
                 ),
@@ -71,6 +77,7 @@ fn test_set_data_instruction_data() {
                         compression: 0,
                         format: 1,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&updated_data),
                 ),
@@ -78,11 +85,11 @@ fn test_set_data_instruction_data() {
                     Check::success(),
                     // data length
                     Check::account(&metadata_key)
-                        .space(Header::LEN + updated_data.len())
+                        .space(Header::LEN + REVISION_AREA_LEN + updated_data.len())
                         .build(),
                     // metadata data
                     Check::account(&metadata_key)
-                        .data_slice(Header::LEN, &updated_data)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
                         .build(),
                 ],
             ),
@@ -97,6 +104,80 @@ fn test_set_data_instruction_data() {
     );
 }
 
+#[test]
+fn test_set_data_canonical_loader_v4() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_loader_v4_program_account(&authority_key, LoaderV4Status::Deployed);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = [2u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    None,
+                    SetDataArgs {
+                        encoding: 1,
+                        compression: 0,
+                        format: 1,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
 #[test]
 fn test_set_data_buffer() {
     let authority_key = Pubkey::new_unique();
@@ -118,7 +199,7 @@ fn test_set_data_buffer() {
     let updated_data = [3u8; 9];
 
     let metadata_account = create_funded_account(
-        minimum_balance_for(Header::LEN + updated_data.len()),
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
         system_program::ID,
     );
     let buffer_account = create_funded_account(
@@ -140,6 +221,7 @@ fn test_set_data_buffer() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&initial_data),
                 ),
@@ -181,6 +263,7 @@ fn test_set_data_buffer() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     None,
                 ),
@@ -188,11 +271,11 @@ fn test_set_data_buffer() {
                     Check::success(),
                     // data length
                     Check::account(&metadata_key)
-                        .space(Header::LEN + updated_data.len())
+                        .space(Header::LEN + REVISION_AREA_LEN + updated_data.len())
                         .build(),
                     // metadata data
                     Check::account(&metadata_key)
-                        .data_slice(Header::LEN, &updated_data)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
                         .build(),
                 ],
             ),
@@ -244,6 +327,7 @@ fn test_set_data_without_replacing_data() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -261,6 +345,7 @@ fn test_set_data_without_replacing_data() {
                         compression: 2,
                         format: 3,
                         data_source: None,
+                        hash: None,
                     },
                     None,
                 ),
@@ -285,6 +370,207 @@ fn test_set_data_without_replacing_data() {
     );
 }
 
+#[test]
+fn test_set_data_with_zstd_brotli_and_lz4_compression() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [4u8; 6];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let set_compression = |compression: u8| {
+        set_data(
+            &metadata_key,
+            &authority_key,
+            None,
+            None,
+            None,
+            SetDataArgs {
+                encoding: 0,
+                compression,
+                format: 0,
+                data_source: None,
+                hash: None,
+            },
+            None,
+        )
+    };
+    let zstd = set_compression(3);
+    let brotli = set_compression(4);
+    let lz4 = set_compression(5);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &zstd,
+                &[
+                    Check::success(),
+                    // compression
+                    Check::account(&metadata_key).data_slice(84, &[3]).build(),
+                ],
+            ),
+            (
+                &brotli,
+                &[
+                    Check::success(),
+                    // compression
+                    Check::account(&metadata_key).data_slice(84, &[4]).build(),
+                ],
+            ),
+            (
+                &lz4,
+                &[
+                    Check::success(),
+                    // compression
+                    Check::account(&metadata_key).data_slice(84, &[5]).build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_with_binary_formats() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [4u8; 6];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let set_format = |format: u8| {
+        set_data(
+            &metadata_key,
+            &authority_key,
+            None,
+            None,
+            None,
+            SetDataArgs {
+                encoding: 0,
+                compression: 0,
+                format,
+                data_source: None,
+                hash: None,
+            },
+            None,
+        )
+    };
+    let borsh = set_format(4);
+    let protobuf = set_format(5);
+    let cbor = set_format(6);
+    let message_pack = set_format(7);
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &borsh,
+                &[
+                    Check::success(),
+                    // format
+                    Check::account(&metadata_key).data_slice(85, &[4]).build(),
+                ],
+            ),
+            (
+                &protobuf,
+                &[
+                    Check::success(),
+                    // format
+                    Check::account(&metadata_key).data_slice(85, &[5]).build(),
+                ],
+            ),
+            (
+                &cbor,
+                &[
+                    Check::success(),
+                    // format
+                    Check::account(&metadata_key).data_slice(85, &[6]).build(),
+                ],
+            ),
+            (
+                &message_pack,
+                &[
+                    Check::success(),
+                    // format
+                    Check::account(&metadata_key).data_slice(85, &[7]).build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
 #[test]
 fn test_set_data_shrinks_metadata_account() {
     let authority_key = Pubkey::new_unique();
@@ -305,7 +591,7 @@ fn test_set_data_shrinks_metadata_account() {
     let updated_data = [2u8; 4];
 
     let metadata_account = create_funded_account(
-        minimum_balance_for(Header::LEN + initial_data.len()),
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + initial_data.len()),
         system_program::ID,
     );
 
@@ -323,6 +609,7 @@ fn test_set_data_shrinks_metadata_account() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&initial_data),
                 ),
@@ -340,16 +627,17 @@ fn test_set_data_shrinks_metadata_account() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&updated_data),
                 ),
                 &[
                     Check::success(),
                     Check::account(&metadata_key)
-                        .space(Header::LEN + updated_data.len())
+                        .space(Header::LEN + REVISION_AREA_LEN + updated_data.len())
                         .build(),
                     Check::account(&metadata_key)
-                        .data_slice(Header::LEN, &updated_data)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
                         .build(),
                 ],
             ),
@@ -400,6 +688,7 @@ fn fail_set_data_with_wrong_authority() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -417,6 +706,7 @@ fn fail_set_data_with_wrong_authority() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&[2u8; 5]),
                 ),
@@ -473,6 +763,7 @@ fn fail_set_data_from_empty_buffer_as_direct_data() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -494,6 +785,7 @@ fn fail_set_data_from_empty_buffer_as_direct_data() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     None,
                 ),
@@ -549,6 +841,7 @@ fn fail_set_data_with_invalid_compression() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -566,6 +859,7 @@ fn fail_set_data_with_invalid_compression() {
                         compression: 99, // <- invalid compression
                         format: 0,
                         data_source: None,
+                        hash: None,
                     },
                     None,
                 ),
@@ -618,6 +912,7 @@ fn fail_set_data_with_invalid_data_source() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -635,6 +930,7 @@ fn fail_set_data_with_invalid_data_source() {
                         compression: 0,
                         format: 0,
                         data_source: Some(99), // <- invalid data source
+                        hash: None,
                     },
                     Some(&[2u8; 5]),
                 ),
@@ -690,6 +986,7 @@ fn fail_set_data_from_wrong_owner_buffer() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -707,6 +1004,7 @@ fn fail_set_data_from_wrong_owner_buffer() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     None,
                 ),
@@ -759,6 +1057,7 @@ fn fail_set_data_with_invalid_encoding() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -776,6 +1075,7 @@ fn fail_set_data_with_invalid_encoding() {
                         compression: 0,
                         format: 0,
                         data_source: None,
+                        hash: None,
                     },
                     None,
                 ),
@@ -791,3 +1091,1641 @@ fn fail_set_data_with_invalid_encoding() {
         ],
     );
 }
+
+#[test]
+fn test_set_data_with_base16_encoding() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [0xde, 0xad, 0xbe, 0xef];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 4,
+                        compression: 0,
+                        format: 0,
+                        data_source: None,
+                        hash: None,
+                    },
+                    None,
+                ),
+                &[
+                    Check::success(),
+                    // encoding
+                    Check::account(&metadata_key).data_slice(83, &[4]).build(),
+                    // data (unchanged)
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+/// Serializes chunks as `[address | offset (u32) | length (u32)]` entries.
+fn chunked_data(chunks: &[(Pubkey, u32, u32)]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for (address, offset, length) in chunks {
+        data.extend_from_slice(address.as_ref());
+        data.extend_from_slice(&offset.to_le_bytes());
+        data.extend_from_slice(&length.to_le_bytes());
+    }
+    data
+}
+
+#[test]
+fn test_set_data_with_chunked_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = chunked_data(&[
+        (Pubkey::new_unique(), Header::LEN as u32, 10_000),
+        (Pubkey::new_unique(), Header::LEN as u32, 2_500),
+    ]);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(4),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // data source
+                    Check::account(&metadata_key).data_slice(86, &[4]).build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_empty_chunk() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = chunked_data(&[
+        (Pubkey::new_unique(), 0, 10_000),
+        (Pubkey::new_unique(), 0, 0), // <- empty chunk
+    ]);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(4),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_partial_chunk() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let mut updated_data = chunked_data(&[(Pubkey::new_unique(), 0, 10_000)]);
+    updated_data.extend_from_slice(&[1u8; 4]); // <- partial chunk
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(4),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::InvalidDataLength as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_with_alias_data() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    // Canonical metadata account of another deployment of the same program.
+    let (aliased_metadata_key, _) =
+        Pubkey::find_program_address(&[Pubkey::new_unique().as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + AliasData::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(5),
+                        hash: None,
+                    },
+                    Some(aliased_metadata_key.as_ref()),
+                ),
+                &[
+                    Check::success(),
+                    // data source
+                    Check::account(&metadata_key).data_slice(86, &[5]).build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(
+                            Header::LEN + REVISION_AREA_LEN,
+                            aliased_metadata_key.as_ref(),
+                        )
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_default_alias_address() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + AliasData::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(5),
+                        hash: None,
+                    },
+                    Some(&[0u8; AliasData::LEN]), // <- default address
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+/// Serializes an `ExternalDataV2` value.
+fn external_data_v2(address: &Pubkey, offset: u32, owner: &Pubkey, digest: [u8; 32]) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(address.as_ref());
+    data.extend_from_slice(&offset.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&digest);
+    data
+}
+
+#[test]
+fn test_set_data_with_external_data_v2() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let external_key = Pubkey::new_unique();
+    let external_owner = Pubkey::new_unique();
+    let external_data = [9u8; 128];
+    let external_account = create_account(external_data.to_vec(), false, external_owner);
+
+    let initial_data = [1u8; 5];
+    let updated_data = external_data_v2(
+        &external_key,
+        96,
+        &external_owner,
+        sha256(&external_data[96..]),
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + ExternalDataV2::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &with_external(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        None,
+                        Some(&program_key),
+                        Some(&program_data_key),
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(2),
+                            hash: None,
+                        },
+                        Some(&updated_data),
+                    ),
+                    &external_key,
+                    6,
+                ),
+                &[
+                    Check::success(),
+                    // data source
+                    Check::account(&metadata_key).data_slice(86, &[2]).build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (external_key, external_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_external_data_v2_without_external_account() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = external_data_v2(&Pubkey::new_unique(), 96, &PROGRAM_ID, [7u8; 32]);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + ExternalDataV2::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(2),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[Check::err(ProgramError::NotEnoughAccountKeys)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_external_data_v2_with_wrong_owner() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let external_key = Pubkey::new_unique();
+    let external_owner = Pubkey::new_unique();
+    let external_data = [9u8; 128];
+    // <- not owned by the expected owner
+    let external_account = create_account(external_data.to_vec(), false, Pubkey::new_unique());
+
+    let initial_data = [1u8; 5];
+    let updated_data = external_data_v2(
+        &external_key,
+        96,
+        &external_owner,
+        sha256(&external_data[96..]),
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + ExternalDataV2::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &with_external(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        None,
+                        Some(&program_key),
+                        Some(&program_data_key),
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(2),
+                            hash: None,
+                        },
+                        Some(&updated_data),
+                    ),
+                    &external_key,
+                    6,
+                ),
+                &[Check::err(ProgramError::InvalidAccountOwner)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (external_key, external_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_external_data_v2_with_mismatched_digest() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let external_key = Pubkey::new_unique();
+    let external_owner = Pubkey::new_unique();
+    let external_data = [9u8; 128];
+    let external_account = create_account(external_data.to_vec(), false, external_owner);
+
+    let initial_data = [1u8; 5];
+    let updated_data = external_data_v2(
+        &external_key,
+        96,
+        &external_owner,
+        sha256(&external_data), // <- not the digest of the referenced range
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + ExternalDataV2::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &with_external(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        None,
+                        Some(&program_key),
+                        Some(&program_data_key),
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(2),
+                            hash: None,
+                        },
+                        Some(&updated_data),
+                    ),
+                    &external_key,
+                    6,
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::HashMismatch as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            (external_key, external_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_external_data_v2_without_owner() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = external_data_v2(
+        &Pubkey::new_unique(),
+        96,
+        &Pubkey::default(), // <- default owner
+        [7u8; 32],
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + ExternalDataV2::LEN),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(2),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_from_buffer_range() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut idl_seed = [0u8; SEED_LEN];
+    idl_seed[0..3].copy_from_slice("idl".as_bytes());
+    let (idl_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &idl_seed],
+        &PROGRAM_ID,
+    );
+
+    let mut docs_seed = [0u8; SEED_LEN];
+    docs_seed[0..4].copy_from_slice("docs".as_bytes());
+    let (docs_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &docs_seed],
+        &PROGRAM_ID,
+    );
+
+    // A single buffer holding the data of both accounts.
+    let idl = [1u8; 6];
+    let docs = [2u8; 4];
+    let staging = [idl.as_slice(), docs.as_slice()].concat();
+
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + staging.len()),
+        system_program::ID,
+    );
+    let idl_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + 6),
+        system_program::ID,
+    );
+    let docs_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + 4),
+        system_program::ID,
+    );
+
+    let initialize_args = |seed| InitializeArgs {
+        canonical: false,
+        seed,
+        encoding: 0,
+        compression: 0,
+        format: 0,
+        data_source: 0,
+        hash: None,
+    };
+    let set_data_args = || SetDataArgs {
+        encoding: 0,
+        compression: 0,
+        format: 0,
+        data_source: Some(0),
+        hash: None,
+    };
+    let range = |offset: u32, length: u32| [offset.to_le_bytes(), length.to_le_bytes()].concat();
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    initialize_args(idl_seed),
+                    Some(&[0u8]),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    initialize_args(docs_seed),
+                    Some(&[0u8]),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &staging),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &idl_key,
+                    &authority_key,
+                    Some(&buffer_key),
+                    None,
+                    None,
+                    set_data_args(),
+                    Some(&range(0, 6)),
+                ),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&idl_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + 6)
+                        .build(),
+                    // metadata data
+                    Check::account(&idl_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &idl)
+                        .build(),
+                ],
+            ),
+            (
+                &set_data(
+                    &docs_key,
+                    &authority_key,
+                    Some(&buffer_key),
+                    None,
+                    None,
+                    set_data_args(),
+                    Some(&range(6, 4)),
+                ),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&docs_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + 4)
+                        .build(),
+                    // metadata data
+                    Check::account(&docs_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &docs)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (idl_key, idl_account),
+            (docs_key, docs_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_from_out_of_bounds_buffer_range() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 6];
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let mut range = 4u32.to_le_bytes().to_vec();
+    range.extend_from_slice(&3u32.to_le_bytes());
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&buffer_key),
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&range),
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_closes_buffer() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + data.len()),
+        system_program::ID,
+    );
+    let buffer_key = Pubkey::new_unique();
+    let buffer_lamports = minimum_balance_for(Buffer::LEN + data.len());
+    let buffer_account = create_funded_account(buffer_lamports, system_program::ID);
+    let destination_key = Pubkey::new_unique();
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&[0u8]),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &set_authority(&buffer_key, &buffer_key, None, None, Some(&authority_key)),
+                &[Check::success()],
+            ),
+            (
+                &close_buffer(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        Some(&buffer_key),
+                        None,
+                        None,
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(0),
+                            hash: None,
+                        },
+                        None,
+                    ),
+                    &destination_key,
+                    false,
+                ),
+                &[
+                    Check::success(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &data)
+                        .build(),
+                    // buffer account
+                    Check::account(&buffer_key).closed().build(),
+                    // destination lamports
+                    Check::account(&destination_key)
+                        .lamports(buffer_lamports)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_closing_buffer_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+    let destination_key = Pubkey::new_unique();
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&[0u8]),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                // The buffer is its own authority, but it is not a signer.
+                &close_buffer(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        Some(&buffer_key),
+                        None,
+                        None,
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(0),
+                            hash: None,
+                        },
+                        None,
+                    ),
+                    &destination_key,
+                    false,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_closing_buffer_signed_by_previous_authority() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [5u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+    let destination_key = Pubkey::new_unique();
+    let new_buffer_authority_key = Pubkey::new_unique();
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&[0u8]),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &set_authority(
+                    &buffer_key,
+                    &buffer_key,
+                    None,
+                    None,
+                    Some(&new_buffer_authority_key),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The buffer keypair signs, but it is no longer the buffer authority.
+                &close_buffer(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        Some(&buffer_key),
+                        None,
+                        None,
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(0),
+                            hash: None,
+                        },
+                        None,
+                    ),
+                    &destination_key,
+                    true,
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_at_maximum_revision() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let metadata_key = Pubkey::new_unique();
+    // The revision does not wrap around.
+    let metadata_account = setup_metadata_account_at_revision(
+        &program_key,
+        &authority_key,
+        &seed,
+        &[1u8; 6],
+        u64::MAX,
+    );
+
+    process_instruction(
+        (
+            &set_data(
+                &metadata_key,
+                &authority_key,
+                None,
+                None,
+                None,
+                SetDataArgs {
+                    encoding: 0,
+                    compression: 0,
+                    format: 0,
+                    data_source: Some(0),
+                    hash: None,
+                },
+                Some(&[2u8; 6]),
+            ),
+            &[Check::err(ProgramError::ArithmeticOverflow)],
+        ),
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_refunds_excess_lamports() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = [1u8; 200];
+    let updated_data = [2u8; 20];
+    let metadata_lamports =
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + initial_data.len());
+    let metadata_account = create_funded_account(metadata_lamports, system_program::ID);
+    let destination_key = Pubkey::new_unique();
+
+    let updated_lamports =
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len());
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &with_destination(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        None,
+                        None,
+                        None,
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(0),
+                            hash: None,
+                        },
+                        Some(&updated_data),
+                    ),
+                    &destination_key,
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + updated_data.len())
+                        .build(),
+                    // metadata lamports
+                    Check::account(&metadata_key)
+                        .lamports(updated_lamports)
+                        .build(),
+                    // destination lamports
+                    Check::account(&destination_key)
+                        .lamports(metadata_lamports - updated_lamports)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_with_metadata_as_destination() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 20];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &with_destination(
+                    set_data(
+                        &metadata_key,
+                        &authority_key,
+                        None,
+                        None,
+                        None,
+                        SetDataArgs {
+                            encoding: 0,
+                            compression: 0,
+                            format: 0,
+                            data_source: Some(0),
+                            hash: None,
+                        },
+                        Some(&data[..4]),
+                    ),
+                    &metadata_key,
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
@@ -0,0 +1,481 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{header::Header, HASH_LEN, SEED_LEN},
+};
+
+#[test]
+fn test_set_data_checked_revision() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let initial_data = [1u8; 5];
+    let updated_data = [2u8; 12];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[
+                    Check::success(),
+                    // account size (no revision extension)
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + initial_data.len())
+                        .build(),
+                ],
+            ),
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    DataExpectation::Revision(0),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // revision
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &revision_area(1))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_checked_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = [1u8; 5];
+    let updated_data = [2u8; 12];
+    let hash = sha256(&initial_data);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: Some(hash),
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Hash(hash),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // recomputed hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &sha256(&updated_data))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+            (
+                // The hash of the updated data can be expected right away.
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Hash(sha256(&updated_data)),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[
+                    Check::success(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN + REVISION_AREA_LEN, &initial_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_checked_stale_revision() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = [1u8; 5];
+    let first_update = [2u8; 5];
+    let second_update = [3u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + REVISION_AREA_LEN + initial_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Revision(0),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&first_update),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Revision(0),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&second_update),
+                ),
+                &[
+                    Check::err(ProgramError::Custom(
+                        ProgramMetadataError::MetadataChanged as u32,
+                    )),
+                    // metadata data (unchanged)
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, &first_update)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_data_checked_without_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Hash([7u8; HASH_LEN]),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::MetadataChanged as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_checked_unchanged_data_keeps_revision() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            // Same data with a new format.
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Revision(0),
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 1,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            // Header only update.
+            (
+                &set_data_checked(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    DataExpectation::Revision(0),
+                    SetDataArgs {
+                        encoding: 1,
+                        compression: 0,
+                        format: 1,
+                        data_source: None,
+                        hash: None,
+                    },
+                    None,
+                ),
+                &[
+                    Check::success(),
+                    // account size (no revision extension)
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
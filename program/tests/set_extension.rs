@@ -0,0 +1,549 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{extension::REVISION_EXTENSION_TYPE, header::Header, HASH_LEN, SEED_LEN},
+};
+
+/// Offset of the `flags` field in the metadata header.
+const FLAGS_OFFSET: usize = 91;
+
+#[test]
+fn test_set_extension() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    let value = "application/json".as_bytes();
+    // length prefix (2) + extension (4 + 16) + padding (2)
+    let mut area = vec![24, 0, 1, 0, 16, 0];
+    area.extend_from_slice(value);
+    area.extend_from_slice(&[0, 0]);
+
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + area.len() + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    1,
+                    value,
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + area.len() + data.len())
+                        .build(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[2])
+                        .build(),
+                    // extension area
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &area)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + area.len(), &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_update_and_remove_extension() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + 32 + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 1, &[7; 4]),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 2, &[8; 3]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + 24 + data.len())
+                        .build(),
+                    // extension area
+                    Check::account(&metadata_key)
+                        .data_slice(
+                            Header::LEN,
+                            &[
+                                24, 0, 1, 0, 4, 0, 7, 7, 7, 7, 2, 0, 3, 0, 8, 8, 8, 0, 0, 0, 0, 0,
+                                0, 0,
+                            ],
+                        )
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + 24, &data)
+                        .build(),
+                ],
+            ),
+            (
+                // Replaces the extension with a longer value.
+                &set_extension(&metadata_key, &authority_key, None, None, 1, &[9; 10]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + 32 + data.len())
+                        .build(),
+                    // extension area
+                    Check::account(&metadata_key)
+                        .data_slice(
+                            Header::LEN,
+                            &[
+                                32, 0, 2, 0, 3, 0, 8, 8, 8, 1, 0, 10, 0, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+                                9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                            ],
+                        )
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + 32, &data)
+                        .build(),
+                ],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 1, &[]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + 16 + data.len())
+                        .build(),
+                    // extension area
+                    Check::account(&metadata_key)
+                        .data_slice(
+                            Header::LEN,
+                            &[16, 0, 2, 0, 3, 0, 8, 8, 8, 0, 0, 0, 0, 0, 0, 0],
+                        )
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + 16, &data)
+                        .build(),
+                ],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 2, &[]),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[0])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_preserves_extensions() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = [1u8; 5];
+    let updated_data = [2u8; 12];
+    let hash = sha256(&initial_data);
+    let area = [8, 0, 1, 0, 2, 0, 3, 3];
+    // length prefix (2) + extensions (4 + 2, 4 + 8) + padding (4)
+    let mut updated_area = vec![24, 0, 1, 0, 2, 0, 3, 3];
+    updated_area.extend_from_slice(&REVISION_EXTENSION_TYPE.to_le_bytes());
+    updated_area.extend_from_slice(&[8, 0]);
+    updated_area.extend_from_slice(&1u64.to_le_bytes());
+    updated_area.extend_from_slice(&[0; 4]);
+
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + updated_area.len() + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(&metadata_key, &authority_key, None, None, Some(&hash)),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 1, &[3; 2]),
+                &[
+                    Check::success(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[3])
+                        .build(),
+                    // extension area
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN, &area)
+                        .build(),
+                ],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + updated_area.len() + updated_data.len())
+                        .build(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[3])
+                        .build(),
+                    // recomputed hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &sha256(&updated_data))
+                        .build(),
+                    // extension area (with the revision)
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN, &updated_area)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN + updated_area.len(), &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_extension_with_reserved_type() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 0, &[1; 4]),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+            (
+                &set_extension(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    REVISION_EXTENSION_TYPE,
+                    &[1; 8],
+                ),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_extension_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+    let wrong_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + 16 + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &wrong_authority_key, None, None, 1, &[1; 4]),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (wrong_authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_extension_immutable_metadata() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + 16 + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(&metadata_key, &authority_key, None, None),
+                &[Check::success()],
+            ),
+            (
+                &set_extension(&metadata_key, &authority_key, None, None, 1, &[1; 4]),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::ImmutableMetadataAccount as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
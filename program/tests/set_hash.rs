@@ -0,0 +1,672 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{header::Header, HASH_LEN, SEED_LEN},
+};
+
+/// Offset of the `flags` field in the metadata header.
+const FLAGS_OFFSET: usize = 91;
+
+#[test]
+fn test_set_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_data_key = Pubkey::new_unique();
+    let program_data_account = setup_program_data_account(Some(&authority_key));
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&program_data_key);
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) =
+        Pubkey::find_program_address(&[program_key.as_ref(), &seed], &PROGRAM_ID);
+
+    let data = [1u8; 5];
+    let hash = sha256(&data);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    Some(&program_data_key),
+                    InitializeArgs {
+                        canonical: true,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    Some(&program_key),
+                    Some(&program_data_key),
+                    Some(&hash),
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + data.len())
+                        .build(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[1])
+                        .build(),
+                    // content hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &hash)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (program_data_key, program_data_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_update_and_remove_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    // The hash of url data is not checked against the account data.
+    let data = *b"https://example.com/idl.json";
+    let initial_hash = [7u8; HASH_LEN];
+    let updated_hash = [8u8; HASH_LEN];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 1,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    Some(&initial_hash),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    Some(&updated_hash),
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + data.len())
+                        .build(),
+                    // content hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &updated_hash)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN, &data)
+                        .build(),
+                ],
+            ),
+            (
+                &set_hash(&metadata_key, &authority_key, None, None, None),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + data.len())
+                        .build(),
+                    // flags
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[0])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_recomputes_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = [1u8; 5];
+    let updated_data = [2u8; 12];
+    let hash = sha256(&initial_data);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(&metadata_key, &authority_key, None, None, Some(&hash)),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(0),
+                        hash: None,
+                    },
+                    Some(&updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + REVISION_AREA_LEN + updated_data.len())
+                        .build(),
+                    // flags (hash section and extension area)
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[3])
+                        .build(),
+                    // recomputed hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &sha256(&updated_data))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN + REVISION_AREA_LEN, &updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_removes_url_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = b"https://example.com/idl.json";
+    let updated_data = b"https://example.com/v2/idl.json";
+    // Digest of the referenced content, which is not checked by the program.
+    let hash = [7u8; HASH_LEN];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 1,
+                        hash: Some(hash),
+                    },
+                    Some(initial_data),
+                ),
+                &[
+                    Check::success(),
+                    // hash recorded on initialization
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &hash)
+                        .build(),
+                ],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(1),
+                        hash: None,
+                    },
+                    Some(updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + REVISION_AREA_LEN + updated_data.len())
+                        .build(),
+                    // flags (extension area only)
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[2])
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + REVISION_AREA_LEN, updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_set_data_with_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let initial_data = b"https://example.com/idl.json";
+    let updated_data = b"https://example.com/v2/idl.json";
+    // Digest of the referenced content, which is not checked by the program.
+    let hash = [7u8; HASH_LEN];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + REVISION_AREA_LEN + updated_data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 1,
+                        hash: None,
+                    },
+                    Some(initial_data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_data(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    None,
+                    SetDataArgs {
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: Some(1),
+                        hash: Some(hash),
+                    },
+                    Some(updated_data),
+                ),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + REVISION_AREA_LEN + updated_data.len())
+                        .build(),
+                    // flags (hash section and extension area)
+                    Check::account(&metadata_key)
+                        .data_slice(FLAGS_OFFSET, &[3])
+                        .build(),
+                    // recorded hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &hash)
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN + REVISION_AREA_LEN, updated_data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_hash_with_wrong_authority() {
+    let authority_key = Pubkey::new_unique();
+    let wrong_authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &wrong_authority_key,
+                    None,
+                    None,
+                    Some(&[7u8; HASH_LEN]),
+                ),
+                &[Check::err(ProgramError::IncorrectAuthority)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (wrong_authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_hash_immutable_metadata() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(&metadata_key, &authority_key, None, None),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    Some(&[7u8; HASH_LEN]),
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::ImmutableMetadataAccount as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_set_hash_with_mismatched_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                // The hash of direct data must be the digest of the account data.
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    Some(&sha256(&[2u8; 5])),
+                ),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::HashMismatch as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
@@ -47,6 +47,7 @@ fn test_set_immutable_canonical() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -73,6 +74,7 @@ fn test_set_immutable_canonical() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&[2u8; 6]),
                 ),
@@ -129,6 +131,7 @@ fn test_set_immutable_non_canonical() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -150,6 +153,7 @@ fn test_set_immutable_non_canonical() {
                         compression: 0,
                         format: 0,
                         data_source: Some(0),
+                        hash: None,
                     },
                     Some(&[2u8; 6]),
                 ),
@@ -205,6 +209,7 @@ fn fail_set_immutable_with_wrong_authority() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
@@ -291,6 +296,7 @@ fn fail_set_immutable_twice() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&data),
                 ),
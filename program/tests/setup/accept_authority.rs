@@ -0,0 +1,28 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::{find_pending_authority_address, PROGRAM_ID};
+
+pub fn accept_authority(
+    account: &Pubkey,
+    new_authority: &Pubkey,
+    program: Option<&Pubkey>,
+    program_data: Option<&Pubkey>,
+    destination: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*new_authority, true),
+        AccountMeta::new_readonly(*program.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new(find_pending_authority_address(account), false),
+        AccountMeta::new(*destination, false),
+    ];
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: vec![ProgramMetadataInstruction::AcceptAuthority as u8],
+    }
+}
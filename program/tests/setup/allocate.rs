@@ -3,7 +3,7 @@ use solana_pubkey::Pubkey;
 use solana_sdk_ids::system_program;
 use spl_program_metadata::{instruction::ProgramMetadataInstruction, state::SEED_LEN};
 
-use super::PROGRAM_ID;
+use super::{encode_seed, PROGRAM_ID};
 
 pub fn allocate(
     buffer: &Pubkey,
@@ -31,3 +31,17 @@ pub fn allocate(
         data,
     }
 }
+
+/// Replaces the seed of an `allocate` instruction with an encoded variable-length
+/// `seed`.
+pub fn with_variable_seed(mut instruction: Instruction, seed: &[u8]) -> Instruction {
+    instruction.data.truncate(1);
+    instruction.data.extend_from_slice(&encode_seed(seed));
+    instruction
+}
+
+/// Appends an initial data `capacity` to an `allocate` instruction.
+pub fn with_capacity(mut instruction: Instruction, capacity: u32) -> Instruction {
+    instruction.data.extend_from_slice(&capacity.to_le_bytes());
+    instruction
+}
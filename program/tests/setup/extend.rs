@@ -9,7 +9,7 @@ pub fn extend(
     authority: &Pubkey,
     program: Option<&Pubkey>,
     program_data: Option<&Pubkey>,
-    length: u16,
+    length: u32,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*account, false),
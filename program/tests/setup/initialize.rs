@@ -1,8 +1,9 @@
 use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 use solana_sdk_ids::system_program;
+use spl_program_metadata::{instruction::ProgramMetadataInstruction, state::VARIABLE_SEED_LEN};
 
-use super::PROGRAM_ID;
+use super::{push_hash, PROGRAM_ID};
 
 pub fn initialize(
     authority: &Pubkey,
@@ -33,6 +34,7 @@ pub fn initialize(
     data[18] = args.compression;
     data[19] = args.format;
     data[20] = args.data_source;
+    push_hash(&mut data, args.hash.as_ref());
 
     if let Some(instruction_data) = instruction_data {
         data.extend_from_slice(instruction_data);
@@ -52,4 +54,74 @@ pub struct InitializeArgs {
     pub compression: u8,
     pub format: u8,
     pub data_source: u8,
+    pub hash: Option<[u8; 32]>,
+}
+
+/// Adds the `source_buffer` account to an `Initialize` instruction and, if
+/// provided, the `destination` account to close it after its data is copied.
+pub fn with_source_buffer(
+    mut instruction: Instruction,
+    source_buffer: &Pubkey,
+    destination: Option<&Pubkey>,
+    buffer_signer: bool,
+) -> Instruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new(*source_buffer, buffer_signer));
+    if let Some(destination) = destination {
+        instruction
+            .accounts
+            .push(AccountMeta::new(*destination, false));
+    }
+    instruction
+}
+
+/// Encodes a variable-length seed as its length followed by the zero-padded
+/// seed bytes.
+pub fn encode_seed(seed: &[u8]) -> [u8; VARIABLE_SEED_LEN] {
+    let mut encoded = [0u8; VARIABLE_SEED_LEN];
+    encoded[0] = seed.len() as u8;
+    encoded[1..=seed.len()].copy_from_slice(seed);
+    encoded
+}
+
+pub fn initialize_with_seed(
+    authority: &Pubkey,
+    program: &Pubkey,
+    program_data: Option<&Pubkey>,
+    canonical: bool,
+    seed: &[u8],
+    instruction_data: Option<&[u8]>,
+) -> Instruction {
+    let seeds: &[&[u8]] = if canonical {
+        &[program.as_ref(), seed]
+    } else {
+        &[program.as_ref(), authority.as_ref(), seed]
+    };
+    let (metadata_key, _) = Pubkey::find_program_address(seeds, &PROGRAM_ID);
+
+    let accounts = vec![
+        AccountMeta::new(metadata_key, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*program, false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    // Encoding, compression, format and data source are all `0`, and there is
+    // no hash.
+    let mut data = vec![ProgramMetadataInstruction::InitializeWithSeed as u8];
+    data.extend_from_slice(&encode_seed(seed));
+    data.extend_from_slice(&[0u8; 4]);
+    push_hash(&mut data, None);
+
+    if let Some(instruction_data) = instruction_data {
+        data.extend_from_slice(instruction_data);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
 }
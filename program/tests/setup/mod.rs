@@ -1,29 +1,52 @@
+mod accept_authority;
 mod allocate;
+mod cancel_authority;
 mod close;
 mod extend;
 mod initialize;
+mod migrate;
+mod move_metadata;
+mod patch;
+mod promote;
+mod propose_authority;
 mod set_authority;
 mod set_data;
+mod set_data_checked;
+mod set_extension;
+mod set_hash;
 mod set_immutable;
 mod trim;
+mod verify_hash;
 mod write;
 
+pub use accept_authority::*;
 pub use allocate::*;
+pub use cancel_authority::*;
 pub use close::*;
 pub use extend::*;
 pub use initialize::*;
+pub use migrate::*;
+pub use move_metadata::*;
+pub use patch::*;
+pub use promote::*;
+pub use propose_authority::*;
 pub use set_authority::*;
 pub use set_data::*;
+pub use set_data_checked::*;
+pub use set_extension::*;
+pub use set_hash::*;
 pub use set_immutable::*;
 pub use trim::*;
+pub use verify_hash::*;
 pub use write::*;
 
 use mollusk_svm::{result::Check, Mollusk};
 use solana_account::{create_account_for_test, Account};
-use solana_instruction::Instruction;
+use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 use solana_rent::{Rent, DEFAULT_LAMPORTS_PER_BYTE};
-use solana_sdk_ids::bpf_loader_upgradeable;
+use solana_sdk_ids::{bpf_loader_upgradeable, loader_v4, system_program};
+use spl_program_metadata::state::extension::REVISION_EXTENSION_TYPE;
 
 pub const PROGRAM_ID: Pubkey = spl_program_metadata::ID;
 
@@ -68,6 +91,79 @@ pub fn minimum_balance_for(data_len: usize) -> u64 {
     Rent::default().minimum_balance(data_len)
 }
 
+/// Returns the SHA-256 digest of the `data`, which is the expected content hash
+/// of metadata accounts holding the `data` directly.
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    solana_sha256_hasher::hash(data).to_bytes()
+}
+
+/// Length of the extension area of a metadata account holding only the revision
+/// extension: length prefix (2) + extension (4 + 8) + padding (2).
+pub const REVISION_AREA_LEN: usize = 16;
+
+/// Returns the extension area of a metadata account holding only the revision
+/// extension with the given `revision`.
+pub fn revision_area(revision: u64) -> Vec<u8> {
+    let mut area = (REVISION_AREA_LEN as u16).to_le_bytes().to_vec();
+    area.extend_from_slice(&REVISION_EXTENSION_TYPE.to_le_bytes());
+    area.extend_from_slice(&8u16.to_le_bytes());
+    area.extend_from_slice(&revision.to_le_bytes());
+    area.resize(REVISION_AREA_LEN, 0);
+    area
+}
+
+/// Appends an optional content hash to the instruction data, encoded as an
+/// `Option<[u8; 32]>`.
+pub fn push_hash(data: &mut Vec<u8>, hash: Option<&[u8; 32]>) {
+    match hash {
+        Some(hash) => {
+            data.push(1);
+            data.extend_from_slice(hash);
+        }
+        None => data.push(0),
+    }
+}
+
+/// Adds the optional `destination` account to an instruction, which must be
+/// the last account expected by the instruction.
+pub fn with_destination(mut instruction: Instruction, destination: &Pubkey) -> Instruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new(*destination, false));
+    instruction
+}
+
+/// Adds the optional `external` account at the given `position` of an instruction,
+/// which must be the last account expected by the instruction.
+///
+/// Optional accounts between the current accounts and the `external` account are
+/// omitted.
+pub fn with_external(
+    mut instruction: Instruction,
+    external: &Pubkey,
+    position: usize,
+) -> Instruction {
+    while instruction.accounts.len() < position {
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(PROGRAM_ID, false));
+    }
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*external, false));
+    instruction
+}
+
+/// Adds the optional `payer` account and the system program to an instruction,
+/// which must be the last accounts expected by the instruction.
+pub fn with_payer(mut instruction: Instruction, payer: &Pubkey) -> Instruction {
+    instruction.accounts.extend([
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ]);
+    instruction
+}
+
 pub fn process_instruction(instruction: (&Instruction, &[Check]), accounts: &[(Pubkey, Account)]) {
     process_instructions(&[instruction], accounts);
 }
@@ -103,3 +199,114 @@ pub fn setup_program_data_account(authority: Option<&Pubkey>) -> Account {
 
     create_account(data, false, bpf_loader_upgradeable::ID)
 }
+
+/// Creates a metadata account using the layout prior to versioning (version `0`).
+///
+/// The `authority` is only set for non-canonical accounts and `trailing` bytes
+/// are added after the data.
+pub fn setup_legacy_metadata_account(
+    program: &Pubkey,
+    authority: Option<&Pubkey>,
+    seed: &[u8; 16],
+    data_source: u8,
+    data: &[u8],
+    trailing: usize,
+) -> Account {
+    let mut account_data = vec![0; 96];
+    account_data[0] = 2;
+    account_data[1..33].copy_from_slice(program.as_ref());
+
+    if let Some(authority) = authority {
+        account_data[33..65].copy_from_slice(authority.as_ref());
+    }
+
+    account_data[65] = 1;
+    account_data[66] = authority.is_none() as u8;
+    account_data[67..83].copy_from_slice(seed);
+    account_data[86] = data_source;
+    account_data[87..91].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    account_data.extend_from_slice(data);
+    account_data.extend(core::iter::repeat_n(0, trailing));
+
+    create_account(account_data, false, PROGRAM_ID)
+}
+
+/// Creates a non-canonical metadata account holding the `data` directly, at the
+/// given `revision`.
+pub fn setup_metadata_account_at_revision(
+    program: &Pubkey,
+    authority: &Pubkey,
+    seed: &[u8; 16],
+    data: &[u8],
+    revision: u64,
+) -> Account {
+    let mut account = setup_legacy_metadata_account(program, Some(authority), seed, 0, data, 0);
+    // flags (extension area)
+    account.data[91] = 0b0000_0010;
+    // version
+    account.data[94] = 1;
+    account.data.splice(96..96, revision_area(revision));
+    account.lamports = Rent::default().minimum_balance(account.data.len());
+
+    account
+}
+
+/// Loader `v4` program status.
+#[derive(Clone, Copy, PartialEq)]
+pub enum LoaderV4Status {
+    Retracted,
+    Deployed,
+    Finalized,
+}
+
+pub fn setup_loader_v4_program_account(authority: &Pubkey, status: LoaderV4Status) -> Account {
+    let mut data = vec![0; 48];
+    data[8..40].copy_from_slice(authority.as_ref());
+    data[40..48].copy_from_slice(&(status as u64).to_le_bytes());
+
+    create_account(data, status != LoaderV4Status::Retracted, loader_v4::ID)
+}
+
+pub const TOKEN_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Creates an initialized token mint account owned by the `token_program`.
+///
+/// When `extended` is set, the mint uses the Token-2022 layout with extensions,
+/// where the account type follows the length of a token account.
+pub fn setup_mint_account(
+    mint_authority: Option<&Pubkey>,
+    token_program: &Pubkey,
+    extended: bool,
+) -> Account {
+    let mut data = vec![0; if extended { 170 } else { 82 }];
+
+    if let Some(mint_authority) = mint_authority {
+        data[0] = 1;
+        data[4..36].copy_from_slice(mint_authority.as_ref());
+    }
+    // is_initialized
+    data[45] = 1;
+
+    if extended {
+        // account type
+        data[165] = 1;
+    }
+
+    create_account(data, false, *token_program)
+}
+
+pub fn setup_loader_v3_buffer_account(authority: Option<&Pubkey>) -> Account {
+    let mut data = vec![0; 37];
+    data[0] = 1;
+
+    if let Some(authority) = authority {
+        data[4] = 1;
+        data[5..37].copy_from_slice(authority.as_ref());
+    }
+
+    create_account(data, false, bpf_loader_upgradeable::ID)
+}
@@ -0,0 +1,44 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::{encode_seed, find_pending_authority_address, PROGRAM_ID};
+
+#[allow(clippy::too_many_arguments)]
+pub fn move_metadata(
+    metadata: &Pubkey,
+    authority: &Pubkey,
+    new_metadata: &Pubkey,
+    program: &Pubkey,
+    program_data: Option<&Pubkey>,
+    destination: &Pubkey,
+    new_authority: Option<&Pubkey>,
+    new_seed: &[u8],
+    variable_seed: bool,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*metadata, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*new_metadata, false),
+        AccountMeta::new_readonly(*program, false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(find_pending_authority_address(metadata), false),
+        AccountMeta::new(*destination, false),
+    ];
+
+    if let Some(new_authority) = new_authority {
+        accounts.push(AccountMeta::new_readonly(*new_authority, true));
+    }
+
+    let mut data = vec![ProgramMetadataInstruction::Move as u8];
+    data.extend_from_slice(&encode_seed(new_seed));
+    data.push(variable_seed as u8);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
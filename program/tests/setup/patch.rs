@@ -0,0 +1,33 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::PROGRAM_ID;
+
+pub fn patch(
+    metadata: &Pubkey,
+    authority: &Pubkey,
+    program: Option<&Pubkey>,
+    program_data: Option<&Pubkey>,
+    offset: u32,
+    data_length: u32,
+    patch: &[u8],
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*metadata, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*program.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+    ];
+
+    let mut data = vec![ProgramMetadataInstruction::Patch as u8];
+    data.extend_from_slice(&offset.to_le_bytes());
+    data.extend_from_slice(&data_length.to_le_bytes());
+    data.extend_from_slice(patch);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
@@ -0,0 +1,35 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::PROGRAM_ID;
+
+pub fn promote(
+    metadata: &Pubkey,
+    authority: &Pubkey,
+    source: &Pubkey,
+    program: &Pubkey,
+    program_data: Option<&Pubkey>,
+    source_authority: Option<&Pubkey>,
+    copy: bool,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*metadata, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*source, false),
+        AccountMeta::new_readonly(*program, false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    if let Some(source_authority) = source_authority {
+        accounts.push(AccountMeta::new_readonly(*source_authority, true));
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: vec![ProgramMetadataInstruction::Promote as u8, copy as u8],
+    }
+}
@@ -0,0 +1,38 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    instruction::ProgramMetadataInstruction, state::pending_authority::PENDING_AUTHORITY_SEED,
+};
+
+use super::PROGRAM_ID;
+
+pub fn find_pending_authority_address(account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PENDING_AUTHORITY_SEED, account.as_ref()], &PROGRAM_ID).0
+}
+
+pub fn propose_authority(
+    account: &Pubkey,
+    authority: &Pubkey,
+    program: Option<&Pubkey>,
+    program_data: Option<&Pubkey>,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*account, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*program.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new(find_pending_authority_address(account), false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let mut data = vec![ProgramMetadataInstruction::ProposeAuthority as u8];
+    data.extend_from_slice(new_authority.as_ref());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
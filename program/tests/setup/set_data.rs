@@ -2,7 +2,7 @@ use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 use spl_program_metadata::instruction::ProgramMetadataInstruction;
 
-use super::PROGRAM_ID;
+use super::{push_hash, with_destination, PROGRAM_ID};
 
 pub fn set_data(
     metadata: &Pubkey,
@@ -27,6 +27,7 @@ pub fn set_data(
         args.compression,
         args.format,
     ];
+    push_hash(&mut data, args.hash.as_ref());
 
     if let Some(data_source) = args.data_source {
         data.push(data_source);
@@ -47,4 +48,17 @@ pub struct SetDataArgs {
     pub compression: u8,
     pub format: u8,
     pub data_source: Option<u8>,
+    pub hash: Option<[u8; 32]>,
+}
+
+/// Adds the `destination` account to a `SetData` instruction, so the buffer
+/// account is closed after its data is copied.
+pub fn close_buffer(
+    mut instruction: Instruction,
+    destination: &Pubkey,
+    buffer_signer: bool,
+) -> Instruction {
+    instruction.accounts[2].is_writable = true;
+    instruction.accounts[2].is_signer = buffer_signer;
+    with_destination(instruction, destination)
 }
@@ -0,0 +1,51 @@
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::{set_data, SetDataArgs};
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_data_checked(
+    metadata: &Pubkey,
+    authority: &Pubkey,
+    buffer: Option<&Pubkey>,
+    program: Option<&Pubkey>,
+    program_data: Option<&Pubkey>,
+    expectation: DataExpectation,
+    args: SetDataArgs,
+    instruction_data: Option<&[u8]>,
+) -> Instruction {
+    let mut instruction = set_data(
+        metadata,
+        authority,
+        buffer,
+        program,
+        program_data,
+        args,
+        instruction_data,
+    );
+
+    let mut data = vec![ProgramMetadataInstruction::SetDataChecked as u8];
+
+    match expectation {
+        DataExpectation::Revision(revision) => {
+            data.push(0);
+            data.extend_from_slice(&revision.to_le_bytes());
+        }
+        DataExpectation::Hash(hash) => {
+            data.push(1);
+            data.extend_from_slice(&hash);
+        }
+    }
+
+    // Skip the `SetData` discriminator.
+    data.extend_from_slice(&instruction.data[1..]);
+    instruction.data = data;
+
+    instruction
+}
+
+pub enum DataExpectation {
+    Revision(u64),
+    Hash([u8; 32]),
+}
@@ -0,0 +1,31 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::PROGRAM_ID;
+
+pub fn set_extension(
+    metadata: &Pubkey,
+    authority: &Pubkey,
+    program: Option<&Pubkey>,
+    program_data: Option<&Pubkey>,
+    extension_type: u16,
+    value: &[u8],
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*metadata, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*program.unwrap_or(&PROGRAM_ID), false),
+        AccountMeta::new_readonly(*program_data.unwrap_or(&PROGRAM_ID), false),
+    ];
+
+    let mut data = vec![ProgramMetadataInstruction::SetExtension as u8];
+    data.extend_from_slice(&extension_type.to_le_bytes());
+    data.extend_from_slice(value);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
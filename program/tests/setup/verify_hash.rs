@@ -0,0 +1,16 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use spl_program_metadata::instruction::ProgramMetadataInstruction;
+
+use super::PROGRAM_ID;
+
+pub fn verify_hash(metadata: &Pubkey, hash: &[u8; 32]) -> Instruction {
+    let mut data = vec![ProgramMetadataInstruction::VerifyHash as u8];
+    data.extend_from_slice(hash);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(*metadata, false)],
+        data,
+    }
+}
@@ -6,7 +6,7 @@ use solana_account::Account;
 use solana_program_error::ProgramError;
 use solana_pubkey::Pubkey;
 use solana_sdk_ids::{system_program, sysvar::rent};
-use spl_program_metadata::state::{buffer::Buffer, header::Header};
+use spl_program_metadata::state::{buffer::Buffer, header::Header, HASH_LEN};
 
 const EXCESS_LAMPORTS: usize = 90;
 
@@ -46,6 +46,7 @@ fn test_trim_canonical() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&[1u8; 10]),
                 ),
@@ -140,6 +141,7 @@ fn test_trim_non_canonical() {
                         compression: 0,
                         format: 0,
                         data_source: 0,
+                        hash: None,
                     },
                     Some(&[1u8; 10]),
                 ),
@@ -251,6 +253,101 @@ fn test_trim_buffer() {
     );
 }
 
+#[test]
+fn test_trim_with_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; 16];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + 10 + EXCESS_LAMPORTS),
+        system_program::ID,
+    );
+
+    let destination_key = Pubkey::new_unique();
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&[1u8; 10]),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    Some(&sha256(&[1u8; 10])),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &extend(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    EXCESS_LAMPORTS as u32,
+                ),
+                &[Check::success()],
+            ),
+            (
+                &trim(&metadata_key, &authority_key, None, None, &destination_key),
+                &[
+                    Check::success(),
+                    // account size
+                    Check::account(&metadata_key)
+                        .space(Header::LEN + HASH_LEN + 10)
+                        .build(),
+                    // content hash
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN, &sha256(&[1u8; 10]))
+                        .build(),
+                    // metadata data
+                    Check::account(&metadata_key)
+                        .data_slice(Header::LEN + HASH_LEN, &[1u8; 10])
+                        .build(),
+                    // destination lamports
+                    Check::account(&destination_key)
+                        .lamports(lamports_for(EXCESS_LAMPORTS))
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (destination_key, Account::default()),
+            keyed_account_for_system_program(),
+            (solana_rent::sysvar::ID, rent_sysvar()),
+        ],
+    );
+}
+
 #[test]
 fn fail_trim_non_rent_exempt_account() {
     let destination_key = Pubkey::new_unique();
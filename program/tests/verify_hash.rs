@@ -0,0 +1,233 @@
+mod setup;
+pub use setup::*;
+
+use mollusk_svm::{program::keyed_account_for_system_program, result::Check};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+use solana_sdk_ids::system_program;
+use spl_program_metadata::{
+    error::ProgramMetadataError,
+    state::{header::Header, HASH_LEN, LAYOUT_VERSION, SEED_LEN},
+};
+
+#[test]
+fn test_verify_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let url = b"https://example.com/idl.json";
+    let hash = [7u8; HASH_LEN];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + url.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 1,
+                        compression: 0,
+                        format: 1,
+                        data_source: 1,
+                        hash: None,
+                    },
+                    Some(url),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(&metadata_key, &authority_key, None, None, Some(&hash)),
+                &[Check::success()],
+            ),
+            (
+                &set_immutable(&metadata_key, &authority_key, None, None),
+                &[Check::success()],
+            ),
+            (&verify_hash(&metadata_key, &hash), &[Check::success()]),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_verify_hash_mismatch() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(
+                    &metadata_key,
+                    &authority_key,
+                    None,
+                    None,
+                    Some(&sha256(&data)),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &verify_hash(&metadata_key, &[8u8; HASH_LEN]),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::HashMismatch as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_verify_hash_without_hash() {
+    let authority_key = Pubkey::new_unique();
+
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &verify_hash(&metadata_key, &[0u8; HASH_LEN]),
+                &[Check::err(ProgramError::Custom(
+                    ProgramMetadataError::HashMismatch as u32,
+                ))],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_verify_hash_with_unsupported_version() {
+    let authority_key = Pubkey::new_unique();
+    let program_key = Pubkey::new_unique();
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [1u8; 5];
+    let hash = sha256(&data);
+    let mut metadata_account =
+        setup_legacy_metadata_account(&program_key, Some(&authority_key), &seed, 0, &data, 0);
+    // mutable (immutable accounts can also be verified)
+    metadata_account.data[65] = 0;
+    // flags (hash section)
+    metadata_account.data[91] = 1;
+    // Simulates an account created by a newer version of the program.
+    metadata_account.data[94] = LAYOUT_VERSION + 1;
+    metadata_account.data.splice(Header::LEN..Header::LEN, hash);
+
+    process_instruction(
+        (
+            &verify_hash(&metadata_key, &hash),
+            &[Check::err(ProgramError::Custom(
+                ProgramMetadataError::UnsupportedAccountVersion as u32,
+            ))],
+        ),
+        &[(metadata_key, metadata_account)],
+    );
+}
@@ -6,7 +6,7 @@ use solana_account::Account;
 use solana_program_error::ProgramError;
 use solana_pubkey::Pubkey;
 use solana_sdk_ids::system_program;
-use spl_program_metadata::state::buffer::Buffer;
+use spl_program_metadata::state::{buffer::Buffer, header::Header, HASH_LEN, SEED_LEN};
 
 #[test]
 fn test_write_instruction_data() {
@@ -258,3 +258,318 @@ fn fail_write_from_same_buffer() {
         ],
     );
 }
+
+#[test]
+fn test_write_from_metadata() {
+    let authority_key = Pubkey::new_unique();
+    let program_key = Pubkey::new_unique();
+    let program_account = setup_program_account(&Pubkey::new_unique());
+
+    let mut seed = [0u8; SEED_LEN];
+    seed[0..3].copy_from_slice("idl".as_bytes());
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[program_key.as_ref(), authority_key.as_ref(), &seed],
+        &PROGRAM_ID,
+    );
+
+    let data = [3u8; 10];
+    let hash = sha256(&data);
+    let metadata_account = create_funded_account(
+        minimum_balance_for(Header::LEN + HASH_LEN + data.len()),
+        system_program::ID,
+    );
+
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &initialize(
+                    &authority_key,
+                    &program_key,
+                    None,
+                    InitializeArgs {
+                        canonical: false,
+                        seed,
+                        encoding: 0,
+                        compression: 0,
+                        format: 0,
+                        data_source: 0,
+                        hash: None,
+                    },
+                    Some(&data),
+                ),
+                &[Check::success()],
+            ),
+            (
+                &set_hash(&metadata_key, &authority_key, None, None, Some(&hash)),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, Some(&metadata_key), 0, &[]),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&buffer_key)
+                        .space(Buffer::LEN + data.len())
+                        .build(),
+                    // buffer data (without the content hash)
+                    Check::account(&buffer_key)
+                        .data_slice(Buffer::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (authority_key, Account::default()),
+            (program_key, program_account),
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_write_from_immutable_metadata() {
+    let program_key = Pubkey::new_unique();
+    let metadata_key = Pubkey::new_unique();
+
+    let data = [4u8; 8];
+    // Legacy accounts might have trailing bytes after the data.
+    let mut metadata_account =
+        setup_legacy_metadata_account(&program_key, None, &[1u8; SEED_LEN], 0, &data, 5);
+    // mutable = false
+    metadata_account.data[65] = 0;
+
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + 2 + data.len()),
+        system_program::ID,
+    );
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, Some(&metadata_key), 2, &[]),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&buffer_key)
+                        .space(Buffer::LEN + 2 + data.len())
+                        .build(),
+                    // buffer data
+                    Check::account(&buffer_key)
+                        .data_slice(Buffer::LEN + 2, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_write_from_metadata_with_invalid_data_length() {
+    let program_key = Pubkey::new_unique();
+    let metadata_key = Pubkey::new_unique();
+
+    let data = [4u8; 8];
+    let mut metadata_account =
+        setup_legacy_metadata_account(&program_key, None, &[1u8; SEED_LEN], 0, &data, 0);
+    // data_length larger than the account data
+    metadata_account.data[87..91].copy_from_slice(&20u32.to_le_bytes());
+
+    let buffer_key = Pubkey::new_unique();
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN + 20), system_program::ID);
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&buffer_key, &buffer_key, Some(&metadata_key), 0, &[]),
+                &[Check::err(ProgramError::InvalidAccountData)],
+            ),
+        ],
+        &[
+            (metadata_key, metadata_account),
+            (buffer_key, buffer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_write_from_buffer_range() {
+    let source_key = Pubkey::new_unique();
+    let target_key = Pubkey::new_unique();
+    let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+    let source_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+    let target_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN + 5), system_program::ID);
+
+    let mut range = 2u32.to_le_bytes().to_vec();
+    range.extend_from_slice(&3u32.to_le_bytes());
+
+    process_instructions(
+        &[
+            (
+                &allocate(&source_key, &source_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&source_key, &source_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&target_key, &target_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&target_key, &target_key, None, 0, &[9, 9]),
+                &[Check::success()],
+            ),
+            (
+                // Appends bytes `2..5` of the source buffer.
+                &write(&target_key, &target_key, Some(&source_key), 2, &range),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&target_key).space(Buffer::LEN + 5).build(),
+                    // target buffer data
+                    Check::account(&target_key)
+                        .data_slice(Buffer::LEN, &[9, 9, 3, 4, 5])
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (target_key, target_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn fail_write_from_buffer_with_invalid_range() {
+    let source_key = Pubkey::new_unique();
+    let target_key = Pubkey::new_unique();
+    let data = [1u8; 8];
+
+    let source_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+    let target_account = create_funded_account(
+        minimum_balance_for(Buffer::LEN + data.len()),
+        system_program::ID,
+    );
+
+    let mut range = 6u32.to_le_bytes().to_vec();
+    range.extend_from_slice(&3u32.to_le_bytes());
+
+    process_instructions(
+        &[
+            (
+                &allocate(&source_key, &source_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &write(&source_key, &source_key, None, 0, &data),
+                &[Check::success()],
+            ),
+            (
+                &allocate(&target_key, &target_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                // Range out of bounds of the source data.
+                &write(&target_key, &target_key, Some(&source_key), 0, &range),
+                &[Check::err(ProgramError::InvalidArgument)],
+            ),
+        ],
+        &[
+            (source_key, source_account),
+            (target_key, target_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn test_write_funded_by_payer() {
+    let buffer_key = Pubkey::new_unique();
+    let data = [6u8; 300];
+    // Only funded for the initial size of the buffer.
+    let buffer_account =
+        create_funded_account(minimum_balance_for(Buffer::LEN), system_program::ID);
+
+    let payer_key = Pubkey::new_unique();
+    let payer_lamports = 1_000_000_000;
+    let payer_account = create_funded_account(payer_lamports, system_program::ID);
+
+    let required_lamports =
+        minimum_balance_for(Buffer::LEN + data.len()) - minimum_balance_for(Buffer::LEN);
+
+    process_instructions(
+        &[
+            (
+                &allocate(&buffer_key, &buffer_key, None, None, None),
+                &[Check::success()],
+            ),
+            (
+                &with_payer(write(&buffer_key, &buffer_key, None, 0, &data), &payer_key),
+                &[
+                    Check::success(),
+                    // data length
+                    Check::account(&buffer_key)
+                        .space(Buffer::LEN + data.len())
+                        .build(),
+                    // lamports
+                    Check::account(&buffer_key)
+                        .lamports(minimum_balance_for(Buffer::LEN + data.len()))
+                        .build(),
+                    // payer lamports
+                    Check::account(&payer_key)
+                        .lamports(payer_lamports - required_lamports)
+                        .build(),
+                    // buffer data
+                    Check::account(&buffer_key)
+                        .data_slice(Buffer::LEN, &data)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (buffer_key, buffer_account),
+            (payer_key, payer_account),
+            keyed_account_for_system_program(),
+        ],
+    );
+}